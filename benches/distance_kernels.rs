@@ -0,0 +1,119 @@
+//! Compare les implémentations du produit scalaire et de la distance euclidienne au carré :
+//! boucle naïve sur slices, ndarray et noyaux par blocs de `distance::kernels`.
+//!
+//! Lancer avec `cargo bench --bench distance_kernels`.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use ndarray::Array1;
+
+use rust_first_steps::distance::kernels;
+
+const LENGTHS: [usize; 5] = [4, 64, 512, 4096, 65536];
+
+// Version naïve, identique à l'ancienne euclidean_distance (sans la racine)
+fn squared_euclidean_slice_f64(x: &[f64], y: &[f64]) -> f64 {
+    x.iter()
+        .zip(y.iter())
+        .map(|(xi, yi)| (xi - yi).powi(2))
+        .sum()
+}
+
+fn squared_euclidean_slice_f32(x: &[f32], y: &[f32]) -> f32 {
+    x.iter()
+        .zip(y.iter())
+        .map(|(xi, yi)| (xi - yi).powi(2))
+        .sum()
+}
+
+fn dot_slice_f64(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y.iter()).map(|(xi, yi)| xi * yi).sum()
+}
+
+fn dot_slice_f32(x: &[f32], y: &[f32]) -> f32 {
+    x.iter().zip(y.iter()).map(|(xi, yi)| xi * yi).sum()
+}
+
+fn make_vec_f64(n: usize, offset: f64) -> Vec<f64> {
+    (0..n).map(|i| (i as f64 * 0.37 + offset).sin()).collect()
+}
+
+fn make_vec_f32(n: usize, offset: f32) -> Vec<f32> {
+    (0..n).map(|i| (i as f32 * 0.37 + offset).sin()).collect()
+}
+
+fn bench_squared_euclidean(c: &mut Criterion) {
+    let mut group = c.benchmark_group("squared_euclidean");
+    for &n in LENGTHS.iter() {
+        group.throughput(Throughput::Elements(n as u64));
+
+        let (x, y) = (make_vec_f64(n, 0.0), make_vec_f64(n, 1.0));
+        let (x_nd, y_nd) = (Array1::from_vec(x.clone()), Array1::from_vec(y.clone()));
+        group.bench_with_input(BenchmarkId::new("slice_f64", n), &n, |b, _| {
+            b.iter(|| squared_euclidean_slice_f64(black_box(&x), black_box(&y)))
+        });
+        group.bench_with_input(BenchmarkId::new("ndarray_f64", n), &n, |b, _| {
+            b.iter(|| {
+                (black_box(&x_nd) - black_box(&y_nd))
+                    .mapv(|v| v.powi(2))
+                    .sum()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("kernel_f64", n), &n, |b, _| {
+            b.iter(|| kernels::squared_euclidean(black_box(&x), black_box(&y)).unwrap())
+        });
+
+        let (x, y) = (make_vec_f32(n, 0.0), make_vec_f32(n, 1.0));
+        let (x_nd, y_nd) = (Array1::from_vec(x.clone()), Array1::from_vec(y.clone()));
+        group.bench_with_input(BenchmarkId::new("slice_f32", n), &n, |b, _| {
+            b.iter(|| squared_euclidean_slice_f32(black_box(&x), black_box(&y)))
+        });
+        group.bench_with_input(BenchmarkId::new("ndarray_f32", n), &n, |b, _| {
+            b.iter(|| {
+                (black_box(&x_nd) - black_box(&y_nd))
+                    .mapv(|v| v.powi(2))
+                    .sum()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("kernel_f32", n), &n, |b, _| {
+            b.iter(|| kernels::squared_euclidean(black_box(&x), black_box(&y)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+    for &n in LENGTHS.iter() {
+        group.throughput(Throughput::Elements(n as u64));
+
+        let (x, y) = (make_vec_f64(n, 0.0), make_vec_f64(n, 1.0));
+        let (x_nd, y_nd) = (Array1::from_vec(x.clone()), Array1::from_vec(y.clone()));
+        group.bench_with_input(BenchmarkId::new("slice_f64", n), &n, |b, _| {
+            b.iter(|| dot_slice_f64(black_box(&x), black_box(&y)))
+        });
+        group.bench_with_input(BenchmarkId::new("ndarray_f64", n), &n, |b, _| {
+            b.iter(|| black_box(&x_nd).dot(black_box(&y_nd)))
+        });
+        group.bench_with_input(BenchmarkId::new("kernel_f64", n), &n, |b, _| {
+            b.iter(|| kernels::dot(black_box(&x), black_box(&y)).unwrap())
+        });
+
+        let (x, y) = (make_vec_f32(n, 0.0), make_vec_f32(n, 1.0));
+        let (x_nd, y_nd) = (Array1::from_vec(x.clone()), Array1::from_vec(y.clone()));
+        group.bench_with_input(BenchmarkId::new("slice_f32", n), &n, |b, _| {
+            b.iter(|| dot_slice_f32(black_box(&x), black_box(&y)))
+        });
+        group.bench_with_input(BenchmarkId::new("ndarray_f32", n), &n, |b, _| {
+            b.iter(|| black_box(&x_nd).dot(black_box(&y_nd)))
+        });
+        group.bench_with_input(BenchmarkId::new("kernel_f32", n), &n, |b, _| {
+            b.iter(|| kernels::dot(black_box(&x), black_box(&y)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_squared_euclidean, bench_dot);
+criterion_main!(benches);
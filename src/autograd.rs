@@ -0,0 +1,466 @@
+//! Différentiation automatique en mode inverse (rétropropagation) sur un ruban.
+//!
+//! Chaque opération sur des [`Var`] calcule sa valeur immédiatement et s'enregistre
+//! sur le [`Tape`]. [`Var::backward`] parcourt ensuite le ruban à l'envers et applique
+//! la règle de la chaîne : on obtient le gradient d'une perte par rapport à toutes
+//! les variables en une seule passe, sans dériver les formules à la main.
+//!
+//! Les valeurs sont des matrices `Array2<f64>` ; un scalaire est une matrice 1×1.
+//! Les opérations élément par élément diffusent (broadcast) comme ndarray, ce qui
+//! permet d'ajouter un biais 1×n à une matrice n_obs×n ou de multiplier par un scalaire.
+//!
+//! # Example
+//! ```
+//! use ndarray::array;
+//! use rust_first_steps::autograd::Tape;
+//!
+//! let tape = Tape::new();
+//! let x = tape.var(array![[1.0, 2.0]]);
+//! let w = tape.var(array![[3.0], [4.0]]);
+//! // y = exp(x · w) / 10, scalaire
+//! let y = x.matmul(w).exp() * 0.1;
+//! let grads = y.backward();
+//! // dy/dw = y · xᵀ
+//! let dw = grads.wrt(w).unwrap();
+//! assert!((dw[[1, 0]] - 2.0 * y.item()).abs() < 1e-9);
+//! ```
+
+use std::cell::RefCell;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use ndarray::{Array2, Axis};
+
+use crate::neural_network::Activation;
+
+/// Opération qui a produit un nœud, avec les indices de ses entrées sur le ruban.
+#[derive(Debug, Clone)]
+enum Op {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Neg(usize),
+    Scale(usize, f64),
+    MatMul(usize, usize),
+    Exp(usize),
+    Ln(usize),
+    Clamp(usize, f64, f64),
+    Activation(usize, Activation),
+    Sum(usize),
+    Mean(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    value: Array2<f64>,
+    op: Op,
+}
+
+/// Ruban qui enregistre les opérations dans l'ordre où elles sont calculées.
+#[derive(Debug, Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>,
+}
+
+impl Tape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nouvelle variable (feuille du graphe de calcul), par exemple des poids ou des données.
+    pub fn var(&self, value: Array2<f64>) -> Var<'_> {
+        self.push(value, Op::Leaf)
+    }
+
+    /// Variable scalaire, stockée comme une matrice 1×1.
+    pub fn scalar(&self, value: f64) -> Var<'_> {
+        self.var(Array2::from_elem((1, 1), value))
+    }
+
+    /// Nombre de nœuds enregistrés.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&self, value: Array2<f64>, op: Op) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var {
+            tape: self,
+            id: nodes.len() - 1,
+        }
+    }
+
+    fn value(&self, id: usize) -> Array2<f64> {
+        self.nodes.borrow()[id].value.clone()
+    }
+
+    /// Applique `f` aux valeurs de deux nœuds et enregistre le résultat.
+    fn binary(
+        &self,
+        a: usize,
+        b: usize,
+        f: impl Fn(&Array2<f64>, &Array2<f64>) -> Array2<f64>,
+        op: Op,
+    ) -> Var<'_> {
+        let value = {
+            let nodes = self.nodes.borrow();
+            f(&nodes[a].value, &nodes[b].value)
+        };
+        self.push(value, op)
+    }
+
+    fn unary(&self, a: usize, f: impl Fn(&Array2<f64>) -> Array2<f64>, op: Op) -> Var<'_> {
+        let value = f(&self.nodes.borrow()[a].value);
+        self.push(value, op)
+    }
+}
+
+/// Référence vers un nœud du ruban. `Var` est `Copy` : on peut la réutiliser
+/// dans plusieurs expressions, les gradients s'additionnent.
+#[derive(Debug, Clone, Copy)]
+pub struct Var<'t> {
+    tape: &'t Tape,
+    id: usize,
+}
+
+impl<'t> Var<'t> {
+    /// Valeur calculée lors de la passe avant.
+    pub fn value(&self) -> Array2<f64> {
+        self.tape.value(self.id)
+    }
+
+    /// Valeur d'une variable scalaire (1×1).
+    ///
+    /// # Panics
+    /// Si la variable n'est pas 1×1.
+    pub fn item(&self) -> f64 {
+        let nodes = self.tape.nodes.borrow();
+        let value = &nodes[self.id].value;
+        assert_eq!(value.dim(), (1, 1), "item() attend une variable 1×1");
+        value[[0, 0]]
+    }
+
+    /// Produit matriciel self · other.
+    pub fn matmul(self, other: Var<'t>) -> Var<'t> {
+        self.tape.binary(
+            self.id,
+            other.id,
+            |a, b| a.dot(b),
+            Op::MatMul(self.id, other.id),
+        )
+    }
+
+    pub fn exp(self) -> Var<'t> {
+        self.tape
+            .unary(self.id, |a| a.mapv(f64::exp), Op::Exp(self.id))
+    }
+
+    /// Logarithme népérien, élément par élément.
+    pub fn ln(self) -> Var<'t> {
+        self.tape
+            .unary(self.id, |a| a.mapv(f64::ln), Op::Ln(self.id))
+    }
+
+    /// Borne chaque élément dans [min, max] ; le gradient est nul là où la borne s'applique.
+    pub fn clamp(self, min: f64, max: f64) -> Var<'t> {
+        self.tape.unary(
+            self.id,
+            |a| a.mapv(|v| v.clamp(min, max)),
+            Op::Clamp(self.id, min, max),
+        )
+    }
+
+    /// Applique une fonction d'activation (softmax : ligne par ligne).
+    pub fn activation(self, activation: Activation) -> Var<'t> {
+        self.tape.unary(
+            self.id,
+            |a| activation.forward(a),
+            Op::Activation(self.id, activation),
+        )
+    }
+
+    pub fn relu(self) -> Var<'t> {
+        self.activation(Activation::Relu)
+    }
+
+    pub fn sigmoid(self) -> Var<'t> {
+        self.activation(Activation::Sigmoid)
+    }
+
+    pub fn tanh(self) -> Var<'t> {
+        self.activation(Activation::Tanh)
+    }
+
+    pub fn softmax(self) -> Var<'t> {
+        self.activation(Activation::Softmax)
+    }
+
+    /// Somme de tous les éléments (résultat 1×1).
+    pub fn sum(self) -> Var<'t> {
+        self.tape.unary(
+            self.id,
+            |a| Array2::from_elem((1, 1), a.sum()),
+            Op::Sum(self.id),
+        )
+    }
+
+    /// Moyenne de tous les éléments (résultat 1×1).
+    pub fn mean(self) -> Var<'t> {
+        self.tape.unary(
+            self.id,
+            |a| Array2::from_elem((1, 1), a.mean().unwrap_or(0.0)),
+            Op::Mean(self.id),
+        )
+    }
+
+    /// Rétropropagation depuis cette variable : gradient de la somme de ses
+    /// éléments (de sa valeur, si elle est scalaire) par rapport à chaque nœud du ruban.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::{Array2, array};
+    /// use rust_first_steps::autograd::{Tape, Var, numerical_gradient};
+    ///
+    /// // MLP 3 -> 4 (tanh) -> 3 (softmax), entropie croisée moyenne
+    /// fn mlp_loss<'t>(
+    ///     tape: &'t Tape,
+    ///     params: &[Var<'t>],
+    ///     x: &Array2<f64>,
+    ///     y: &Array2<f64>,
+    /// ) -> Var<'t> {
+    ///     let hidden = (tape.var(x.clone()).matmul(params[0]) + params[1]).tanh();
+    ///     let proba = (hidden.matmul(params[2]) + params[3]).softmax();
+    ///     (tape.var(y.clone()) * proba.ln()).sum() * (-1.0 / x.nrows() as f64)
+    /// }
+    ///
+    /// let x = array![[0.5, -1.0, 2.0], [1.5, 0.3, -0.4], [-0.7, 2.0, 0.1]];
+    /// let y = array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    /// let shapes = [(3, 4), (1, 4), (4, 3), (1, 3)];
+    /// // Paramètres fixes mais variés, sans générateur aléatoire
+    /// let params: Vec<Array2<f64>> = shapes
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(k, &shape)| {
+    ///         Array2::from_shape_fn(shape, |(i, j)| ((1 + k + 3 * i + j) as f64).sin())
+    ///     })
+    ///     .collect();
+    ///
+    /// let tape = Tape::new();
+    /// let vars: Vec<Var> = params.iter().map(|p| tape.var(p.clone())).collect();
+    /// let grads = mlp_loss(&tape, &vars, &x, &y).backward();
+    /// for (k, param) in params.iter().enumerate() {
+    ///     // Différences finies sur le paramètre k, les autres restent fixes
+    ///     let numerical = numerical_gradient(
+    ///         |p| {
+    ///             let tape = Tape::new();
+    ///             let vars: Vec<Var> = params
+    ///                 .iter()
+    ///                 .enumerate()
+    ///                 .map(|(j, q)| tape.var(if j == k { p.clone() } else { q.clone() }))
+    ///                 .collect();
+    ///             mlp_loss(&tape, &vars, &x, &y).item()
+    ///         },
+    ///         param,
+    ///         1e-6,
+    ///     );
+    ///     let error = (grads.wrt(vars[k]).unwrap() - &numerical).mapv(f64::abs);
+    ///     assert!(error.iter().all(|&e| e < 1e-7), "paramètre {} : {}", k, error);
+    /// }
+    /// ```
+    pub fn backward(&self) -> Gradients {
+        let nodes = self.tape.nodes.borrow();
+        let mut grads: Vec<Option<Array2<f64>>> = vec![None; self.id + 1];
+        grads[self.id] = Some(Array2::ones(nodes[self.id].value.raw_dim()));
+
+        // Le ruban est dans l'ordre de calcul : le parcourir à l'envers respecte les dépendances
+        for id in (0..=self.id).rev() {
+            let Some(grad) = grads[id].take() else {
+                continue;
+            };
+            let node = &nodes[id];
+            let mut accumulate = |target: usize, g: Array2<f64>| {
+                let g = unbroadcast(g, nodes[target].value.dim());
+                match &mut grads[target] {
+                    Some(existing) => *existing += &g,
+                    slot => *slot = Some(g),
+                }
+            };
+            match node.op {
+                Op::Leaf => {}
+                Op::Add(a, b) => {
+                    accumulate(a, grad.clone());
+                    accumulate(b, grad.clone());
+                }
+                Op::Sub(a, b) => {
+                    accumulate(a, grad.clone());
+                    accumulate(b, -&grad);
+                }
+                Op::Mul(a, b) => {
+                    accumulate(a, &grad * &nodes[b].value);
+                    accumulate(b, &grad * &nodes[a].value);
+                }
+                Op::Neg(a) => accumulate(a, -&grad),
+                Op::Scale(a, factor) => accumulate(a, &grad * factor),
+                Op::MatMul(a, b) => {
+                    accumulate(a, grad.dot(&nodes[b].value.t()));
+                    accumulate(b, nodes[a].value.t().dot(&grad));
+                }
+                Op::Exp(a) => accumulate(a, &grad * &node.value),
+                Op::Ln(a) => accumulate(a, &grad / &nodes[a].value),
+                Op::Clamp(a, min, max) => {
+                    let inside = nodes[a]
+                        .value
+                        .mapv(|v| if (min..=max).contains(&v) { 1.0 } else { 0.0 });
+                    accumulate(a, &grad * &inside);
+                }
+                Op::Activation(a, activation) => {
+                    accumulate(a, activation.backward(&nodes[a].value, &node.value, &grad));
+                }
+                Op::Sum(a) => {
+                    accumulate(a, Array2::from_elem(nodes[a].value.raw_dim(), grad[[0, 0]]))
+                }
+                Op::Mean(a) => {
+                    let n = nodes[a].value.len() as f64;
+                    accumulate(
+                        a,
+                        Array2::from_elem(nodes[a].value.raw_dim(), grad[[0, 0]] / n),
+                    );
+                }
+            }
+            grads[id] = Some(grad);
+        }
+        Gradients { grads }
+    }
+}
+
+/// Gradients calculés par [`Var::backward`].
+#[derive(Debug, Clone)]
+pub struct Gradients {
+    grads: Vec<Option<Array2<f64>>>,
+}
+
+impl Gradients {
+    /// Gradient par rapport à `var`, ou `None` si le résultat n'en dépend pas.
+    pub fn wrt(&self, var: Var<'_>) -> Option<&Array2<f64>> {
+        self.grads.get(var.id).and_then(Option::as_ref)
+    }
+}
+
+/// Ramène un gradient à la forme d'une entrée qui a été diffusée, en sommant sur
+/// les axes où l'entrée valait 1.
+fn unbroadcast(grad: Array2<f64>, (rows, cols): (usize, usize)) -> Array2<f64> {
+    let mut grad = grad;
+    if rows == 1 && grad.nrows() != 1 {
+        grad = grad.sum_axis(Axis(0)).insert_axis(Axis(0));
+    }
+    if cols == 1 && grad.ncols() != 1 {
+        grad = grad.sum_axis(Axis(1)).insert_axis(Axis(1));
+    }
+    grad
+}
+
+impl<'t> Add for Var<'t> {
+    type Output = Var<'t>;
+
+    fn add(self, other: Var<'t>) -> Var<'t> {
+        self.tape
+            .binary(self.id, other.id, |a, b| a + b, Op::Add(self.id, other.id))
+    }
+}
+
+impl<'t> Sub for Var<'t> {
+    type Output = Var<'t>;
+
+    fn sub(self, other: Var<'t>) -> Var<'t> {
+        self.tape
+            .binary(self.id, other.id, |a, b| a - b, Op::Sub(self.id, other.id))
+    }
+}
+
+/// Produit élément par élément (voir [`Var::matmul`] pour le produit matriciel).
+impl<'t> Mul for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, other: Var<'t>) -> Var<'t> {
+        self.tape
+            .binary(self.id, other.id, |a, b| a * b, Op::Mul(self.id, other.id))
+    }
+}
+
+impl<'t> Mul<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, factor: f64) -> Var<'t> {
+        self.tape
+            .unary(self.id, |a| a * factor, Op::Scale(self.id, factor))
+    }
+}
+
+impl<'t> Neg for Var<'t> {
+    type Output = Var<'t>;
+
+    fn neg(self) -> Var<'t> {
+        self.tape.unary(self.id, |a| -a, Op::Neg(self.id))
+    }
+}
+
+/// Gradient de `f` en `x` par différences finies centrées, pour vérifier la rétropropagation.
+///
+/// # Example
+/// ```
+/// use ndarray::{Array2, array};
+/// use rust_first_steps::autograd::{Tape, Var, numerical_gradient};
+///
+/// // Entropie croisée d'une régression logistique, en fonction des poids w
+/// fn logistic_loss<'t>(
+///     tape: &'t Tape,
+///     w: Var<'t>,
+///     x: &Array2<f64>,
+///     y: &Array2<f64>,
+/// ) -> Var<'t> {
+///     let p = tape.var(x.clone()).matmul(w).sigmoid();
+///     let (y, one) = (tape.var(y.clone()), tape.scalar(1.0));
+///     -(y * p.ln() + (one - y) * (one - p).ln()).mean()
+/// }
+///
+/// let x = array![[0.5, -1.0], [1.5, 0.3], [-0.7, 2.0], [0.1, 0.4]];
+/// let y = array![[1.0], [0.0], [1.0], [0.0]];
+/// let w0 = array![[0.3], [-0.2]];
+///
+/// let tape = Tape::new();
+/// let w = tape.var(w0.clone());
+/// let grads = logistic_loss(&tape, w, &x, &y).backward();
+/// let numerical = numerical_gradient(
+///     |w| {
+///         let tape = Tape::new();
+///         logistic_loss(&tape, tape.var(w.clone()), &x, &y).item()
+///     },
+///     &w0,
+///     1e-6,
+/// );
+/// let error = (grads.wrt(w).unwrap() - &numerical).mapv(f64::abs);
+/// assert!(error.iter().all(|&e| e < 1e-7));
+/// ```
+pub fn numerical_gradient(
+    f: impl Fn(&Array2<f64>) -> f64,
+    x: &Array2<f64>,
+    eps: f64,
+) -> Array2<f64> {
+    let mut grad = Array2::zeros(x.raw_dim());
+    let mut shifted = x.clone();
+    for (idx, g) in grad.indexed_iter_mut() {
+        let original = shifted[idx];
+        shifted[idx] = original + eps;
+        let plus = f(&shifted);
+        shifted[idx] = original - eps;
+        let minus = f(&shifted);
+        shifted[idx] = original;
+        *g = (plus - minus) / (2.0 * eps);
+    }
+    grad
+}
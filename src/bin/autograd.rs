@@ -0,0 +1,225 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2, Axis};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use rust_first_steps::autograd::{Tape, Var, numerical_gradient};
+use rust_first_steps::data::{load_diabetes, load_iris};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::neural_network::{Activation, MlpClassifier, Optimizer};
+use rust_first_steps::preprocessing::LabelEncoder;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // --- Vérification : gradients du ruban contre différences finies, pour un petit MLP
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let x_small = x.select(Axis(0), &[0, 1, 50, 51, 100, 101]);
+    let y_small = one_hot(&y.select(Axis(0), &[0, 1, 50, 51, 100, 101]), 3);
+    let params = init_mlp(&[4, 5, 3], &mut rng);
+
+    let tape = Tape::new();
+    let vars: Vec<Var> = params.iter().map(|p| tape.var(p.clone())).collect();
+    let loss = mlp_loss(&tape, &vars, &x_small, &y_small);
+    let grads = loss.backward();
+    for (k, param) in params.iter().enumerate() {
+        // On ne fait varier que le paramètre k, les autres restent fixes
+        let numerical = numerical_gradient(
+            |p| {
+                let tape = Tape::new();
+                let vars: Vec<Var> = params
+                    .iter()
+                    .enumerate()
+                    .map(|(j, q)| tape.var(if j == k { p.clone() } else { q.clone() }))
+                    .collect();
+                mlp_loss(&tape, &vars, &x_small, &y_small).item()
+            },
+            param,
+            1e-6,
+        );
+        let autograd = grads
+            .wrt(vars[k])
+            .expect("la perte dépend de tous les paramètres");
+        let max_error = (autograd - &numerical)
+            .mapv(f64::abs)
+            .fold(0.0_f64, |a, &b| a.max(b));
+        println!(
+            "[GRADIENT CHECK] Paramètre {} : écart max = {:.2e}",
+            k, max_error
+        );
+    }
+
+    // --- MLP 4 -> 16 -> 3 sur iris, gradients calculés par le ruban
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+    let target = one_hot(&y_train, 3);
+    let mut params = init_mlp(&[4, 16, 3], &mut rng);
+    for _ in 0..500 {
+        let tape = Tape::new();
+        let vars: Vec<Var> = params.iter().map(|p| tape.var(p.clone())).collect();
+        let grads = mlp_loss(&tape, &vars, &x_train, &target).backward();
+        for (param, var) in params.iter_mut().zip(&vars) {
+            *param -= &(grads.wrt(*var).expect("gradient calculé") * 0.5);
+        }
+    }
+    let tape = Tape::new();
+    let vars: Vec<Var> = params.iter().map(|p| tape.var(p.clone())).collect();
+    let proba = mlp_forward(&tape, &vars, &x_test).value();
+    let y_pred: Array1<usize> = proba
+        .rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .fold((0, f64::NEG_INFINITY), |best, (k, &p)| {
+                    if p > best.1 { (k, p) } else { best }
+                })
+                .0
+        })
+        .collect();
+    println!(
+        "[IRIS] MLP (autograd) : accuracy test = {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+
+    // --- Même réseau avec MlpClassifier : rétropropagation écrite à la main puis par le ruban
+    for autograd in [false, true] {
+        let mut mlp = MlpClassifier::new(&[16])
+            .with_activation(Activation::Tanh)
+            .with_optimizer(Optimizer::sgd(0.5, 0.0))
+            .with_batch_size(x_train.nrows())
+            .with_max_epochs(500)
+            .with_autograd(autograd);
+        mlp.fit(&x_train, &y_train)?;
+        println!(
+            "[IRIS] MlpClassifier (autograd = {}) : accuracy test = {:.3}",
+            autograd,
+            accuracy(&y_test, &mlp.predict(&x_test)?)?
+        );
+    }
+
+    // --- Régression logistique sur diabetes, puis même modèle avec une perte personnalisée
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+    let target = y_train.mapv(|c| c as f64).insert_axis(Axis(1));
+
+    for (name, positive_weight, l2) in [
+        ("entropie croisée", 1.0, 0.0),
+        ("entropie croisée pondérée (x2 malades) + L2", 2.0, 0.01),
+    ] {
+        let (w, b) = fit_logistic(&x_train, &target, positive_weight, l2);
+        let y_pred = (x_test.dot(&w) + b).mapv(|z| usize::from(z > 0.0));
+        let positives = y_test.iter().filter(|&&c| c == 1).count();
+        let detected = y_test
+            .iter()
+            .zip(&y_pred)
+            .filter(|&(&t, &p)| t == 1 && p == 1)
+            .count();
+        println!(
+            "[DIABETES] Logistique ({}) : accuracy = {:.3}, rappel des malades = {:.3}",
+            name,
+            accuracy(&y_test, &y_pred)?,
+            detected as f64 / positives as f64
+        );
+    }
+
+    Ok(())
+}
+
+/*
+MODÈLES ÉCRITS AVEC LE RUBAN
+*/
+
+/// Poids (entrées × sorties) et biais (1 × sorties) de chaque couche, initialisation de Glorot
+fn init_mlp(sizes: &[usize], rng: &mut StdRng) -> Vec<Array2<f64>> {
+    sizes
+        .windows(2)
+        .flat_map(|w| {
+            let limit = (6.0 / (w[0] + w[1]) as f64).sqrt();
+            [
+                Array2::from_shape_fn((w[0], w[1]), |_| rng.random_range(-limit..limit)),
+                Array2::zeros((1, w[1])),
+            ]
+        })
+        .collect()
+}
+
+/// Couches cachées tanh, sortie softmax ; `vars` alterne poids et biais
+fn mlp_forward<'t>(tape: &'t Tape, vars: &[Var<'t>], x: &Array2<f64>) -> Var<'t> {
+    let n_layers = vars.len() / 2;
+    let mut a = tape.var(x.clone());
+    for l in 0..n_layers {
+        let z = a.matmul(vars[2 * l]) + vars[2 * l + 1];
+        a = if l + 1 == n_layers {
+            z.softmax()
+        } else {
+            z.tanh()
+        };
+    }
+    a
+}
+
+/// Entropie croisée moyenne : -1/n Σᵢ Σₖ yᵢₖ ln(pᵢₖ)
+fn mlp_loss<'t>(
+    tape: &'t Tape,
+    vars: &[Var<'t>],
+    x: &Array2<f64>,
+    target: &Array2<f64>,
+) -> Var<'t> {
+    let proba = mlp_forward(tape, vars, x);
+    (tape.var(target.clone()) * proba.ln()).sum() * (-1.0 / x.nrows() as f64)
+}
+
+/// Régression logistique par descente de gradient ; la perte est une entropie croisée
+/// où les malades pèsent `positive_weight`, plus une pénalité L2 sur les poids
+fn fit_logistic(
+    x: &Array2<f64>,
+    target: &Array2<f64>,
+    positive_weight: f64,
+    l2: f64,
+) -> (Array1<f64>, f64) {
+    let mut w = Array2::zeros((x.ncols(), 1));
+    let mut b = Array2::zeros((1, 1));
+    let weights = target.mapv(|t| if t > 0.5 { positive_weight } else { 1.0 });
+    for _ in 0..1000 {
+        let tape = Tape::new();
+        let (w_var, b_var) = (tape.var(w.clone()), tape.var(b.clone()));
+        let p = (tape.var(x.clone()).matmul(w_var) + b_var).sigmoid();
+        let y = tape.var(target.clone());
+        let one = tape.scalar(1.0);
+        let log_likelihood = y * p.ln() + (one - y) * (one - p).ln();
+        let loss =
+            -(tape.var(weights.clone()) * log_likelihood).mean() + (w_var * w_var).sum() * l2;
+
+        let grads = loss.backward();
+        w -= &(grads.wrt(w_var).expect("gradient calculé") * 0.5);
+        b -= &(grads.wrt(b_var).expect("gradient calculé") * 0.5);
+    }
+    (w.column(0).to_owned(), b[[0, 0]])
+}
+
+// Une colonne par classe, 1 pour la classe de l'observation
+fn one_hot(y: &Array1<usize>, n_classes: usize) -> Array2<f64> {
+    let mut encoded = Array2::zeros((y.len(), n_classes));
+    for (i, &c) in y.iter().enumerate() {
+        encoded[[i, c]] = 1.0;
+    }
+    encoded
+}
+
+// Centre et réduit les features avec les statistiques du jeu d'entraînement
+fn standardize(x_train: &Array2<f64>, x_test: &Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let mean = x_train
+        .mean_axis(Axis(0))
+        .expect("jeu d'entraînement non vide");
+    let std = x_train.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    ((x_train - &mean) / &std, (x_test - &mean) / &std)
+}
+
+/*
+DATA MANAGEMENT
+*/
@@ -0,0 +1,80 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+
+use rust_first_steps::correlation::{CorrelationMethod, correlation_matrix};
+use rust_first_steps::data::{DIABETES_FEATURES, IRIS_FEATURES, load_diabetes, load_iris};
+use rust_first_steps::plot::{plot_correlation_heatmap, plot_pair_plot};
+use rust_first_steps::preprocessing::LabelEncoder;
+
+const METHODS: [CorrelationMethod; 3] = [
+    CorrelationMethod::Pearson,
+    CorrelationMethod::Spearman,
+    CorrelationMethod::Kendall,
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (x, species) = load_iris("data/iris.csv")?;
+    let mut encoder = LabelEncoder::new();
+    let y = encoder.fit_transform(&species)?;
+    let classes: Vec<&str> = encoder.classes()?.iter().map(String::as_str).collect();
+    analyse("iris", &x, &y, &IRIS_FEATURES, &classes)?;
+
+    let (x, outcome) = load_diabetes("data/diabetes.csv")?;
+    analyse(
+        "diabetes",
+        &x,
+        &outcome,
+        &DIABETES_FEATURES,
+        &["Non diabétique", "Diabétique"],
+    )?;
+    Ok(())
+}
+
+/// Les trois matrices de corrélation (affichées et en heatmap) et le pair plot
+fn analyse(
+    dataset: &str,
+    x: &Array2<f64>,
+    y: &Array1<usize>,
+    features: &[&str],
+    classes: &[&str],
+) -> Result<(), Box<dyn Error>> {
+    for method in METHODS {
+        let corr = correlation_matrix(x, method);
+        println!("\n[{}] Corrélation de {} :", dataset, method.name());
+        print_matrix(&corr, features);
+        plot_correlation_heatmap(
+            &corr,
+            features,
+            &format!("Corrélation de {} ({})", method.name(), dataset),
+            format!("plots/correlation_{}_{}.html", method.name(), dataset),
+        )?;
+    }
+    plot_pair_plot(
+        x,
+        y,
+        features,
+        classes,
+        &format!("Pair plot {}", dataset),
+        format!("plots/pair_plot_{}.html", dataset),
+    )?;
+    println!("[{}] Heatmaps et pair plot écrits dans plots/", dataset);
+    Ok(())
+}
+
+fn print_matrix(corr: &Array2<f64>, names: &[&str]) {
+    let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+    // En-têtes tronqués à 8 caractères pour garder des colonnes étroites
+    print!("{:<width$}", "");
+    for name in names {
+        print!(" {:>8.8}", name);
+    }
+    println!();
+    for (name, row) in names.iter().zip(corr.rows()) {
+        print!("{:<width$}", name);
+        for r in row {
+            print!(" {:>8.3}", r);
+        }
+        println!();
+    }
+}
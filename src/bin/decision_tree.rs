@@ -0,0 +1,226 @@
+use std::error::Error;
+
+use ndarray::Array1;
+use plotly::common::{Mode, Position};
+use plotly::layout::{Axis, Layout};
+use plotly::{Plot, Scatter};
+
+use rust_first_steps::data::{
+    DIABETES_FEATURES, HOUSING_FEATURES, IRIS_FEATURES, IRIS_SPECIES, load_diabetes, load_housing,
+    load_iris,
+};
+use rust_first_steps::metrics::{accuracy, mean_squared_error, r2_score};
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::preprocessing::LabelEncoder;
+use rust_first_steps::tree::{
+    Criterion, DecisionTreeClassifier, DecisionTreeRegressor, Node, Tree,
+};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // --- Iris : classification des espèces
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut tree = DecisionTreeClassifier::new().with_max_depth(3);
+    tree.fit(&x_train, &y_train)?;
+    println!(
+        "[IRIS] Arbre (Gini, profondeur max 3) :\n{}",
+        tree.export_text(&IRIS_FEATURES, &IRIS_SPECIES)?
+    );
+    println!(
+        "[IRIS] Accuracy (Gini) : {:.3}",
+        accuracy(&y_test, &tree.predict(&x_test)?)?
+    );
+    print_importances(&IRIS_FEATURES, tree.feature_importances()?);
+    plot_tree(
+        tree.tree()?,
+        &IRIS_FEATURES,
+        "Arbre de décision - iris",
+        "plots/decision_tree_iris.html",
+        |node| IRIS_SPECIES[node.majority_class()].to_string(),
+    );
+
+    let mut tree_entropy = DecisionTreeClassifier::new()
+        .with_criterion(Criterion::Entropy)
+        .with_max_depth(3);
+    tree_entropy.fit(&x_train, &y_train)?;
+    println!(
+        "[IRIS] Accuracy (entropie) : {:.3}",
+        accuracy(&y_test, &tree_entropy.predict(&x_test)?)?
+    );
+
+    // --- Diabetes : classification de l'outcome
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut tree = DecisionTreeClassifier::new()
+        .with_max_depth(4)
+        .with_min_samples_leaf(5);
+    tree.fit(&x_train, &y_train)?;
+    println!(
+        "[DIABETES] Accuracy : {:.3}",
+        accuracy(&y_test, &tree.predict(&x_test)?)?
+    );
+    print_importances(&DIABETES_FEATURES, tree.feature_importances()?);
+
+    // --- Housing : régression du prix
+    let (x, y) = load_housing("data/housing.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut tree = DecisionTreeRegressor::new()
+        .with_max_depth(5)
+        .with_min_samples_split(10);
+    tree.fit(&x_train, &y_train)?;
+    let y_pred = tree.predict(&x_test)?;
+    println!(
+        "[HOUSING] Arbre (profondeur max 5) : {} feuilles",
+        tree.tree()?.n_leaves()
+    );
+    println!(
+        "[HOUSING] RMSE : {:.2}",
+        mean_squared_error(&y_test, &y_pred)?.sqrt()
+    );
+    println!("[HOUSING] R² : {:.3}", r2_score(&y_test, &y_pred)?);
+    print_importances(&HOUSING_FEATURES, tree.feature_importances()?);
+
+    let mut shallow = DecisionTreeRegressor::new().with_max_depth(2);
+    shallow.fit(&x_train, &y_train)?;
+    println!(
+        "[HOUSING] Arbre (profondeur max 2) :\n{}",
+        shallow.export_text(&HOUSING_FEATURES)?
+    );
+    plot_tree(
+        shallow.tree()?,
+        &HOUSING_FEATURES,
+        "Arbre de régression - housing",
+        "plots/decision_tree_housing.html",
+        |node| format!("{:.0}", node.value[0]),
+    );
+
+    Ok(())
+}
+
+// Affiche l'importance de chaque feature, de la plus importante à la moins importante
+fn print_importances(names: &[&str], importances: &Array1<f64>) {
+    let mut pairs: Vec<(&str, f64)> = names
+        .iter()
+        .copied()
+        .zip(importances.iter().copied())
+        .collect();
+    pairs.sort_by(|a, b| b.1.total_cmp(&a.1));
+    println!("Importance des features :");
+    for (name, importance) in pairs {
+        println!("  {:<28} {:.3}", name, importance);
+    }
+}
+
+/*
+PLOTTING
+*/
+
+// Dessine l'arbre : chaque nœud est un point, chaque branche un segment.
+// Les feuilles sont espacées régulièrement sur l'axe x, un nœud interne est centré
+// au-dessus de ses deux enfants, l'axe y correspond à la profondeur.
+fn plot_tree(
+    tree: &Tree,
+    feature_names: &[&str],
+    title: &str,
+    filename: &str,
+    leaf_label: impl Fn(&Node) -> String,
+) {
+    let nodes = tree.nodes();
+    let mut positions = vec![(0.0, 0.0); nodes.len()];
+    let mut next_leaf_x = 0.0;
+    layout_node(tree, 0, 0, &mut next_leaf_x, &mut positions);
+
+    // Segments parent -> enfant, séparés par None pour que Plotly ne les relie pas entre eux
+    let mut edge_x: Vec<Option<f64>> = Vec::new();
+    let mut edge_y: Vec<Option<f64>> = Vec::new();
+    for (id, node) in nodes.iter().enumerate() {
+        if let Some(split) = &node.split {
+            for child in [split.left, split.right] {
+                edge_x.extend([Some(positions[id].0), Some(positions[child].0), None]);
+                edge_y.extend([Some(positions[id].1), Some(positions[child].1), None]);
+            }
+        }
+    }
+
+    let labels: Vec<String> = nodes
+        .iter()
+        .map(|node| match &node.split {
+            Some(split) => format!(
+                "{} <= {:.2}",
+                feature_names.get(split.feature).copied().unwrap_or("?"),
+                split.threshold
+            ),
+            None => leaf_label(node),
+        })
+        .collect();
+    let hover: Vec<String> = nodes
+        .iter()
+        .map(|node| format!("n = {}, impureté = {:.3}", node.n_samples, node.impurity))
+        .collect();
+
+    let edges = Scatter::new(edge_x, edge_y)
+        .mode(Mode::Lines)
+        .name("Branches");
+    let points = Scatter::new(
+        positions.iter().map(|p| p.0).collect(),
+        positions.iter().map(|p| p.1).collect(),
+    )
+    .mode(Mode::MarkersText)
+    .text_array(labels)
+    .text_position(Position::TopCenter)
+    .hover_text_array(hover)
+    .name("Nœuds");
+
+    let mut plot = Plot::new();
+    plot.add_trace(edges);
+    plot.add_trace(points);
+    plot.set_layout(
+        Layout::new()
+            .title(title)
+            .x_axis(
+                Axis::new()
+                    .show_tick_labels(false)
+                    .show_grid(false)
+                    .zero_line(false),
+            )
+            .y_axis(
+                Axis::new()
+                    .title("Profondeur")
+                    .show_grid(false)
+                    .zero_line(false),
+            ),
+    );
+    std::fs::create_dir_all("plots").expect("Impossible de créer le dossier plots");
+    plot.write_html(filename);
+}
+
+// Calcule récursivement la position (x, y) de chaque nœud
+fn layout_node(
+    tree: &Tree,
+    id: usize,
+    depth: usize,
+    next_leaf_x: &mut f64,
+    positions: &mut [(f64, f64)],
+) {
+    let y = -(depth as f64);
+    match &tree.nodes()[id].split {
+        Some(split) => {
+            layout_node(tree, split.left, depth + 1, next_leaf_x, positions);
+            layout_node(tree, split.right, depth + 1, next_leaf_x, positions);
+            let x = (positions[split.left].0 + positions[split.right].0) / 2.0;
+            positions[id] = (x, y);
+        }
+        None => {
+            positions[id] = (*next_leaf_x, y);
+            *next_leaf_x += 1.0;
+        }
+    }
+}
+
+/*
+DATA MANAGEMENT
+*/
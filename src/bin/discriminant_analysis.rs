@@ -0,0 +1,77 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+
+use plotly::Plot;
+use plotly::layout::{Axis as PlotAxis, Layout};
+
+use rust_first_steps::data::{IRIS_SPECIES, load_iris};
+use rust_first_steps::discriminant_analysis::{
+    LinearDiscriminantAnalysis, QuadraticDiscriminantAnalysis,
+};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::plot::{add_class_scatter, save_html};
+use rust_first_steps::preprocessing::LabelEncoder;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut lda = LinearDiscriminantAnalysis::new();
+    lda.fit(&x_train, &y_train)?;
+    println!(
+        "[IRIS] LDA accuracy : {:.3}",
+        accuracy(&y_test, &lda.predict(&x_test)?)?
+    );
+
+    let mut qda = QuadraticDiscriminantAnalysis::new();
+    qda.fit(&x_train, &y_train)?;
+    println!(
+        "[IRIS] QDA accuracy : {:.3}",
+        accuracy(&y_test, &qda.predict(&x_test)?)?
+    );
+    println!(
+        "[IRIS] QDA predict_proba (3 premières lignes) :\n{:.3}",
+        qda.predict_proba(&x_test.slice(ndarray::s![..3, ..]).to_owned())?
+    );
+
+    // Réduction de dimension supervisée : 3 classes donc 2 directions discriminantes
+    let mut lda = LinearDiscriminantAnalysis::new();
+    let projected = lda.fit_transform(&x, &y)?;
+    println!(
+        "[IRIS] LDA : part de la dispersion inter-classes par direction : {:.4}",
+        lda.explained_variance_ratio()
+    );
+    println!(
+        "[IRIS] LDA : directions discriminantes (en colonnes) :\n{:.3}",
+        lda.scalings()
+    );
+    plot_projection(&projected, &y, "plots/lda_projection_iris.html")?;
+
+    Ok(())
+}
+
+/// Observations projetées sur les deux directions discriminantes, colorées par espèce
+fn plot_projection(
+    projected: &Array2<f64>,
+    y: &Array1<usize>,
+    filename: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut plot = Plot::new();
+    add_class_scatter(&mut plot, projected, y, &IRIS_SPECIES);
+    plot.set_layout(
+        Layout::new()
+            .title("LDA d'iris : projection sur les deux directions discriminantes")
+            .x_axis(PlotAxis::new().title("LD1"))
+            .y_axis(PlotAxis::new().title("LD2")),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+/*
+DATA MANAGEMENT
+*/
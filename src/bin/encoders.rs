@@ -0,0 +1,110 @@
+use std::error::Error;
+
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2, Axis, array, concatenate};
+
+use rust_first_steps::data::{load_categorical, load_features, load_iris};
+use rust_first_steps::linear_model::{add_bias_column, linear_regression_svd, predict_linear};
+use rust_first_steps::metrics::r2_score;
+use rust_first_steps::preprocessing::{HandleUnknown, LabelEncoder, OneHotEncoder, OrdinalEncoder};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    labels()?;
+    one_hot()?;
+    ordinal()?;
+    Ok(())
+}
+
+/// Classes apprises sur les données, puis retour aux noms à partir des indices
+fn labels() -> Result<(), Box<dyn Error>> {
+    let (_, species) = load_iris("data/iris.csv")?;
+    let mut encoder = LabelEncoder::new();
+    let y = encoder.fit_transform(&species)?;
+    println!("[Label] Classes apprises : {:?}", encoder.classes()?);
+    println!(
+        "[Label] Lignes 0, 50 et 100 : {} -> {:?}",
+        y.select(Axis(0), &[0, 50, 100]),
+        encoder
+            .inverse_transform(&y.select(Axis(0), &[0, 50, 100]))?
+            .to_vec()
+    );
+    // Une classe jamais vue renvoie une erreur au lieu de paniquer
+    if let Err(e) = encoder.transform(["Rose"]) {
+        println!("[Label] transform([\"Rose\"]) : {}", e);
+    }
+    Ok(())
+}
+
+/// L'espèce en one-hot, comme features supplémentaires d'une régression linéaire
+fn one_hot() -> Result<(), Box<dyn Error>> {
+    let x = load_features("data/iris.csv", &["petal_length"])?;
+    let y = load_features("data/iris.csv", &["petal_width"])?
+        .column(0)
+        .to_owned();
+    let species = load_categorical("data/iris.csv", &["species"])?;
+
+    let mut encoder = OneHotEncoder::new().with_handle_unknown(HandleUnknown::Ignore);
+    let one_hot = encoder.fit_transform(&species)?;
+    println!(
+        "\n[One-hot] Colonnes ajoutées : {:?}",
+        encoder.feature_names(&["species"])?
+    );
+
+    // L'intercept de la colonne de biais est redondant avec la somme des indicatrices :
+    // la SVD renvoie alors la solution de norme minimale
+    let with_species = concatenate![Axis(1), x, one_hot];
+    for (name, features) in [("petal_length seule", &x), ("+ espèce", &with_species)] {
+        let r2 = fit_r2(features, &y)?;
+        println!("[One-hot] R² de petal_width ~ {} : {:.3}", name, r2);
+    }
+
+    // Une espèce inconnue donne une ligne de zéros : seule petal_length compte
+    let unseen = array![["Setosa".to_string()], ["Rose".to_string()]];
+    println!(
+        "[One-hot] Setosa et Rose encodées :\n{}",
+        encoder.transform(&unseen)?
+    );
+    Ok(())
+}
+
+/// Catégories ordonnées : la longueur des pétales découpée en trois niveaux
+fn ordinal() -> Result<(), Box<dyn Error>> {
+    let x = load_features("data/iris.csv", &["petal_length"])?;
+    let levels: Array2<String> = x.mapv(|length| {
+        match length {
+            l if l < 2.5 => "court",
+            l if l < 5.0 => "moyen",
+            _ => "long",
+        }
+        .to_string()
+    });
+
+    let order = ["court", "moyen", "long"].map(String::from).to_vec();
+    let mut encoder = OrdinalEncoder::new()
+        .with_categories(vec![order])
+        .with_handle_unknown(HandleUnknown::Ignore);
+    let encoded = encoder.fit_transform(&levels)?;
+    println!(
+        "\n[Ordinal] Lignes 0, 50 et 100 : {:?} -> {}",
+        levels.column(0).select(Axis(0), &[0, 50, 100]).to_vec(),
+        encoded.column(0).select(Axis(0), &[0, 50, 100])
+    );
+    // Une catégorie inconnue est encodée NaN, à compléter avec un imputer
+    let unseen = array![["moyen".to_string()], ["géant".to_string()]];
+    println!(
+        "[Ordinal] moyen et géant encodés : {}",
+        encoder.transform(&unseen)?.column(0)
+    );
+    Ok(())
+}
+
+fn fit_r2(x: &Array2<f64>, y: &Array1<f64>) -> Result<f64, Box<dyn Error>> {
+    let x = add_bias_column(&DMatrix::from_row_iterator(
+        x.nrows(),
+        x.ncols(),
+        x.iter().copied(),
+    ));
+    let theta = linear_regression_svd(&x, &DVector::from_iterator(y.len(), y.iter().copied()))?;
+    let y_pred: Array1<f64> = predict_linear(&x, &theta)?.iter().copied().collect();
+    Ok(r2_score(y, &y_pred)?)
+}
@@ -1,48 +1,59 @@
+use nalgebra::{DMatrix, DVector};
 use ndarray::Array1;
 
-fn main() {
+use rust_first_steps::distance::{
+    Chebyshev, Cosine, Distance, Euclidean, Hamming, Mahalanobis, Manhattan, Minkowski,
+    SquaredEuclidean, euclidean_distance,
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Here we'll calculate the Euclidean distance between two points in 2D space.");
     let a = vec![1.0, 2.0, 3.0];
     let b = vec![4.0, 6.0, 3.0];
 
-    let distance = euclidean_distance(&a, &b);
+    let distance = euclidean_distance(&a, &b)?;
     println!("Euclidean distance with slices : {}", distance);
 
-    // Utilisation de ndarray
+    // Utilisation de ndarray : la même métrique fonctionne directement sur des Array1
     let a_nd = Array1::from_vec(a.clone()); // on clone a pour éviter de consommer `a`
     let b_nd = Array1::from_vec(b.clone()); // on clone b pour éviter de consommer `b`
-    let distance_nd = euclidean_distance_nd(&a_nd, &b_nd);
+    let distance_nd = Euclidean.distance(&a_nd, &b_nd)?;
     println!("Euclidean distance with ndarray : {}", distance_nd);
-}
 
-/// Calcule la distance euclidienne entre deux vecteurs de même taille en utilisant les slices.
-/// 
-/// # Arguments
-/// * `x` - Le premier vecteur, qui est une reférence à un slice de f64.
-/// * `y` - Le second vecteur, qui est une référence à un slice de f64.
-/// 
-/// # Returns
-/// * La distance euclidienne entre les deux vecteurs.
-///
-/// # Example
-/// ```
-/// let x = vec![1.0, 2.0];
-/// let y = vec![4.0, 6.0];
-/// let dist = euclidean_distance(&x, &y);
-/// assert!((dist - 5.0).abs() < 1e-6);
-/// ```
-fn euclidean_distance(x: &[f64], y: &[f64]) -> f64 { // &[f64] = slice = un vecteur dont on connaît la taille à l’exécution
-    assert_eq!(x.len(), y.len(), "Les vecteurs doivent avoir la même taille !");
-    let sum_squared_diff: f64 = x.iter() // .iter() = itère sur les éléments du vecteur
-        .zip(y.iter()) // .zip() = associe les éléments des deux vecteurs
-        .map(|(xi, yi)| (xi - yi).powi(2))  // powi(2) = carré d’un f64
-        .sum();
-
-    sum_squared_diff.sqrt()
-}
+    // Et sur des DVector de nalgebra
+    let a_na = DVector::from_vec(a.clone());
+    let b_na = DVector::from_vec(b.clone());
+    let distance_na = Euclidean.distance(&a_na, &b_na)?;
+    println!("Euclidean distance with nalgebra : {}", distance_na);
+
+    // Les autres métriques partagent le même trait `Distance`
+    println!("Squared Euclidean : {}", SquaredEuclidean.distance(&a, &b)?);
+    println!("Manhattan : {}", Manhattan.distance(&a, &b)?);
+    println!("Chebyshev : {}", Chebyshev.distance(&a, &b)?);
+    println!(
+        "Minkowski (p = 3) : {}",
+        Minkowski::new(3.0)?.distance(&a, &b)?
+    );
+    println!("Cosine : {}", Cosine.distance(&a, &b)?);
+    println!("Hamming : {}", Hamming.distance(&a, &b)?);
 
-/// Calcule la distance euclidienne entre deux vecteurs de même taille en utilisant ndarray.
-fn euclidean_distance_nd(x: &Array1<f64>, y: &Array1<f64>) -> f64 {
-    ((x - y).mapv(|v| v.powi(2))).sum().sqrt()  // mapv = applique une fonction à chaque élément du tableau (pas la copie de l'élément)
-    // ou (x - y).mapv(|v| v.powi(2)).scalar_sum().sqrt()
-}
\ No newline at end of file
+    // Mahalanobis a besoin de la covariance des données : on l'estime sur quelques points
+    #[rustfmt::skip]
+    let data = DMatrix::from_row_slice(4, 3, &[
+        1.0, 2.0, 3.0,
+        4.0, 6.0, 3.5,
+        2.0, 1.0, 2.0,
+        3.0, 5.0, 4.0,
+    ]);
+    let mahalanobis = Mahalanobis::from_data(&data)?;
+    println!("Mahalanobis : {}", mahalanobis.distance(&a, &b)?);
+
+    // Une différence de taille renvoie une erreur au lieu de paniquer
+    let c = vec![1.0, 2.0];
+    match Euclidean.distance(&a, &c) {
+        Ok(d) => println!("Distance inattendue : {}", d),
+        Err(e) => println!("Erreur attendue : {}", e),
+    }
+
+    Ok(())
+}
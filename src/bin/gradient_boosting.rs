@@ -0,0 +1,160 @@
+use std::error::Error;
+
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2, Axis};
+
+use rust_first_steps::data::{load_diabetes, load_housing};
+use rust_first_steps::ensemble::{
+    GradientBoostingClassifier, GradientBoostingRegressor, RegressionLoss,
+};
+use rust_first_steps::linear_model::{add_bias_column, linear_regression_svd, predict_linear};
+use rust_first_steps::metrics::{accuracy, mean_squared_error, r2_score};
+use rust_first_steps::model_selection::train_test_split;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    housing()?;
+    diabetes()?;
+    Ok(())
+}
+
+/// Régression du prix : gradient boosting contre la régression linéaire par SVD
+fn housing() -> Result<(), Box<dyn Error>> {
+    let (x, y) = load_housing("data/housing.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    // Référence : moindres carrés avec biais, résolus par pseudo-inverse
+    let theta = linear_regression_svd(
+        &add_bias_column(&to_dmatrix(&x_train)),
+        &DVector::from_iterator(y_train.len(), y_train.iter().copied()),
+    )?;
+    let y_linear = predict_linear(&add_bias_column(&to_dmatrix(&x_test)), &theta)?;
+    let y_linear = Array1::from_iter(y_linear.iter().copied());
+    println!(
+        "[HOUSING] Régression linéaire SVD : R² = {:.3}, MSE = {:.3e}",
+        r2_score(&y_test, &y_linear)?,
+        mean_squared_error(&y_test, &y_linear)?
+    );
+
+    // Le prix est presque linéaire en (area, distance) : les arbres approchent la droite
+    // par paliers, d'où des arbres peu profonds et beaucoup d'itérations
+    let losses = [
+        ("squared_error", RegressionLoss::SquaredError),
+        ("absolute_error", RegressionLoss::AbsoluteError),
+        ("huber", RegressionLoss::Huber { delta: 1e4 }),
+    ];
+    for (name, loss) in losses {
+        let mut model = GradientBoostingRegressor::new()
+            .with_loss(loss)
+            .with_n_estimators(500)
+            .with_max_depth(2)
+            .with_min_samples_leaf(3)
+            .with_random_state(42);
+        model.fit(&x_train, &y_train)?;
+        let y_pred = model.predict(&x_test)?;
+        println!(
+            "[HOUSING] Gradient boosting ({}) : R² = {:.3}, MSE = {:.3e}",
+            name,
+            r2_score(&y_test, &y_pred)?,
+            mean_squared_error(&y_test, &y_pred)?
+        );
+    }
+
+    // Boosting stochastique + arrêt précoce : on s'arrête quand la validation ne progresse plus
+    let mut model = GradientBoostingRegressor::new()
+        .with_n_estimators(2000)
+        .with_max_depth(2)
+        .with_min_samples_leaf(3)
+        .with_subsample(0.8)
+        .with_early_stopping(20, 0.1)
+        .with_random_state(42);
+    model.fit(&x_train, &y_train)?;
+    let y_pred = model.predict(&x_test)?;
+    println!(
+        "[HOUSING] Subsample 0.8 + arrêt précoce : {} arbres conservés, R² = {:.3}",
+        model.n_trees(),
+        r2_score(&y_test, &y_pred)?
+    );
+
+    Ok(())
+}
+
+/// Classification du diabète : gradient boosting contre la régression logistique
+fn diabetes() -> Result<(), Box<dyn Error>> {
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let y_logistic = logistic_regression(&x_train, &y_train, &x_test);
+    println!(
+        "[DIABETES] Régression logistique : accuracy = {:.3}",
+        accuracy(&y_test, &y_logistic)?
+    );
+
+    let mut model = GradientBoostingClassifier::new()
+        .with_n_estimators(200)
+        .with_learning_rate(0.05)
+        .with_max_depth(2)
+        .with_subsample(0.8)
+        .with_random_state(42);
+    model.fit(&x_train, &y_train)?;
+    println!(
+        "[DIABETES] Gradient boosting : accuracy = {:.3}, log-loss entraînement = {:.3}",
+        accuracy(&y_test, &model.predict(&x_test)?)?,
+        model.train_loss()[model.n_trees() - 1]
+    );
+
+    // Même modèle avec arrêt précoce sur 15 % des données d'entraînement
+    let mut early = GradientBoostingClassifier::new()
+        .with_n_estimators(1000)
+        .with_learning_rate(0.05)
+        .with_max_depth(2)
+        .with_subsample(0.8)
+        .with_early_stopping(30, 0.15)
+        .with_random_state(42);
+    early.fit(&x_train, &y_train)?;
+    println!(
+        "[DIABETES] Arrêt précoce : {} arbres conservés, accuracy = {:.3}",
+        early.n_trees(),
+        accuracy(&y_test, &early.predict(&x_test)?)?
+    );
+
+    Ok(())
+}
+
+/*
+BASELINES
+*/
+
+// Le module linear_model travaille avec nalgebra
+fn to_dmatrix(x: &Array2<f64>) -> DMatrix<f64> {
+    DMatrix::from_row_iterator(x.nrows(), x.ncols(), x.iter().copied())
+}
+
+// Régression logistique par descente de gradient sur les features standardisées
+fn logistic_regression(
+    x_train: &Array2<f64>,
+    y_train: &Array1<usize>,
+    x_test: &Array2<f64>,
+) -> Array1<usize> {
+    let mean = x_train
+        .mean_axis(Axis(0))
+        .expect("jeu d'entraînement non vide");
+    let std = x_train.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    let x_train = (x_train - &mean) / &std;
+    let x_test = (x_test - &mean) / &std;
+    let y = y_train.mapv(|c| c as f64);
+    let n = x_train.nrows() as f64;
+
+    let mut w = Array1::<f64>::zeros(x_train.ncols());
+    let mut b = 0.0;
+    for _ in 0..2000 {
+        let p = (x_train.dot(&w) + b).mapv(|z| 1.0 / (1.0 + (-z).exp()));
+        let error = p - &y;
+        w = w - 0.1 * x_train.t().dot(&error) / n;
+        b -= 0.1 * error.sum() / n;
+    }
+    (x_test.dot(&w) + b).mapv(|z| usize::from(z > 0.0))
+}
+
+/*
+DATA MANAGEMENT
+*/
@@ -1,39 +1,39 @@
 use std::error::Error;
-use std::fs::File;
 use std::path::Path;
 
-use csv::ReaderBuilder;
-use serde::Deserialize;
+use ndarray::s;
 
-use ndarray::{Array2, s};
-use nalgebra::DMatrix;
-
-use polars::prelude::*;
-
-// Représente une ligne du fichier CSV avec les bons noms de colonnes
-#[derive(Debug, Deserialize)]
-struct IrisRow {
-    sepal_length: f64,
-    sepal_width: f64,
-    petal_length: f64,
-    petal_width: f64,
-    species: String,
-}
+use rust_first_steps::data::{
+    IRIS_FEATURES, load_csv_nalgebra, load_csv_ndarray, load_csv_polars, read_headers,
+};
+use rust_first_steps::describe::{describe_dataframe, format_table, write_csv, write_json};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let path = Path::new("data/iris.csv");
 
-    // Chargement avec ndarray
-    let (data_nd, labels_nd) = load_csv_ndarray(path)?;
+    println!("[CSV] Colonnes : {:?}", read_headers(path)?);
+
+    // Chargement avec ndarray : les colonnes sont choisies par leur nom dans l'en-tête
+    let (data_nd, labels_nd) = load_csv_ndarray::<String>(path, &IRIS_FEATURES, "species")?;
     println!("[NDARRAY] Shape : {:?}", data_nd.dim());
     println!("[NDARRAY] Premières lignes :\n{:?}", data_nd.slice(s![..5, ..]));
-    println!("[NDARRAY] Labels : {:?}", &labels_nd[..5]);
+    println!("[NDARRAY] Labels : {:?}", labels_nd.slice(s![..5]));
 
     // Chargement avec nalgebra
-    let (data_na, labels_na) = load_csv_nalgebra(path)?;
+    let (data_na, labels_na) = load_csv_nalgebra::<String>(path, &IRIS_FEATURES, "species")?;
     println!("[NALGEBRA] Shape : {} lignes × {} colonnes", data_na.nrows(), data_na.ncols());
     println!("[NALGEBRA] Premières lignes :\n{}", data_na.rows(0, 5));
-    println!("[NALGEBRA] Labels : {:?}", &labels_na[..5]);
+    println!("[NALGEBRA] Labels : {:?}", &labels_na.as_slice()[..5]);
+
+    // Le même chargeur lit n'importe quel fichier, avec une cible numérique
+    let (data_diabetes, outcome) =
+        load_csv_ndarray::<usize>("data/diabetes.csv", &["glucose", "bmi", "age"], "outcome")?;
+    println!("[DIABETES] Shape : {:?}", data_diabetes.dim());
+    println!(
+        "[DIABETES] Premières lignes :\n{:?}",
+        data_diabetes.slice(s![..5, ..])
+    );
+    println!("[DIABETES] Outcome : {:?}", outcome.slice(s![..5]));
 
     // Chargement avec Polars
     let df = load_csv_polars("data/iris.csv")?;
@@ -41,68 +41,15 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("[POLARS] Colonnes : {:?}", df.get_column_names());
     println!("[POLARS] Premières lignes :\n{}", df.head(Some(5)));
 
-    Ok(())
-}
-
-fn load_csv_ndarray(path: &Path) -> Result<(Array2<f64>, Vec<String>), Box<dyn Error>> {
-    let file = File::open(path)?;
-    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
-
-    let mut features = Vec::new();
-    let mut labels = Vec::new();
-    let mut n_rows = 0;
-
-    for result in reader.deserialize::<IrisRow>() {
-        let row = result?;
-        features.extend_from_slice(&[
-            row.sepal_length,
-            row.sepal_width,
-            row.petal_length,
-            row.petal_width,
-        ]);
-        labels.push(row.species);
-        n_rows += 1;
+    // Résumé statistique de chaque jeu de données, exporté en JSON et CSV
+    std::fs::create_dir_all("output")?;
+    for name in ["iris", "housing", "diabetes"] {
+        let df = load_csv_polars(format!("data/{}.csv", name))?;
+        let summaries = describe_dataframe(&df)?;
+        println!("\n[DESCRIBE] {} :\n{}", name, format_table(&summaries));
+        write_json(&summaries, format!("output/describe_{}.json", name))?;
+        write_csv(&summaries, format!("output/describe_{}.csv", name))?;
     }
 
-    let data = Array2::from_shape_vec((n_rows, 4), features)?;
-    Ok((data, labels))
-}
-
-fn load_csv_nalgebra(path: &Path) -> Result<(DMatrix<f64>, Vec<String>), Box<dyn Error>> {
-    let file = File::open(path)?;
-    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
-
-    let mut features = Vec::new();
-    let mut labels = Vec::new();
-    let mut n_rows = 0;
-
-    for result in reader.deserialize::<IrisRow>() {
-        let row = result?;
-        features.extend_from_slice(&[
-            row.sepal_length,
-            row.sepal_width,
-            row.petal_length,
-            row.petal_width,
-        ]);
-        labels.push(row.species);
-        n_rows += 1;
-    }
-
-    let data = DMatrix::from_vec(n_rows, 4, features);
-    Ok((data, labels))
-}
-
-/// Charge un fichier CSV en un DataFrame Polars
-///
-/// # Arguments
-/// * `path` - Le chemin vers le fichier CSV
-///
-/// # Returns
-/// * `DataFrame` contenant les données
-pub fn load_csv_polars(path: &str) -> PolarsResult<DataFrame> {
-    use std::path::PathBuf;
-    CsvReadOptions::default()
-        .with_has_header(true)
-        .try_into_reader_with_file_path(Some(PathBuf::from(path)))?
-        .finish()
+    Ok(())
 }
@@ -0,0 +1,164 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2, Axis};
+
+use rust_first_steps::data::{DIABETES_FEATURES, load_csv_ndarray, load_diabetes};
+use rust_first_steps::discriminant_analysis::LinearDiscriminantAnalysis;
+use rust_first_steps::impute::{ImputeStrategy, KnnImputer, SimpleImputer};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::naive_bayes::{GaussianNB, NaiveBayes};
+
+// Dans diabetes, un 0 dans ces colonnes signifie « non mesuré » (une glycémie ou un IMC nuls sont impossibles)
+const ZERO_AS_MISSING: [&str; 5] = [
+    "glucose",
+    "blood_pressure",
+    "skin_thickness",
+    "insulin",
+    "bmi",
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    empty_cells()?;
+    diabetes()?;
+    Ok(())
+}
+
+/// Les cellules vides d'un CSV sont lues comme NaN puis imputées
+fn empty_cells() -> Result<(), Box<dyn Error>> {
+    let path = std::env::temp_dir().join("rust_first_steps_missing.csv");
+    std::fs::write(
+        &path,
+        "height,weight,label\n1.70,65,a\n1.82,,b\n,58,a\n1.65,NA,a\n1.90,92,b\n",
+    )?;
+    let (x, _) = load_csv_ndarray::<String>(&path, &["height", "weight"], "label")?;
+    println!("[CSV] Données lues (NaN = cellule vide) :\n{}", x);
+
+    let mut imputer = SimpleImputer::new()
+        .with_strategy(ImputeStrategy::Median)
+        .with_add_indicator(true);
+    println!(
+        "[CSV] Médianes + indicatrices (height manquant, weight manquant) :\n{}",
+        imputer.fit_transform(&x)?
+    );
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+fn diabetes() -> Result<(), Box<dyn Error>> {
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let zero_columns: Vec<usize> = ZERO_AS_MISSING
+        .iter()
+        .map(|name| {
+            DIABETES_FEATURES
+                .iter()
+                .position(|f| f == name)
+                .expect("colonne de diabetes")
+        })
+        .collect();
+    for &j in &zero_columns {
+        let n_zero = x.column(j).iter().filter(|&&v| v == 0.0).count();
+        println!(
+            "[DIABETES] {:<16} : {:>3} zéros ({:.1} %)",
+            DIABETES_FEATURES[j],
+            n_zero,
+            100.0 * n_zero as f64 / x.nrows() as f64
+        );
+    }
+
+    // Les imputeurs sont ajustés sur le jeu d'entraînement seulement
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let simple = |strategy: ImputeStrategy, add_indicator: bool| {
+        zero_columns.iter().fold(
+            SimpleImputer::new()
+                .with_strategy(strategy)
+                .with_add_indicator(add_indicator),
+            |imputer, &j| imputer.with_sentinel(j, 0.0),
+        )
+    };
+
+    let mut variants: Vec<(&str, Array2<f64>, Array2<f64>)> =
+        vec![("zéros conservés", x_train.clone(), x_test.clone())];
+    for (name, strategy, add_indicator) in [
+        ("moyenne", ImputeStrategy::Mean, false),
+        ("médiane", ImputeStrategy::Median, false),
+        (
+            "valeur la plus fréquente",
+            ImputeStrategy::MostFrequent,
+            false,
+        ),
+        ("médiane + indicatrices", ImputeStrategy::Median, true),
+    ] {
+        let mut imputer = simple(strategy, add_indicator);
+        let train = imputer.fit_transform(&x_train)?;
+        if add_indicator {
+            println!(
+                "[DIABETES] Indicatrices ajoutées pour : {:?}",
+                imputer
+                    .indicator_features()
+                    .iter()
+                    .map(|&j| DIABETES_FEATURES[j])
+                    .collect::<Vec<_>>()
+            );
+        } else {
+            println!(
+                "[DIABETES] Valeurs de remplacement ({}) : {:.2}",
+                name,
+                imputer.statistics()?
+            );
+        }
+        variants.push((name, train, imputer.transform(&x_test)?));
+    }
+
+    // KNN : les features sont standardisées (sans les valeurs manquantes) pour que
+    // l'insuline, de l'ordre de 100, n'écrase pas les autres dans la distance
+    let (train_std, test_std, mean, std) = standardize_observed(&x_train, &x_test, &zero_columns);
+    let mut knn = KnnImputer::new(5);
+    let train = knn.fit_transform(&train_std)? * &std + &mean;
+    let test = knn.transform(&test_std)? * &std + &mean;
+    variants.push(("KNN (k = 5)", train, test));
+
+    println!("\n{:<28} {:>12} {:>10}", "Imputation", "Naive Bayes", "LDA");
+    for (name, train, test) in &variants {
+        let mut nb = GaussianNB::new();
+        nb.fit(train, &y_train)?;
+        let mut lda = LinearDiscriminantAnalysis::new();
+        lda.fit(train, &y_train)?;
+        println!(
+            "{:<28} {:>12.3} {:>10.3}",
+            name,
+            accuracy(&y_test, &nb.predict(test)?)?,
+            accuracy(&y_test, &lda.predict(test)?)?
+        );
+    }
+    Ok(())
+}
+
+// Centre et réduit chaque feature en ignorant les zéros des colonnes où ils sont
+// des valeurs manquantes ; ces zéros deviennent NaN pour le KnnImputer
+fn standardize_observed(
+    x_train: &Array2<f64>,
+    x_test: &Array2<f64>,
+    zero_columns: &[usize],
+) -> (Array2<f64>, Array2<f64>, Array1<f64>, Array1<f64>) {
+    let to_nan = |x: &Array2<f64>| {
+        let mut x = x.clone();
+        for &j in zero_columns {
+            x.column_mut(j)
+                .mapv_inplace(|v| if v == 0.0 { f64::NAN } else { v });
+        }
+        x
+    };
+    let (train, test) = (to_nan(x_train), to_nan(x_test));
+    let mut mean = Array1::zeros(train.ncols());
+    let mut std = Array1::zeros(train.ncols());
+    for (j, column) in train.axis_iter(Axis(1)).enumerate() {
+        let observed: Vec<f64> = column.iter().copied().filter(|v| !v.is_nan()).collect();
+        let n = observed.len() as f64;
+        mean[j] = observed.iter().sum::<f64>() / n;
+        std[j] = (observed.iter().map(|v| (v - mean[j]).powi(2)).sum::<f64>() / n)
+            .sqrt()
+            .max(1e-12);
+    }
+    ((train - &mean) / &std, (test - &mean) / &std, mean, std)
+}
@@ -1,102 +1,95 @@
 use std::error::Error;
-use std::fs::File;
-use std::path::Path;
-use serde::Deserialize;
-use csv::ReaderBuilder;
-use nalgebra::{DMatrix, DVector};
-use polars::prelude::*;
-use plotly::{Plot, Scatter};
-use plotly::common::Mode;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let path = Path::new("data/iris.csv");
+use ndarray::{Array2, Axis, s};
+use plotly::Plot;
+use plotly::layout::{Axis as PlotAxis, Layout};
+
+use rust_first_steps::cluster::KMeans;
+use rust_first_steps::data::{Dataset, IRIS_SPECIES, load_csv_polars, load_iris};
+use rust_first_steps::error::ModelError;
+use rust_first_steps::plot::{add_class_scatter, save_html};
+use rust_first_steps::preprocessing::LabelEncoder;
+use rust_first_steps::traits::{Fit, Score};
 
+fn main() -> Result<(), Box<dyn Error>> {
     // 🔍 Analyse avec Polars
-    let df = load_csv_polars(path.to_str().unwrap())?;
+    let df = load_csv_polars("data/iris.csv")?;
     println!("[POLARS] Premières lignes :\n{}", df.head(Some(5)));
 
-    // 📈 Entraînement avec nalgebra
-    let (data, labels): (DMatrix<f64>, Vec<String>) = load_csv_nalgebra(&path)?;
-    // println!("[NALGEBRA] Dimensions de la matrice : {}x{}", data.nrows(), data.ncols());
-    // afficher les premières lignes
-    print_head(&data, 5);
-    // afficher les labels
-    // println!("[NALGEBRA] Labels : {:?}", &labels[..5]);
-    // Encode les labels en valeurs numériques
-    let y = encode_labels(&labels);
-
-    Ok(())
-}
-
-/*
-MODEL
-*/
-
-// Encode les labels de l'iris en valeurs numériques
-fn encode_labels(labels: &[String]) -> DVector<f64> {
-    DVector::from_iterator(
-        labels.len(),
-        labels.iter().map(|label| match label.as_str() {
-            "Setosa" => 0.0,
-            "Versicolor" => 1.0,
-            "Virginica" => 2.0,
-            _ => panic!("Label inconnu"),
-        }),
-    )
-}
+    // 📈 Entraînement sur les 4 mesures, sans utiliser les espèces
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
 
-/*
-DATA MANAGEMENT
-*/
+    let mut kmeans = KMeans::new(3).with_random_state(42);
+    let clusters = kmeans.fit_predict(&x)?;
+    println!(
+        "[KMEANS] Inertie : {:.3} ({} itérations)",
+        kmeans.inertia()?,
+        kmeans.n_iter()
+    );
+    println!("[KMEANS] Centroïdes :\n{:.3}", kmeans.centroids()?);
 
-// Représente une ligne du fichier CSV avec les bons noms de colonnes
-#[derive(Debug, Deserialize)]
-struct IrisRow {
-    sepal_length: f64,
-    sepal_width: f64,
-    petal_length: f64,
-    petal_width: f64,
-    species: String,
-}
-
-/// Charge un fichier CSV en un DataFrame Polars
-fn load_csv_polars(path: &str) -> PolarsResult<DataFrame> {
-    use std::path::PathBuf;
-    CsvReadOptions::default()
-        .with_has_header(true)
-        .try_into_reader_with_file_path(Some(PathBuf::from(path)))?
-        .finish()
-}
-
-/// Charge un fichier CSV en une matrice nalgebra et un vecteur de labels
-fn load_csv_nalgebra(path: &Path) -> Result<(DMatrix<f64>, Vec<String>), Box<dyn Error>> {
-    let file = File::open(path)?;
-    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(file);
+    // Les numéros de cluster sont arbitraires : on compare la partition aux espèces
+    // avec une table de contingence (une ligne par espèce, une colonne par cluster)
+    let mut contingency = Array2::<usize>::zeros((IRIS_SPECIES.len(), 3));
+    for (&species, &cluster) in y.iter().zip(&clusters) {
+        contingency[[species, cluster]] += 1;
+    }
+    println!("[KMEANS] Espèces × clusters :\n{}", contingency);
+    let purity = contingency
+        .axis_iter(Axis(1))
+        .map(|column| column.iter().copied().max().unwrap_or(0))
+        .sum::<usize>() as f64
+        / y.len() as f64;
+    println!("[KMEANS] Pureté : {:.3}", purity);
 
-    let mut features = Vec::new();
-    let mut labels = Vec::new();
-    let mut n_rows = 0;
+    // Distances de chaque fleur aux 3 centroïdes : de nouvelles features
+    println!(
+        "[KMEANS] Distances aux centroïdes (3 premières fleurs) :\n{:.3}",
+        kmeans.transform(&x.slice(s![..3, ..]).to_owned())?
+    );
 
-    for result in reader.deserialize::<IrisRow>() {
-        let row = result?;
-        features.extend_from_slice(&[
-            row.sepal_length,
-            row.sepal_width,
-            row.petal_length,
-            row.petal_width,
-        ]);
-        labels.push(row.species);
-        n_rows += 1;
+    // Méthode du coude : inertie en fonction du nombre de clusters, et score
+    // (opposé de l'inertie) sur des fleurs non vues pendant l'entraînement
+    let (train, test) = Dataset::unlabeled(x.clone()).train_test_split(0.3, 42);
+    for k in 1..=6 {
+        let mut model = KMeans::new(k).with_random_state(42);
+        let score = holdout_score(&mut model, &train, &test)?;
+        println!(
+            "[COUDE] k = {} : inertie = {:.3}, score test = {:.3}",
+            k,
+            model.inertia()?,
+            score
+        );
     }
 
-    let data = DMatrix::from_vec(4, n_rows, features).transpose();
-    Ok((data, labels))
+    // Clusters trouvés, sur la longueur et la largeur des pétales
+    let petals = x.select(Axis(1), &[2, 3]);
+    let mut plot = Plot::new();
+    add_class_scatter(
+        &mut plot,
+        &petals,
+        &clusters,
+        &["Cluster 0", "Cluster 1", "Cluster 2"],
+    );
+    plot.set_layout(
+        Layout::new()
+            .title("K-means sur iris (k = 3), projeté sur les pétales")
+            .x_axis(PlotAxis::new().title("petal_length"))
+            .y_axis(PlotAxis::new().title("petal_width")),
+    );
+    save_html(&plot, "plots/kmeans_iris.html")?;
+
+    Ok(())
 }
 
-/// Affiche les `n` premières lignes d'une matrice
-fn print_head(data: &DMatrix<f64>, n: usize) {
-    println!("Premières lignes ({} premières) :", n);
-    for i in 0..n.min(data.nrows()) {
-        print!("{}", data.row(i));
-    }
+// Entraîne sur `train` puis évalue sur `test` ; accepte tout modèle qui implémente
+// les traits communs, ici k-means sans cible (score = opposé de l'inertie)
+fn holdout_score<T, M: Fit<T> + Score<T>>(
+    model: &mut M,
+    train: &Dataset<T>,
+    test: &Dataset<T>,
+) -> Result<f64, ModelError> {
+    model.fit(train)?;
+    model.score(test)
 }
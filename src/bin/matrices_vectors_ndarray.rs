@@ -1,6 +1,10 @@
-use ndarray::{array, Array1, Array2, s};
+// Ce binaire n'utilise pas la bibliothèque : il doit lier lui-même l'implémentation BLAS
+// dont ndarray a besoin pour `dot`
+extern crate blas_src;
+
+use ndarray::{Array1, Array2, array, s};
 use ndarray_rand::RandomExt;
-use ndarray_rand::rand_distr::{Uniform};
+use ndarray_rand::rand_distr::Uniform;
 
 fn main() {
     // Créer un vecteur 1D (Array1)
@@ -9,8 +13,7 @@ fn main() {
     println!("Vecteur v = {}", v);
 
     // Créer une matrice 2D (Array2)
-    let m: Array2<f64> = array![[1.0, 0.0, 2.0],
-                                [0.0, 3.0, -1.0]];
+    let m: Array2<f64> = array![[1.0, 0.0, 2.0], [0.0, 3.0, -1.0]];
     println!("Matrice m =\n{}", m);
 
     // Accès à un élément
@@ -67,8 +70,7 @@ fn main() {
     println!("Matrice transposée (copie) :\n{}", m_transpose_copy);
 }
 
-
 fn create_random_uniform_array2(dimension: usize, size: usize, low: f64, high: f64) -> Array2<f64> {
     // Crée un tableau 2D de taille (dimension, size) avec des valeurs uniformément distribuées entre low et high
     Array2::random((dimension, size), Uniform::new(low, high))
-}
\ No newline at end of file
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+
+use ndarray::{Array2, Axis};
+use plotly::common::{DashType, Line, Mode};
+use plotly::layout::{Axis as PlotAxis, Layout};
+use plotly::{Plot, Scatter};
+
+use rust_first_steps::data::{load_diabetes, load_iris};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::neural_network::{Activation, MlpClassifier, Optimizer, TrainingHistory};
+use rust_first_steps::plot::save_html;
+use rust_first_steps::preprocessing::LabelEncoder;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // --- Iris : 4 features -> 16 neurones ReLU -> 3 sorties softmax
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+
+    let mut iris_mlp = MlpClassifier::new(&[16])
+        .with_optimizer(Optimizer::adam(0.01))
+        .with_batch_size(16)
+        .with_max_epochs(150)
+        .with_validation_fraction(0.2)
+        .with_random_state(42);
+    iris_mlp.fit(&x_train, &y_train)?;
+    println!(
+        "[IRIS] MLP [16] ReLU + Adam : accuracy test = {:.3}",
+        accuracy(&y_test, &iris_mlp.predict(&x_test)?)?
+    );
+
+    // --- Diabetes : comparaison des optimiseurs et des activations
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+
+    let configs = [
+        ("ReLU + Adam", Activation::Relu, Optimizer::adam(1e-3)),
+        ("ReLU + SGD", Activation::Relu, Optimizer::sgd(0.01, 0.0)),
+        (
+            "ReLU + SGD momentum",
+            Activation::Relu,
+            Optimizer::sgd(0.01, 0.9),
+        ),
+        ("tanh + Adam", Activation::Tanh, Optimizer::adam(1e-3)),
+        ("sigmoid + Adam", Activation::Sigmoid, Optimizer::adam(1e-3)),
+        (
+            "leaky ReLU + Adam",
+            Activation::LeakyRelu { alpha: 0.01 },
+            Optimizer::adam(1e-3),
+        ),
+    ];
+    let mut diabetes_histories = Vec::new();
+    for (name, activation, optimizer) in configs {
+        let mut mlp = MlpClassifier::new(&[32, 16])
+            .with_activation(activation)
+            .with_optimizer(optimizer)
+            .with_max_epochs(100)
+            .with_validation_fraction(0.2)
+            .with_random_state(42);
+        mlp.fit(&x_train, &y_train)?;
+        println!(
+            "[DIABETES] MLP [32, 16] {:<20} : accuracy test = {:.3}",
+            name,
+            accuracy(&y_test, &mlp.predict(&x_test)?)?
+        );
+        diabetes_histories.push((name, mlp.history().clone()));
+    }
+
+    // Courbes d'iris et des deux optimiseurs ReLU sur diabetes
+    let mut histories = vec![("Iris".to_string(), iris_mlp.history().clone())];
+    for (name, history) in diabetes_histories {
+        if matches!(name, "ReLU + Adam" | "ReLU + SGD momentum") {
+            histories.push((format!("Diabetes {}", name), history));
+        }
+    }
+    plot_curves(
+        &histories,
+        |h| (&h.loss, &h.validation_loss),
+        "Entropie croisée",
+        "plots/mlp_loss_curves.html",
+    )?;
+    plot_curves(
+        &histories,
+        |h| (&h.score, &h.validation_score),
+        "Accuracy",
+        "plots/mlp_accuracy_curves.html",
+    )?;
+
+    Ok(())
+}
+
+/// Trace une courbe par jeu de données (trait plein : entraînement, pointillés : validation)
+fn plot_curves(
+    histories: &[(String, TrainingHistory)],
+    metric: impl Fn(&TrainingHistory) -> (&Vec<f64>, &Vec<f64>),
+    title: &str,
+    filename: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut plot = Plot::new();
+    for (name, history) in histories {
+        let (train, validation) = metric(history);
+        let epochs: Vec<usize> = (1..=train.len()).collect();
+        plot.add_trace(
+            Scatter::new(epochs.clone(), train.clone())
+                .mode(Mode::Lines)
+                .name(format!("{} - entraînement", name)),
+        );
+        plot.add_trace(
+            Scatter::new(epochs, validation.clone())
+                .mode(Mode::Lines)
+                .line(Line::new().dash(DashType::Dash))
+                .name(format!("{} - validation", name)),
+        );
+    }
+    plot.set_layout(
+        Layout::new()
+            .title(format!("MLP : {} par époque", title))
+            .x_axis(PlotAxis::new().title("Époque"))
+            .y_axis(PlotAxis::new().title(title)),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+// Centre et réduit les features avec les statistiques du jeu d'entraînement
+fn standardize(x_train: &Array2<f64>, x_test: &Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let mean = x_train
+        .mean_axis(Axis(0))
+        .expect("jeu d'entraînement non vide");
+    let std = x_train.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    ((x_train - &mean) / &std, (x_test - &mean) / &std)
+}
+
+/*
+DATA MANAGEMENT
+*/
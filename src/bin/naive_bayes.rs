@@ -0,0 +1,90 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2, Axis, s};
+
+use rust_first_steps::data::{load_diabetes, load_iris};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::naive_bayes::{BernoulliNB, GaussianNB, MultinomialNB, NaiveBayes};
+use rust_first_steps::preprocessing::LabelEncoder;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // --- Iris : 4 features continues, 3 espèces
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut gaussian = GaussianNB::new();
+    gaussian.fit(&x_train, &y_train)?;
+    let y_pred = gaussian.predict(&x_test)?;
+    println!(
+        "[IRIS] GaussianNB accuracy : {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+    println!("[IRIS] Moyennes par classe :\n{:.2}", gaussian.theta());
+    // Probabilités des 3 premières fleurs de test (une colonne par espèce)
+    let proba = gaussian.predict_proba(&x_test)?;
+    println!(
+        "[IRIS] predict_proba (3 premières lignes) :\n{:.3}",
+        proba.slice(s![..3, ..])
+    );
+
+    // --- Diabetes : 8 features positives, 2 classes (outcome)
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    let mut gaussian = GaussianNB::new();
+    gaussian.fit(&x_train, &y_train)?;
+    let y_pred = gaussian.predict(&x_test)?;
+    println!(
+        "[DIABETES] GaussianNB accuracy : {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+
+    // Priors imposés : on suppose les deux issues équiprobables
+    let mut gaussian_uniform = GaussianNB::new().with_priors(vec![0.5, 0.5]);
+    gaussian_uniform.fit(&x_train, &y_train)?;
+    let y_pred = gaussian_uniform.predict(&x_test)?;
+    println!(
+        "[DIABETES] GaussianNB (priors 0.5/0.5) accuracy : {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+
+    // Toutes les features sont positives : on peut les traiter comme des "comptages"
+    let mut multinomial = MultinomialNB::new();
+    multinomial.fit(&x_train, &y_train)?;
+    let y_pred = multinomial.predict(&x_test)?;
+    println!(
+        "[DIABETES] MultinomialNB accuracy : {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+
+    // Bernoulli : chaque feature devient "au-dessus / en dessous de la moyenne d'entraînement"
+    let means = x_train
+        .mean_axis(Axis(0))
+        .expect("jeu d'entraînement non vide");
+    let x_train_bin = binarize_with(&x_train, &means);
+    let x_test_bin = binarize_with(&x_test, &means);
+    let mut bernoulli = BernoulliNB::new().with_binarize(None);
+    bernoulli.fit(&x_train_bin, &y_train)?;
+    let y_pred = bernoulli.predict(&x_test_bin)?;
+    println!(
+        "[DIABETES] BernoulliNB accuracy : {:.3}",
+        accuracy(&y_test, &y_pred)?
+    );
+
+    Ok(())
+}
+
+/*
+DATA MANAGEMENT
+*/
+
+// Remplace chaque valeur par 1 si elle dépasse le seuil de sa colonne, 0 sinon
+fn binarize_with(x: &Array2<f64>, thresholds: &Array1<f64>) -> Array2<f64> {
+    let mut binary = x.clone();
+    for mut row in binary.rows_mut() {
+        row.zip_mut_with(thresholds, |v, &t| *v = if *v > t { 1.0 } else { 0.0 });
+    }
+    binary
+}
@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+use plotly::layout::{Axis, Layout};
+use plotly::{HeatMap, Plot};
+
+use rust_first_steps::data::load_iris;
+use rust_first_steps::distance::{Euclidean, Manhattan, pairwise_distances};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (data, labels) = load_iris("data/iris.csv")?;
+
+    // On trie les observations par espèce pour voir apparaître des blocs sur la heatmap
+    let (data, labels) = sort_by_species(&data, &labels);
+
+    // Matrice 150×150 des distances euclidiennes (calculée avec le produit matriciel BLAS)
+    let distances = pairwise_distances(&data, &data, &Euclidean)?;
+    println!("[EUCLIDEAN] Shape : {:?}", distances.dim());
+    println!(
+        "[EUCLIDEAN] Distance max : {:.3}",
+        distances.fold(0.0_f64, |acc, &d| acc.max(d))
+    );
+
+    // Les autres métriques passent par le calcul générique, ligne par ligne en parallèle
+    let manhattan = pairwise_distances(&data, &data, &Manhattan)?;
+    println!(
+        "[MANHATTAN] Distance max : {:.3}",
+        manhattan.fold(0.0_f64, |acc, &d| acc.max(d))
+    );
+
+    plot_distance_heatmap(&distances, &labels, "plots/iris_distance_heatmap.html");
+
+    Ok(())
+}
+
+/// Réordonne les lignes de la matrice pour regrouper les observations de même espèce
+fn sort_by_species(data: &Array2<f64>, labels: &Array1<String>) -> (Array2<f64>, Vec<String>) {
+    let mut order: Vec<usize> = (0..labels.len()).collect();
+    order.sort_by(|&i, &j| labels[i].cmp(&labels[j])); // tri stable : l'ordre d'origine est conservé dans chaque espèce
+
+    let sorted_data = data.select(ndarray::Axis(0), &order);
+    let sorted_labels = order.iter().map(|&i| labels[i].clone()).collect();
+    (sorted_data, sorted_labels)
+}
+
+/// Affiche la matrice de distances sous forme de heatmap
+fn plot_distance_heatmap(distances: &Array2<f64>, labels: &[String], filename: &str) {
+    // Plotly attend les données sous forme de Vec<Vec<f64>> (une ligne par vecteur)
+    let z: Vec<Vec<f64>> = distances
+        .rows()
+        .into_iter()
+        .map(|row| row.to_vec())
+        .collect();
+    // On numérote les observations pour que deux fleurs de la même espèce restent distinctes sur les axes
+    let names: Vec<String> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| format!("{} #{}", label, i))
+        .collect();
+
+    let trace = HeatMap::new(names.clone(), names, z).name("Distance euclidienne");
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(
+        Layout::new()
+            .title("Distances euclidiennes entre les iris (triées par espèce)")
+            .x_axis(Axis::new().title("Observation"))
+            .y_axis(Axis::new().title("Observation")),
+    );
+
+    std::fs::create_dir_all("plots").expect("Impossible de créer le dossier plots");
+    plot.write_html(filename);
+}
@@ -0,0 +1,150 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2, Axis};
+use plotly::common::{Line, Marker, Mode, Position};
+use plotly::layout::{Axis as PlotAxis, Layout};
+use plotly::{Bar, Plot, Scatter};
+
+use rust_first_steps::data::{IRIS_FEATURES, IRIS_SPECIES, load_iris};
+use rust_first_steps::decomposition::{NComponents, Pca};
+use rust_first_steps::plot::save_html;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (x, species) = load_iris("data/iris.csv")?;
+    // Features standardisées : sinon petal_length, la plus dispersée, domine la première composante
+    let x = standardize(&x);
+
+    let mut pca = Pca::new();
+    let projected = pca.fit_transform(&x)?;
+    let ratio = pca.explained_variance_ratio()?;
+    println!("[IRIS] Variance expliquée par composante : {:.3}", ratio);
+    println!(
+        "[IRIS] Composantes (une par ligne) :\n{:.3}",
+        pca.components()?
+    );
+
+    // Nombre de composantes choisi par la part de variance à conserver
+    let mut pca_95 = Pca::new().with_n_components(NComponents::VarianceRatio(0.95));
+    pca_95.fit(&x)?;
+    println!(
+        "[IRIS] {} composantes suffisent pour 95 % de la variance",
+        pca_95.n_components()
+    );
+
+    // Reconstruction à partir de 2 composantes seulement
+    let mut pca_2 = Pca::new().with_n_components(NComponents::Count(2));
+    let reduced = pca_2.fit_transform(&x)?;
+    let reconstructed = pca_2.inverse_transform(&reduced)?;
+    let error = (&x - &reconstructed).mapv(|v| v * v).mean().unwrap_or(0.0);
+    println!(
+        "[IRIS] Erreur quadratique moyenne de reconstruction (2 composantes) : {:.4}",
+        error
+    );
+
+    // Blanchiment : les composantes projetées sont de variance 1
+    let mut whitened = Pca::new().with_whiten(true);
+    let white = whitened.fit_transform(&x)?;
+    println!(
+        "[IRIS] Variances après blanchiment : {:.3}",
+        white.var_axis(Axis(0), 1.0)
+    );
+
+    plot_scree(ratio, "plots/pca_scree_iris.html")?;
+    plot_biplot(
+        &projected,
+        &species,
+        pca.components()?,
+        "plots/pca_biplot_iris.html",
+    )?;
+
+    Ok(())
+}
+
+/// Variance expliquée par composante (barres) et cumulée (courbe)
+fn plot_scree(ratio: &Array1<f64>, filename: &str) -> Result<(), Box<dyn Error>> {
+    let names: Vec<String> = (1..=ratio.len()).map(|k| format!("PC{}", k)).collect();
+    let cumulated: Vec<f64> = ratio
+        .iter()
+        .scan(0.0, |acc, &r| {
+            *acc += r;
+            Some(*acc)
+        })
+        .collect();
+
+    let mut plot = Plot::new();
+    plot.add_trace(Bar::new(names.clone(), ratio.to_vec()).name("Variance expliquée"));
+    plot.add_trace(
+        Scatter::new(names, cumulated)
+            .mode(Mode::LinesMarkers)
+            .name("Variance cumulée"),
+    );
+    plot.set_layout(
+        Layout::new()
+            .title("ACP d'iris : éboulis des valeurs propres")
+            .x_axis(PlotAxis::new().title("Composante"))
+            .y_axis(
+                PlotAxis::new()
+                    .title("Part de la variance")
+                    .range(vec![0.0, 1.05]),
+            ),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+/// Observations projetées sur (PC1, PC2) et contributions des features (flèches)
+fn plot_biplot(
+    projected: &Array2<f64>,
+    species: &Array1<String>,
+    components: &Array2<f64>,
+    filename: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut plot = Plot::new();
+    for name in IRIS_SPECIES {
+        let rows: Vec<usize> = (0..species.len()).filter(|&i| species[i] == name).collect();
+        plot.add_trace(
+            Scatter::new(
+                rows.iter().map(|&i| projected[[i, 0]]).collect(),
+                rows.iter().map(|&i| projected[[i, 1]]).collect(),
+            )
+            .mode(Mode::Markers)
+            .name(name)
+            .marker(Marker::new().size(7).opacity(0.7)),
+        );
+    }
+
+    // Les coefficients sont entre -1 et 1 : on les agrandit pour les voir à l'échelle des points
+    let scale = 3.0;
+    for (j, feature) in IRIS_FEATURES.iter().enumerate() {
+        let (dx, dy) = (scale * components[[0, j]], scale * components[[1, j]]);
+        plot.add_trace(
+            Scatter::new(vec![0.0, dx], vec![0.0, dy])
+                .mode(Mode::LinesText)
+                .text_array(vec!["".to_string(), feature.to_string()])
+                .text_position(Position::TopCenter)
+                .line(Line::new().color("black").width(2.0))
+                .show_legend(false),
+        );
+    }
+    plot.set_layout(
+        Layout::new()
+            .title("ACP d'iris : biplot des deux premières composantes")
+            .x_axis(PlotAxis::new().title("PC1"))
+            .y_axis(PlotAxis::new().title("PC2")),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+// Centre et réduit chaque feature
+fn standardize(x: &Array2<f64>) -> Array2<f64> {
+    let mean = x.mean_axis(Axis(0)).expect("jeu de données non vide");
+    let std = x.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    (x - &mean) / &std
+}
+
+/*
+DATA MANAGEMENT
+*/
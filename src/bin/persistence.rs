@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::path::Path;
+
+use nalgebra::DVector;
+
+use rust_first_steps::cluster::KMeans;
+use rust_first_steps::data::{
+    HOUSING_FEATURES, IRIS_FEATURES, load_csv_nalgebra, load_housing, load_iris,
+};
+use rust_first_steps::ensemble::RandomForestClassifier;
+use rust_first_steps::linear_model::{add_bias_column, linear_regression_svd, predict_linear};
+use rust_first_steps::persistence::SavedModel;
+use rust_first_steps::preprocessing::{LabelEncoder, StandardScaler};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    random_forest()?;
+    kmeans()?;
+    linear_regression()?;
+    errors()?;
+    Ok(())
+}
+
+/// Même forêt en JSON et en binaire : le modèle relu prédit exactement la même chose
+fn random_forest() -> Result<(), Box<dyn Error>> {
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let mut forest = RandomForestClassifier::new().with_random_state(42);
+    forest.fit(&x, &y)?;
+
+    let saved = SavedModel::new(forest, &IRIS_FEATURES).with_target("species");
+    for path in [
+        "models/random_forest_iris.json",
+        "models/random_forest_iris.bin",
+    ] {
+        saved.save(path)?;
+        let loaded = SavedModel::<RandomForestClassifier>::load(path)?;
+        let identical = loaded.model.predict_proba(&x)? == saved.model.predict_proba(&x)?;
+        println!(
+            "[Forêt] {} ({}) : {:?}, features {:?}, prédictions identiques : {}",
+            path,
+            file_size(path)?,
+            loaded.metadata.model_type,
+            loaded.metadata.feature_names,
+            identical
+        );
+    }
+    Ok(())
+}
+
+/// Centroïdes et scaler sauvegardés ensemble, dans un tuple
+fn kmeans() -> Result<(), Box<dyn Error>> {
+    let (x, _) = load_iris("data/iris.csv")?;
+    let mut scaler = StandardScaler::new();
+    let mut kmeans = KMeans::new(3).with_random_state(42);
+    let clusters = kmeans.fit_predict(&scaler.fit_transform(&x)?)?;
+
+    let path = "models/kmeans_iris.bin";
+    SavedModel::new((scaler, kmeans), &IRIS_FEATURES).save(path)?;
+    let (scaler, kmeans) = SavedModel::<(StandardScaler, KMeans)>::load(path)?.model;
+    let reloaded = kmeans.predict(&scaler.transform(&x)?)?;
+    println!(
+        "\n[K-means] {} ({}) : centroïdes relus\n{:.3}\n[K-means] Mêmes clusters : {}",
+        path,
+        file_size(path)?,
+        kmeans.centroids()?,
+        reloaded == clusters
+    );
+    Ok(())
+}
+
+/// Le vecteur θ de la régression linéaire se sauvegarde comme n'importe quel modèle
+fn linear_regression() -> Result<(), Box<dyn Error>> {
+    let (x, y) = load_csv_nalgebra::<f64>("data/housing.csv", &HOUSING_FEATURES, "price")?;
+    let x = add_bias_column(&x);
+    let theta = linear_regression_svd(&x, &y)?;
+
+    let path = "models/linear_housing.json";
+    SavedModel::new(theta.clone(), &HOUSING_FEATURES)
+        .with_target("price")
+        .save(path)?;
+    let loaded = SavedModel::<DVector<f64>>::load(path)?;
+    let (_, prices) = load_housing("data/housing.csv")?;
+    let y_pred = predict_linear(&x, &loaded.model)?;
+    println!(
+        "\n[Linéaire] {} : θ = {:?}, cible {:?}, 1er prix {:.0} (prédit {:.0})",
+        path,
+        loaded.model.as_slice(),
+        loaded.metadata.target,
+        prices[0],
+        y_pred[0]
+    );
+    Ok(())
+}
+
+/// Un mauvais type de modèle ou un fichier qui n'est pas un modèle sont refusés
+fn errors() -> Result<(), Box<dyn Error>> {
+    match SavedModel::<KMeans>::load("models/random_forest_iris.bin") {
+        Err(e) => println!("\n[Erreur] Forêt relue comme un KMeans : {}", e),
+        Ok(_) => println!("\n[Erreur] La forêt a été relue comme un KMeans !"),
+    }
+    match SavedModel::<KMeans>::load("data/iris.csv") {
+        Err(e) => println!("[Erreur] iris.csv relu comme un modèle : {}", e),
+        Ok(_) => println!("[Erreur] iris.csv a été relu comme un modèle !"),
+    }
+    Ok(())
+}
+
+fn file_size(path: impl AsRef<Path>) -> std::io::Result<String> {
+    let bytes = std::fs::metadata(path)?.len();
+    Ok(format!("{:.1} Ko", bytes as f64 / 1024.0))
+}
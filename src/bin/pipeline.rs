@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use rust_first_steps::data::{DIABETES_FEATURES, HOUSING_FEATURES, load_dataset};
+use rust_first_steps::impute::{ImputeStrategy, SimpleImputer};
+use rust_first_steps::linear_model::LinearRegression;
+use rust_first_steps::metrics::{accuracy, r2_score};
+use rust_first_steps::model_selection::{KFold, cross_val_score};
+use rust_first_steps::persistence::SavedModel;
+use rust_first_steps::pipeline::{Pipeline, Step};
+use rust_first_steps::preprocessing::{BiasColumn, PolynomialFeatures, StandardScaler};
+use rust_first_steps::svm::SvmClassifier;
+use rust_first_steps::traits::{Fit, Score};
+
+// Dans diabetes, un 0 dans ces colonnes signifie « non mesuré »
+const ZERO_AS_MISSING: [&str; 5] = [
+    "glucose",
+    "blood_pressure",
+    "skin_thickness",
+    "insulin",
+    "bmi",
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    polynomial_regression()?;
+    diabetes()?;
+    Ok(())
+}
+
+/// Régression polynomiale sur housing : scaler, features polynomiales, biais puis moindres carrés
+fn polynomial_regression() -> Result<(), Box<dyn Error>> {
+    let housing = load_dataset("data/housing.csv", &HOUSING_FEATURES, "price")?;
+    let cv = KFold::new(5).with_shuffle(true).with_random_state(42);
+
+    for degree in 1..=4 {
+        let pipeline = Pipeline::new(LinearRegression::new().with_fit_intercept(false))
+            .with_step(StandardScaler::new())
+            .with_step(PolynomialFeatures::new(degree))
+            .with_step(BiasColumn);
+        let scores = cross_val_score(&pipeline, &housing, &cv, r2_score)?;
+        println!(
+            "[HOUSING] Degré {} : R² par pli {:.4}, moyenne {:.4}",
+            degree,
+            scores,
+            scores.mean().unwrap_or(f64::NAN)
+        );
+    }
+
+    let mut poly = PolynomialFeatures::new(2);
+    poly.fit(housing.x())?;
+    println!(
+        "[HOUSING] Features de degré 2 : {:?}",
+        poly.feature_names(&HOUSING_FEATURES)?
+    );
+    Ok(())
+}
+
+fn diabetes() -> Result<(), Box<dyn Error>> {
+    let diabetes = load_dataset("data/diabetes.csv", &DIABETES_FEATURES, "outcome")?;
+    let imputer = ZERO_AS_MISSING.iter().fold(
+        SimpleImputer::new().with_strategy(ImputeStrategy::Median),
+        |imputer, name| {
+            let j = DIABETES_FEATURES
+                .iter()
+                .position(|f| f == name)
+                .expect("colonne de diabetes");
+            imputer.with_sentinel(j, 0.0)
+        },
+    );
+    let pipeline = Pipeline::new(SvmClassifier::new())
+        .with_step(imputer.clone())
+        .with_step(StandardScaler::new());
+    let cv = KFold::new(5).with_shuffle(true).with_random_state(42);
+
+    // Pipeline : imputer et scaler sont réappris sur les plis d'entraînement seulement
+    let scores = cross_val_score(&pipeline, &diabetes, &cv, accuracy::<usize>)?;
+    println!(
+        "[DIABETES] Pipeline (imputation + scaler + SVM) : accuracy par pli {:.3}, moyenne {:.3}",
+        scores,
+        scores.mean().unwrap_or(f64::NAN)
+    );
+
+    // Fuite : médianes et moyennes calculées sur tout le jeu, plis de test compris.
+    // L'écart est faible ici, mais le score n'estime plus l'erreur sur des données nouvelles
+    let mut leaky_steps = [Step::from(imputer), Step::from(StandardScaler::new())];
+    let mut x_leaky = diabetes.x().clone();
+    for step in &mut leaky_steps {
+        x_leaky = step.fit_transform(&x_leaky)?;
+    }
+    let leaky = cross_val_score(
+        &SvmClassifier::new(),
+        &diabetes.clone().with_x(x_leaky)?,
+        &cv,
+        accuracy::<usize>,
+    )?;
+    println!(
+        "[DIABETES] Prétraitement appris sur tout le jeu (fuite) : accuracy moyenne {:.3}",
+        leaky.mean().unwrap_or(f64::NAN)
+    );
+
+    // Le pipeline entraîné se sauvegarde en un seul fichier, prétraitement compris
+    let mut pipeline = pipeline;
+    pipeline.fit(&diabetes)?;
+    let path = "models/svm_pipeline_diabetes.json";
+    SavedModel::new(pipeline, &DIABETES_FEATURES)
+        .with_target("outcome")
+        .save(path)?;
+    let loaded = SavedModel::<Pipeline<SvmClassifier>>::load(path)?;
+    println!(
+        "[DIABETES] Pipeline rechargé depuis {} ({} étapes + {}), accuracy d'entraînement {:.3}",
+        path,
+        loaded.model.steps().len(),
+        loaded.metadata.model_type,
+        loaded.model.score(&diabetes)?
+    );
+    Ok(())
+}
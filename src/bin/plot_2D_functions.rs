@@ -1,56 +1,76 @@
-use plotly::common::Mode;
+use plotly::common::{DashType, Line, Mode};
+use plotly::layout::{Axis, Layout};
 use plotly::{Plot, Scatter};
-use plotly::layout::{Layout, Axis};
 use std::process::Command;
 
-/// Fonction d'activation ReLU
-/// ReLU (Rectified Linear Unit) est définie comme f(x) = max(0, x)
-/// Elle est souvent utilisée dans les réseaux de neurones pour introduire de la non-linéarité
-/// et permettre aux modèles d'apprendre des relations complexes dans les données.
-fn relu(x: f64) -> f64 {
-    x.max(0.0)
-}
+use rust_first_steps::neural_network::Activation;
 
-fn main() {
-    // On crée un vecteur de f64 contenant les valeurs de -5.0 à 5.0, espacées de 0.5
-    // -10..=10 -> crée un itérateur de -10 à 10 inclus (-10, -9, -8, ..., 0, ..., 9, 10) ce sont des i32 !
-    // la méthode collect() transforme cet itérateur en un vecteur Vec<i32>, cela consomme l'itérateur
-    let x_vals: Vec<f64> = (-10..=10).map(|x| x as f64 * 0.5).collect();
+// Les fonctions d'activation tracées, avec leur nom et une couleur commune à f et f'.
+// Softmax n'est pas tracée : elle normalise une ligne entière et n'a pas de courbe scalaire
+const ACTIVATIONS: [(&str, Activation, &str); 8] = [
+    ("ReLU", Activation::Relu, "#1f77b4"),
+    (
+        "Leaky ReLU (α = 0.1)",
+        Activation::LeakyRelu { alpha: 0.1 },
+        "#ff7f0e",
+    ),
+    ("ELU (α = 1)", Activation::Elu { alpha: 1.0 }, "#2ca02c"),
+    ("GELU", Activation::Gelu, "#d62728"),
+    ("SiLU", Activation::Silu, "#9467bd"),
+    ("Sigmoid", Activation::Sigmoid, "#8c564b"),
+    ("Tanh", Activation::Tanh, "#e377c2"),
+    ("Softplus", Activation::Softplus, "#7f7f7f"),
+];
 
-    // On applique la fonction ReLU et Sigmoid à chaque valeur de x_vals
-    // iter() crée un itérateur sur les références des éléments de x_vals, donc itération sur des &f64
-    // x_vals est juste emprunté ici, pas consommé
-    let y_vals_relu: Vec<f64> = x_vals.iter().map(|&x| relu(x)).collect();
-    let y_vals_sigmoid: Vec<f64> = x_vals.iter().map(|&x| 1.0 / (1.0 + (-x).exp())).collect();
+fn main() {
+    // On crée un vecteur de f64 contenant les valeurs de -5.0 à 5.0, espacées de 0.05
+    // -100..=100 -> crée un itérateur de -100 à 100 inclus, ce sont des i32 !
+    // la méthode collect() transforme cet itérateur en un vecteur Vec<f64>, cela consomme l'itérateur
+    let x_vals: Vec<f64> = (-100..=100).map(|x| x as f64 * 0.05).collect();
 
     // On crée un nouveau graphique Plotly
     let mut plot = Plot::new();
 
-    // On crée un graphique de type Scatter
-    // On passe un clone de x_vals car Plotly n'accepte pas les références (les données doivent être possédées)
-    // y_vals_relu n'est pas cloné car on ne l'utilise qu'une seule fois
-    let trace1 = Scatter::new(x_vals.clone(), y_vals_relu)
-        .mode(Mode::Lines)
-        .name("ReLU");
+    for (name, activation, color) in ACTIVATIONS {
+        // On applique la fonction et sa dérivée à chaque valeur de x_vals
+        // iter() crée un itérateur sur les références des éléments de x_vals, donc itération sur des &f64
+        // value() et derivative() renvoient None pour softmax seulement, absente de la liste
+        let y_vals: Vec<f64> = x_vals.iter().filter_map(|&x| activation.value(x)).collect();
+        let dy_vals: Vec<f64> = x_vals
+            .iter()
+            .filter_map(|&x| activation.derivative(x))
+            .collect();
 
-    let trace2 = Scatter::new(x_vals.clone(), y_vals_sigmoid)
-        .mode(Mode::Lines)
-        .name("Sigmoid");
+        // La fonction en trait plein, sa dérivée en pointillés de la même couleur
+        // legend_group permet de masquer les deux courbes d'un seul clic dans la légende
+        // On passe un clone de x_vals car Plotly n'accepte pas les références (les données doivent être possédées)
+        let function = Scatter::new(x_vals.clone(), y_vals)
+            .mode(Mode::Lines)
+            .name(name)
+            .legend_group(name)
+            .line(Line::new().color(color));
+        let derivative = Scatter::new(x_vals.clone(), dy_vals)
+            .mode(Mode::Lines)
+            .name(format!("{} (dérivée)", name))
+            .legend_group(name)
+            .line(Line::new().color(color).dash(DashType::Dash));
 
-    // On ajoute les traces au graphique
-    plot.add_trace(trace1);
-    plot.add_trace(trace2);
+        // On ajoute les traces au graphique
+        plot.add_trace(function);
+        plot.add_trace(derivative);
+    }
 
     // Configuration de la mise en page du graphique
     plot.set_layout(
         Layout::new()
-            .title("Fonctions d'activation")
+            .title("Fonctions d'activation (trait plein) et leurs dérivées (pointillés)")
             .x_axis(Axis::new().title("x"))
-            .y_axis(Axis::new().title("f(x)")),
+            .y_axis(Axis::new().title("f(x), f'(x)").range(vec![-1.5, 3.0])),
     );
 
     // On crée un fichier HTML contenant le graphique
-    plot.write_html("plots/relu_plot.html");
+    std::fs::create_dir_all("plots").expect("Impossible de créer le dossier plots");
+    plot.write_html("plots/activation_functions.html");
 
     // On ouvre le fichier HTML dans le navigateur par défaut
     // Command permet d'exécuter une commande système, ici open
@@ -58,8 +78,7 @@ fn main() {
     // status() exécute la commande et retourne un résultat
     // expect() permet de gérer l'erreur si la commande échoue
     Command::new("open")
-        .arg("plots/relu_plot.html")
+        .arg("plots/activation_functions.html")
         .status()
         .expect("Échec de l'ouverture du fichier HTML");
-
-}
\ No newline at end of file
+}
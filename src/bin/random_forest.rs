@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use rust_first_steps::data::{DIABETES_FEATURES, load_diabetes};
+use rust_first_steps::ensemble::{MaxFeatures, RandomForestClassifier};
+use rust_first_steps::metrics::accuracy;
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::tree::DecisionTreeClassifier;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+
+    // Référence : un seul arbre, sans limite de profondeur (il sur-apprend)
+    let mut tree = DecisionTreeClassifier::new();
+    tree.fit(&x_train, &y_train)?;
+    println!(
+        "[ARBRE SEUL] Accuracy test : {:.3}",
+        accuracy(&y_test, &tree.predict(&x_test)?)?
+    );
+
+    // Forêt de 200 arbres, √8 ≈ 2 features candidates par coupure
+    let mut forest = RandomForestClassifier::new()
+        .with_n_estimators(200)
+        .with_min_samples_leaf(2)
+        .with_oob_score(true)
+        .with_random_state(42);
+    forest.fit(&x_train, &y_train)?;
+    println!(
+        "[FORÊT] Accuracy test : {:.3}",
+        accuracy(&y_test, &forest.predict(&x_test)?)?
+    );
+    // Le score OOB estime l'accuracy sans toucher au jeu de test
+    if let Some(oob) = forest.oob_score() {
+        println!("[FORÊT] Accuracy out-of-bag : {:.3}", oob);
+    }
+
+    // Avec toutes les features à chaque coupure, on retombe sur du bagging simple
+    let mut bagging = RandomForestClassifier::new()
+        .with_n_estimators(200)
+        .with_max_features(MaxFeatures::All)
+        .with_oob_score(true)
+        .with_random_state(42);
+    bagging.fit(&x_train, &y_train)?;
+    println!(
+        "[BAGGING] Accuracy test : {:.3}",
+        accuracy(&y_test, &bagging.predict(&x_test)?)?
+    );
+
+    // Importances : diminution d'impureté (entraînement) vs permutation (test)
+    let impurity = forest.feature_importances()?;
+    let permutation = forest.permutation_importances(&x_test, &y_test, 10, 42)?;
+    println!("{:<28} {:>10} {:>12}", "Feature", "Impureté", "Permutation");
+    for (j, name) in DIABETES_FEATURES.iter().enumerate() {
+        println!(
+            "{:<28} {:>10.3} {:>12.3}",
+            name, impurity[j], permutation[j]
+        );
+    }
+
+    Ok(())
+}
+
+/*
+DATA MANAGEMENT
+*/
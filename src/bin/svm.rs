@@ -0,0 +1,187 @@
+use std::error::Error;
+
+use ndarray::{Array1, Array2, Axis};
+use plotly::common::{Marker, Mode};
+use plotly::layout::{Axis as PlotAxis, Layout};
+use plotly::{Plot, Scatter};
+
+use rust_first_steps::data::{IRIS_SPECIES, load_diabetes, load_housing, load_iris};
+use rust_first_steps::metrics::{accuracy, r2_score};
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::plot::save_html;
+use rust_first_steps::preprocessing::LabelEncoder;
+use rust_first_steps::svm::{Kernel, SvmClassifier, SvmRegressor};
+
+const SPECIES_COLORS: [&str; 3] = ["#1f77b4", "#ff7f0e", "#2ca02c"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let kernels = [
+        ("linéaire", Kernel::Linear),
+        (
+            "polynomial",
+            Kernel::Polynomial {
+                degree: 3,
+                gamma: 0.25,
+                coef0: 1.0,
+            },
+        ),
+        ("rbf", Kernel::Rbf { gamma: 0.25 }),
+        (
+            "sigmoïde",
+            Kernel::Sigmoid {
+                gamma: 0.05,
+                coef0: 0.0,
+            },
+        ),
+    ];
+
+    // --- Iris : 3 espèces, donc 3 SVM un-contre-un par modèle (features standardisées)
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+    for (name, kernel) in kernels {
+        let mut svm = SvmClassifier::new().with_kernel(kernel);
+        svm.fit(&x_train, &y_train)?;
+        println!(
+            "[IRIS] Noyau {:<10} : accuracy = {:.3}, vecteurs supports par paire = {:?}",
+            name,
+            accuracy(&y_test, &svm.predict(&x_test)?)?,
+            svm.n_support()
+        );
+    }
+
+    // --- Diabetes : les features ont des échelles très différentes, on les standardise
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+    for (name, kernel) in kernels {
+        let mut svm = SvmClassifier::new().with_kernel(kernel);
+        svm.fit(&x_train, &y_train)?;
+        println!(
+            "[DIABETES] Noyau {:<10} : accuracy = {:.3} ({} itérations SMO)",
+            name,
+            accuracy(&y_test, &svm.predict(&x_test)?)?,
+            svm.n_iter()
+        );
+    }
+
+    // --- Housing : ε-SVR sur features et prix standardisés
+    let (x, y) = load_housing("data/housing.csv")?;
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x, &y, 0.3, 42);
+    let (x_train, x_test) = standardize(&x_train, &x_test);
+    let (y_mean, y_std) = (y_train.mean().unwrap_or(0.0), y_train.std(0.0));
+    let y_train_scaled = y_train.mapv(|v| (v - y_mean) / y_std);
+    for (name, kernel) in [
+        ("linéaire", Kernel::Linear),
+        ("rbf", Kernel::Rbf { gamma: 0.1 }),
+    ] {
+        let mut svr = SvmRegressor::new()
+            .with_kernel(kernel)
+            .with_c(10.0)
+            .with_epsilon(0.05);
+        svr.fit(&x_train, &y_train_scaled)?;
+        let y_pred = svr.predict(&x_test)?.mapv(|v| v * y_std + y_mean);
+        println!(
+            "[HOUSING] ε-SVR noyau {:<8} : R² = {:.3}, {} vecteurs supports",
+            name,
+            r2_score(&y_test, &y_pred)?,
+            svr.n_support()
+        );
+    }
+
+    // --- Frontière de décision sur deux features d'iris (longueur et largeur des pétales)
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let petals = x.select(Axis(1), &[2, 3]);
+    let mut svm = SvmClassifier::new()
+        .with_kernel(Kernel::Rbf { gamma: 0.5 })
+        .with_c(10.0);
+    svm.fit(&petals, &y)?;
+    println!(
+        "[IRIS PÉTALES] accuracy d'entraînement = {:.3}",
+        accuracy(&y, &svm.predict(&petals)?)?
+    );
+    plot_decision_boundary(&svm, &petals, &y, "plots/svm_decision_boundary_iris.html")?;
+
+    Ok(())
+}
+
+/// Colore une grille fine selon la classe prédite, puis superpose les observations
+fn plot_decision_boundary(
+    svm: &SvmClassifier,
+    x: &Array2<f64>,
+    y: &Array1<usize>,
+    filename: &str,
+) -> Result<(), Box<dyn Error>> {
+    let resolution = 150;
+    let bounds = |j: usize| {
+        let column = x.column(j);
+        let min = column.fold(f64::INFINITY, |acc, &v| acc.min(v)) - 0.5;
+        let max = column.fold(f64::NEG_INFINITY, |acc, &v| acc.max(v)) + 0.5;
+        (min, max)
+    };
+    let ((x_min, x_max), (y_min, y_max)) = (bounds(0), bounds(1));
+    let grid = Array2::from_shape_fn((resolution * resolution, 2), |(k, j)| {
+        let (min, max, step) = if j == 0 {
+            (x_min, x_max, k % resolution)
+        } else {
+            (y_min, y_max, k / resolution)
+        };
+        min + (max - min) * step as f64 / (resolution - 1) as f64
+    });
+    let grid_pred = svm.predict(&grid)?;
+
+    let mut plot = Plot::new();
+    for (class, name) in IRIS_SPECIES.iter().enumerate() {
+        // Zone de décision : petits points semi-transparents
+        let region: Vec<usize> = (0..grid.nrows())
+            .filter(|&k| grid_pred[k] == class)
+            .collect();
+        let trace = Scatter::new(
+            region.iter().map(|&k| grid[[k, 0]]).collect(),
+            region.iter().map(|&k| grid[[k, 1]]).collect(),
+        )
+        .mode(Mode::Markers)
+        .name(format!("Zone {}", name))
+        .marker(
+            Marker::new()
+                .size(4)
+                .opacity(0.15)
+                .color(SPECIES_COLORS[class]),
+        );
+        plot.add_trace(trace);
+
+        let points: Vec<usize> = (0..x.nrows()).filter(|&i| y[i] == class).collect();
+        let trace = Scatter::new(
+            points.iter().map(|&i| x[[i, 0]]).collect(),
+            points.iter().map(|&i| x[[i, 1]]).collect(),
+        )
+        .mode(Mode::Markers)
+        .name(*name)
+        .marker(Marker::new().size(8).color(SPECIES_COLORS[class]));
+        plot.add_trace(trace);
+    }
+    plot.set_layout(
+        Layout::new()
+            .title("SVM (noyau RBF) : frontières de décision sur les pétales d'iris")
+            .x_axis(PlotAxis::new().title("petal_length"))
+            .y_axis(PlotAxis::new().title("petal_width")),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+// Centre et réduit les features avec les statistiques du jeu d'entraînement
+fn standardize(x_train: &Array2<f64>, x_test: &Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let mean = x_train
+        .mean_axis(Axis(0))
+        .expect("jeu d'entraînement non vide");
+    let std = x_train.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    ((x_train - &mean) / &std, (x_test - &mean) / &std)
+}
+
+/*
+DATA MANAGEMENT
+*/
@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::time::Instant;
+
+use ndarray::{Array1, Array2, Axis};
+
+use plotly::Plot;
+use plotly::layout::{Axis as PlotAxis, Layout};
+
+use rust_first_steps::data::{IRIS_SPECIES, load_diabetes, load_iris};
+use rust_first_steps::manifold::Tsne;
+use rust_first_steps::plot::{add_class_scatter, save_html};
+use rust_first_steps::preprocessing::LabelEncoder;
+
+const DIABETES_OUTCOMES: [&str; 2] = ["Non diabétique", "Diabétique"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // --- Iris : 4 features, 150 fleurs
+    let (x, species) = load_iris("data/iris.csv")?;
+    let y = LabelEncoder::new().fit_transform(&species)?;
+    let x = standardize(&x);
+
+    let start = Instant::now();
+    let mut tsne = Tsne::new().with_perplexity(30.0).with_random_state(42);
+    let embedding = tsne.fit_transform(&x)?;
+    println!(
+        "[IRIS] t-SNE en {:.2?}, divergence KL = {:.3}",
+        start.elapsed(),
+        tsne.kl_divergence().unwrap_or(f64::NAN)
+    );
+
+    // θ = 0 : calcul exact des répulsions, pour comparer avec l'approximation de Barnes–Hut
+    let start = Instant::now();
+    let mut exact = Tsne::new().with_theta(0.0).with_random_state(42);
+    exact.fit_transform(&x)?;
+    println!(
+        "[IRIS] t-SNE exact (θ = 0) en {:.2?}, divergence KL = {:.3}",
+        start.elapsed(),
+        exact.kl_divergence().unwrap_or(f64::NAN)
+    );
+    plot_embedding(
+        &embedding,
+        &y,
+        &IRIS_SPECIES,
+        "t-SNE d'iris (perplexité 30)",
+        "plots/tsne_iris.html",
+    )?;
+
+    // --- Diabetes : 8 features, 768 patientes
+    let (x, y) = load_diabetes("data/diabetes.csv")?;
+    let x = standardize(&x);
+    for perplexity in [10.0, 50.0] {
+        let start = Instant::now();
+        let mut tsne = Tsne::new()
+            .with_perplexity(perplexity)
+            .with_learning_rate(200.0)
+            .with_early_exaggeration(12.0)
+            .with_random_state(42);
+        let embedding = tsne.fit_transform(&x)?;
+        println!(
+            "[DIABETES] t-SNE perplexité {} en {:.2?}, divergence KL = {:.3}",
+            perplexity,
+            start.elapsed(),
+            tsne.kl_divergence().unwrap_or(f64::NAN)
+        );
+        plot_embedding(
+            &embedding,
+            &y,
+            &DIABETES_OUTCOMES,
+            &format!("t-SNE de diabetes (perplexité {})", perplexity),
+            &format!("plots/tsne_diabetes_perplexity_{}.html", perplexity),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Nuage de points 2D, une trace (donc une couleur) par classe
+fn plot_embedding(
+    embedding: &Array2<f64>,
+    y: &Array1<usize>,
+    class_names: &[&str],
+    title: &str,
+    filename: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut plot = Plot::new();
+    add_class_scatter(&mut plot, embedding, y, class_names);
+    // Les axes d'un t-SNE n'ont pas d'unité : on masque les graduations
+    plot.set_layout(
+        Layout::new()
+            .title(title)
+            .x_axis(PlotAxis::new().show_tick_labels(false).show_grid(false))
+            .y_axis(PlotAxis::new().show_tick_labels(false).show_grid(false)),
+    );
+
+    save_html(&plot, filename)?;
+    Ok(())
+}
+
+// Centre et réduit chaque feature
+fn standardize(x: &Array2<f64>) -> Array2<f64> {
+    let mean = x.mean_axis(Axis(0)).expect("jeu de données non vide");
+    let std = x.std_axis(Axis(0), 0.0).mapv(|s| s.max(1e-12));
+    (x - &mean) / &std
+}
+
+/*
+DATA MANAGEMENT
+*/
@@ -0,0 +1,313 @@
+//! Partitionnement non supervisé des observations.
+
+use ndarray::{Array1, Array2, Axis};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::distance::{Distance, SquaredEuclidean};
+use crate::error::{ModelError, check_n_features};
+
+/// K-means : regroupe les observations autour de `n_clusters` centroïdes.
+///
+/// Les centroïdes initiaux sont tirés avec k-means++ (chaque nouveau centre est choisi
+/// avec une probabilité proportionnelle à sa distance au carré aux centres déjà placés),
+/// puis l'algorithme de Lloyd alterne affectation au centroïde le plus proche et
+/// recalcul des moyennes. L'initialisation est relancée `n_init` fois et la partition
+/// de plus faible inertie est conservée.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::cluster::KMeans;
+///
+/// let x = array![[0.0, 0.0], [0.1, 0.0], [5.0, 5.0], [5.1, 5.0]];
+/// let mut kmeans = KMeans::new(2).with_random_state(0);
+/// let labels = kmeans.fit_predict(&x).unwrap();
+/// assert_eq!(labels[0], labels[1]);
+/// assert_ne!(labels[0], labels[2]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KMeans {
+    n_clusters: usize,
+    max_iter: usize,
+    tol: f64,
+    n_init: usize,
+    random_state: u64,
+    centroids: Option<Array2<f64>>,
+    labels: Array1<usize>,
+    inertia: f64,
+    n_iter: usize,
+}
+
+/// Résultat d'une exécution de Lloyd à partir d'une initialisation.
+struct Run {
+    centroids: Array2<f64>,
+    labels: Array1<usize>,
+    inertia: f64,
+    n_iter: usize,
+}
+
+impl KMeans {
+    pub fn new(n_clusters: usize) -> Self {
+        Self {
+            n_clusters,
+            max_iter: 300,
+            tol: 1e-4,
+            n_init: 10,
+            random_state: 0,
+            centroids: None,
+            labels: Array1::zeros(0),
+            inertia: 0.0,
+            n_iter: 0,
+        }
+    }
+
+    /// Nombre maximal d'itérations de Lloyd par initialisation (300 par défaut).
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Seuil d'arrêt sur le déplacement des centroïdes, relatif à la variance moyenne
+    /// des features (1e-4 par défaut).
+    pub fn with_tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Nombre d'initialisations k-means++ essayées (10 par défaut).
+    pub fn with_n_init(mut self, n_init: usize) -> Self {
+        self.n_init = n_init;
+        self
+    }
+
+    /// Graine du tirage des centroïdes initiaux.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        self.validate(x)?;
+        let tol = self.tol * x.var_axis(Axis(0), 0.0).mean().unwrap_or(0.0);
+        let mut rng = StdRng::seed_from_u64(self.random_state);
+
+        let mut best: Option<Run> = None;
+        for _ in 0..self.n_init {
+            let init = kmeans_plus_plus(x, self.n_clusters, &mut rng)?;
+            let run = self.lloyd(x, init, tol)?;
+            if best.as_ref().is_none_or(|b| run.inertia < b.inertia) {
+                best = Some(run);
+            }
+        }
+
+        let best = best.expect("n_init >= 1");
+        self.labels = best.labels;
+        self.inertia = best.inertia;
+        self.n_iter = best.n_iter;
+        self.centroids = Some(best.centroids);
+        Ok(())
+    }
+
+    /// Indice du centroïde le plus proche de chaque observation.
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let centroids = self.centroids()?;
+        check_n_features(centroids.ncols(), x.ncols())?;
+        Ok(assign(x, centroids)?.0)
+    }
+
+    /// Distance euclidienne de chaque observation à chaque centroïde, matrice
+    /// (n_observations × n_clusters).
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let centroids = self.centroids()?;
+        check_n_features(centroids.ncols(), x.ncols())?;
+        Ok(SquaredEuclidean
+            .pairwise(x.view(), centroids.view())?
+            .mapv(|d| d.max(0.0).sqrt()))
+    }
+
+    /// Opposé de l'inertie de `x` par rapport aux centroïdes appris (plus grand =
+    /// clusters plus compacts).
+    pub fn score(&self, x: &Array2<f64>) -> Result<f64, ModelError> {
+        let centroids = self.centroids()?;
+        check_n_features(centroids.ncols(), x.ncols())?;
+        Ok(-assign(x, centroids)?.1.sum())
+    }
+
+    pub fn fit_predict(&mut self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        self.fit(x)?;
+        Ok(self.labels.clone())
+    }
+
+    /// Centroïdes, un par ligne.
+    pub fn centroids(&self) -> Result<&Array2<f64>, ModelError> {
+        self.centroids.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Cluster de chaque observation du jeu d'entraînement.
+    pub fn labels(&self) -> Result<&Array1<usize>, ModelError> {
+        self.centroids()?;
+        Ok(&self.labels)
+    }
+
+    /// Somme des distances au carré de chaque observation à son centroïde.
+    pub fn inertia(&self) -> Result<f64, ModelError> {
+        self.centroids()?;
+        Ok(self.inertia)
+    }
+
+    /// Nombre d'itérations de Lloyd de la meilleure initialisation.
+    pub fn n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn validate(&self, x: &Array2<f64>) -> Result<(), ModelError> {
+        if x.nrows() == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        if self.n_clusters == 0 || self.n_clusters > x.nrows() {
+            return Err(ModelError::InvalidParameter(format!(
+                "n_clusters doit être entre 1 et le nombre d'observations ({}), reçu {}",
+                x.nrows(),
+                self.n_clusters
+            )));
+        }
+        if self.n_init == 0 || self.max_iter == 0 {
+            return Err(ModelError::InvalidParameter(
+                "n_init et max_iter doivent être au moins 1".to_string(),
+            ));
+        }
+        if self.tol.is_nan() || self.tol < 0.0 {
+            return Err(ModelError::InvalidParameter(format!(
+                "tol doit être positif, reçu {}",
+                self.tol
+            )));
+        }
+        Ok(())
+    }
+
+    /// Algorithme de Lloyd à partir des centroïdes `centroids`.
+    fn lloyd(
+        &self,
+        x: &Array2<f64>,
+        mut centroids: Array2<f64>,
+        tol: f64,
+    ) -> Result<Run, ModelError> {
+        let mut n_iter = 0;
+        while n_iter < self.max_iter {
+            n_iter += 1;
+            let (labels, distances) = assign(x, &centroids)?;
+            let updated = update_centroids(x, &labels, &distances, &centroids);
+            let shift = (&updated - &centroids).mapv(|d| d * d).sum();
+            centroids = updated;
+            if shift <= tol {
+                break;
+            }
+        }
+        // Affectation finale cohérente avec les centroïdes renvoyés
+        let (labels, distances) = assign(x, &centroids)?;
+        Ok(Run {
+            centroids,
+            labels,
+            inertia: distances.sum(),
+            n_iter,
+        })
+    }
+}
+
+/// Initialisation k-means++.
+fn kmeans_plus_plus(
+    x: &Array2<f64>,
+    n_clusters: usize,
+    rng: &mut StdRng,
+) -> Result<Array2<f64>, ModelError> {
+    let n = x.nrows();
+    let mut centroids = Array2::zeros((n_clusters, x.ncols()));
+    centroids.row_mut(0).assign(&x.row(rng.random_range(0..n)));
+
+    // Distance au carré de chaque observation au centre le plus proche déjà choisi
+    let mut closest = nearest_squared(x, &centroids.slice(ndarray::s![..1, ..]).to_owned())?;
+    for k in 1..n_clusters {
+        let total = closest.sum();
+        let chosen = if total > 0.0 {
+            let mut target = rng.random::<f64>() * total;
+            closest
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < 0.0
+                })
+                .unwrap_or(n - 1)
+        } else {
+            // Toutes les observations coïncident avec un centre : tirage uniforme
+            rng.random_range(0..n)
+        };
+        centroids.row_mut(k).assign(&x.row(chosen));
+        let new_distances =
+            nearest_squared(x, &centroids.slice(ndarray::s![k..k + 1, ..]).to_owned())?;
+        closest.zip_mut_with(&new_distances, |c, &d| *c = c.min(d));
+    }
+    Ok(centroids)
+}
+
+/// Distance au carré de chaque observation au plus proche des `centroids`.
+fn nearest_squared(x: &Array2<f64>, centroids: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+    Ok(assign(x, centroids)?.1)
+}
+
+/// Centroïde le plus proche de chaque observation et distance au carré associée.
+fn assign(
+    x: &Array2<f64>,
+    centroids: &Array2<f64>,
+) -> Result<(Array1<usize>, Array1<f64>), ModelError> {
+    let distances = SquaredEuclidean.pairwise(x.view(), centroids.view())?;
+    let mut labels = Array1::zeros(x.nrows());
+    let mut nearest = Array1::zeros(x.nrows());
+    for ((label, best), row) in labels
+        .iter_mut()
+        .zip(nearest.iter_mut())
+        .zip(distances.rows())
+    {
+        let (k, d) =
+            row.iter().enumerate().fold(
+                (0, f64::INFINITY),
+                |(bk, bd), (k, &d)| {
+                    if d < bd { (k, d) } else { (bk, bd) }
+                },
+            );
+        *label = k;
+        // Le calcul par produit matriciel peut donner -1e-15 au lieu de 0
+        *best = d.max(0.0);
+    }
+    Ok((labels, nearest))
+}
+
+/// Moyenne des observations de chaque cluster. Un cluster vide est replacé sur
+/// l'observation la plus éloignée de son centroïde.
+fn update_centroids(
+    x: &Array2<f64>,
+    labels: &Array1<usize>,
+    distances: &Array1<f64>,
+    previous: &Array2<f64>,
+) -> Array2<f64> {
+    let mut sums = Array2::zeros(previous.raw_dim());
+    let mut counts = vec![0usize; previous.nrows()];
+    for (row, &k) in x.rows().into_iter().zip(labels) {
+        sums.row_mut(k).scaled_add(1.0, &row);
+        counts[k] += 1;
+    }
+
+    let mut far = distances.iter().enumerate().collect::<Vec<_>>();
+    far.sort_by(|a, b| b.1.total_cmp(a.1));
+    let mut far = far.into_iter().map(|(i, _)| i);
+
+    for (mut centroid, &count) in sums.rows_mut().into_iter().zip(&counts) {
+        if count > 0 {
+            centroid /= count as f64;
+        } else if let Some(i) = far.next() {
+            centroid.assign(&x.row(i));
+        }
+    }
+    sums
+}
@@ -0,0 +1,148 @@
+//! Matrices de corrélation entre colonnes numériques.
+//!
+//! Trois coefficients, tous compris entre −1 et 1 :
+//! - Pearson mesure une relation linéaire ;
+//! - Spearman est le Pearson des rangs (moyens en cas d'égalité) : il détecte toute
+//!   relation monotone et résiste aux valeurs extrêmes ;
+//! - Kendall (tau-b) compare le sens de variation de chaque paire d'observations,
+//!   en O(n²) par paire de colonnes.
+//!
+//! Comme `DataFrame.corr()` de pandas, chaque coefficient est calculé sur les lignes
+//! où les deux colonnes sont renseignées (les `NaN` sont ignorés paire par paire) ;
+//! il vaut `NaN` si l'une des colonnes est constante sur ces lignes.
+
+use ndarray::{Array2, ArrayView1};
+
+/// Coefficient de corrélation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorrelationMethod {
+    #[default]
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+impl CorrelationMethod {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CorrelationMethod::Pearson => "pearson",
+            CorrelationMethod::Spearman => "spearman",
+            CorrelationMethod::Kendall => "kendall",
+        }
+    }
+
+    /// Coefficient entre deux colonnes de même longueur.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::array;
+    /// use rust_first_steps::correlation::CorrelationMethod;
+    ///
+    /// // Relation monotone mais non linéaire
+    /// let x = array![1.0, 2.0, 3.0, 4.0];
+    /// let y = x.mapv(|v: f64| v.powi(3));
+    /// assert!(CorrelationMethod::Pearson.coefficient(x.view(), y.view()) < 1.0);
+    /// assert_eq!(CorrelationMethod::Spearman.coefficient(x.view(), y.view()), 1.0);
+    /// assert_eq!(CorrelationMethod::Kendall.coefficient(x.view(), y.view()), 1.0);
+    /// ```
+    pub fn coefficient(&self, x: ArrayView1<f64>, y: ArrayView1<f64>) -> f64 {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "Les colonnes doivent avoir la même taille !"
+        );
+        let (x, y): (Vec<f64>, Vec<f64>) = x
+            .iter()
+            .zip(&y)
+            .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+            .map(|(&a, &b)| (a, b))
+            .unzip();
+        match self {
+            CorrelationMethod::Pearson => pearson(&x, &y),
+            CorrelationMethod::Spearman => pearson(&ranks(&x), &ranks(&y)),
+            CorrelationMethod::Kendall => kendall_tau_b(&x, &y),
+        }
+    }
+}
+
+/// Matrice symétrique des corrélations entre les colonnes de `x` (1 sur la diagonale
+/// pour une colonne non constante).
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::correlation::{CorrelationMethod, correlation_matrix};
+///
+/// let x = array![[1.0, 2.0, 3.0], [2.0, 4.0, 1.0], [3.0, 6.0, 2.0]];
+/// let corr = correlation_matrix(&x, CorrelationMethod::Pearson);
+/// assert!((corr[[0, 1]] - 1.0).abs() < 1e-12);
+/// assert_eq!(corr[[0, 2]], corr[[2, 0]]);
+/// ```
+pub fn correlation_matrix(x: &Array2<f64>, method: CorrelationMethod) -> Array2<f64> {
+    let p = x.ncols();
+    let mut corr = Array2::zeros((p, p));
+    for i in 0..p {
+        for j in i..p {
+            let r = method.coefficient(x.column(i), x.column(j));
+            corr[[i, j]] = r;
+            corr[[j, i]] = r;
+        }
+    }
+    corr
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    if x.is_empty() {
+        return f64::NAN;
+    }
+    let n = x.len() as f64;
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y) {
+        let (dx, dy) = (a - mean_x, b - mean_y);
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    // Bornée pour absorber les erreurs d'arrondi (|r| très légèrement > 1)
+    (cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Rangs à partir de 1, les valeurs égales recevant la moyenne de leurs rangs.
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // Rangs start+1..=end, de moyenne (start + 1 + end) / 2
+        let average = (start + 1 + end) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = average;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Tau-b de Kendall : (concordantes − discordantes) / √(paires sans égalité en x ·
+/// paires sans égalité en y).
+fn kendall_tau_b(x: &[f64], y: &[f64]) -> f64 {
+    // Sens de variation d'une paire : −1, 0 (égalité) ou 1 ; les NaN sont déjà filtrés
+    let direction =
+        |v: &[f64], i: usize, j: usize| v[i].partial_cmp(&v[j]).expect("pas de NaN") as i64;
+    let (mut score, mut untied_x, mut untied_y) = (0i64, 0u64, 0u64);
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            let (dx, dy) = (direction(x, i, j), direction(y, i, j));
+            score += dx * dy;
+            untied_x += u64::from(dx != 0);
+            untied_y += u64::from(dy != 0);
+        }
+    }
+    score as f64 / ((untied_x as f64) * (untied_y as f64)).sqrt()
+}
@@ -0,0 +1,500 @@
+//! Chargement de fichiers CSV en matrices.
+//!
+//! Les colonnes sont choisies par leur nom d'en-tête : il n'est plus nécessaire de
+//! déclarer une structure par jeu de données (`IrisRow`, `HousingRow`...). Les
+//! features sont lues en `f64`, ligne par ligne, et la cible dans n'importe quel
+//! type implémentant [`FromStr`] (`String` pour des espèces, `usize` pour des
+//! classes, `f64` pour une régression).
+//!
+//! Une cellule de feature vide (ou `NA`, `NaN`, `null`) est lue comme `f64::NAN` :
+//! les valeurs manquantes sont ensuite remplacées avec [`crate::impute`], ou
+//! signalées par [`check_no_missing`] quand aucun imputer n'est prévu. La
+//! cible, elle, doit toujours être renseignée. Les colonnes catégorielles sont lues
+//! telles quelles avec [`load_categorical`], puis encodées avec
+//! [`crate::preprocessing`].
+//!
+//! Les jeux de données du dossier `data/` ont aussi leurs raccourcis
+//! ([`load_iris`], [`load_diabetes`], [`load_housing`]) et [`load_csv_polars`]
+//! charge un fichier quelconque en `DataFrame` pour l'exploration.
+//!
+//! [`Dataset`] regroupe X, la cible et les noms des features : c'est le type reçu par
+//! les traits de [`crate::traits`].
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use csv::{ReaderBuilder, StringRecord};
+use nalgebra::{DMatrix, DVector, Scalar};
+use ndarray::{Array1, Array2, Axis};
+use polars::prelude::{CsvReadOptions, DataFrame, PolarsResult, SerReader};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::model_selection::shuffled_split;
+
+/// Features d'iris, dans l'ordre des colonnes du fichier.
+pub const IRIS_FEATURES: [&str; 4] = ["sepal_length", "sepal_width", "petal_length", "petal_width"];
+/// Espèces d'iris, dans l'ordre de leur indice de classe donné par
+/// [`LabelEncoder`](crate::preprocessing::LabelEncoder) (ordre alphabétique).
+pub const IRIS_SPECIES: [&str; 3] = ["Setosa", "Versicolor", "Virginica"];
+/// Features de diabetes ; la cible est la colonne `outcome` (0 ou 1).
+pub const DIABETES_FEATURES: [&str; 8] = [
+    "pregnancies",
+    "glucose",
+    "blood_pressure",
+    "skin_thickness",
+    "insulin",
+    "bmi",
+    "diabetes_pedigree_function",
+    "age",
+];
+/// Features de housing ; la cible est la colonne `price`.
+pub const HOUSING_FEATURES: [&str; 2] = ["area", "distance_from_center"];
+
+/// Erreurs possibles lors de la lecture d'un CSV.
+#[derive(Debug)]
+pub enum DataError {
+    /// Le fichier n'a pas pu être ouvert.
+    Io(io::Error),
+    /// Le fichier n'est pas un CSV valide (nombre de champs variable...).
+    Csv(csv::Error),
+    /// Une colonne demandée n'existe pas dans l'en-tête.
+    MissingColumn {
+        name: String,
+        available: Vec<String>,
+    },
+    /// Une cellule n'a pas pu être convertie dans le type attendu.
+    Parse {
+        line: u64,
+        column: String,
+        value: String,
+    },
+    /// Une colonne de features contient des valeurs manquantes, sans imputation prévue.
+    MissingValues { column: String, count: usize },
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::Io(e) => write!(f, "lecture impossible : {}", e),
+            DataError::Csv(e) => write!(f, "CSV invalide : {}", e),
+            DataError::MissingColumn { name, available } => write!(
+                f,
+                "colonne '{}' absente (colonnes disponibles : {})",
+                name,
+                available.join(", ")
+            ),
+            DataError::Parse {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "ligne {} : valeur '{}' invalide pour la colonne '{}'",
+                line, value, column
+            ),
+            DataError::MissingValues { column, count } => write!(
+                f,
+                "{} valeur(s) manquante(s) dans la colonne '{}' : à imputer avant de l'utiliser",
+                count, column
+            ),
+        }
+    }
+}
+
+impl Error for DataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DataError::Io(e) => Some(e),
+            DataError::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DataError {
+    fn from(e: io::Error) -> Self {
+        DataError::Io(e)
+    }
+}
+
+impl From<csv::Error> for DataError {
+    fn from(e: csv::Error) -> Self {
+        DataError::Csv(e)
+    }
+}
+
+/// Observations X (une par ligne), cible y (une valeur par observation) et noms des
+/// features.
+///
+/// `T` vaut `usize` pour des classes, `f64` pour une régression et `()` pour des
+/// données sans cible (clustering, prétraitement), construites avec
+/// [`Dataset::unlabeled`].
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::data::Dataset;
+///
+/// let data = Dataset::new(array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], array![0, 1, 1])
+///     .unwrap()
+///     .with_feature_names(&["a", "b"])
+///     .unwrap();
+/// let subset = data.select(&[2, 0]);
+/// assert_eq!(subset.y(), &array![1, 0]);
+/// assert_eq!(subset.feature_names(), ["a", "b"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dataset<T> {
+    x: Array2<f64>,
+    y: Array1<T>,
+    feature_names: Vec<String>,
+}
+
+impl<T> Dataset<T> {
+    /// Jeu de données sans noms de features ; X et y doivent avoir le même nombre de lignes.
+    pub fn new(x: Array2<f64>, y: Array1<T>) -> Result<Self, ModelError> {
+        check_x_y(&x, y.len())?;
+        Ok(Self {
+            x,
+            y,
+            feature_names: Vec::new(),
+        })
+    }
+
+    /// Nomme les colonnes de X.
+    pub fn with_feature_names(mut self, names: &[&str]) -> Result<Self, ModelError> {
+        check_n_features(self.x.ncols(), names.len())?;
+        self.feature_names = names.iter().map(|name| name.to_string()).collect();
+        Ok(self)
+    }
+
+    /// Remplace X (par exemple par sa version prétraitée) en gardant la cible. Les
+    /// noms des features sont conservés seulement si le nombre de colonnes ne change pas.
+    pub fn with_x(mut self, x: Array2<f64>) -> Result<Self, ModelError> {
+        check_x_y(&x, self.y.len())?;
+        if x.ncols() != self.x.ncols() {
+            self.feature_names.clear();
+        }
+        self.x = x;
+        Ok(self)
+    }
+
+    pub fn x(&self) -> &Array2<f64> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Array1<T> {
+        &self.y
+    }
+
+    /// Noms des features, vide s'ils ne sont pas connus.
+    pub fn feature_names(&self) -> &[String] {
+        &self.feature_names
+    }
+
+    pub fn n_samples(&self) -> usize {
+        self.x.nrows()
+    }
+
+    pub fn n_features(&self) -> usize {
+        self.x.ncols()
+    }
+
+    pub fn into_parts(self) -> (Array2<f64>, Array1<T>) {
+        (self.x, self.y)
+    }
+}
+
+impl Dataset<()> {
+    /// Jeu de données sans cible.
+    pub fn unlabeled(x: Array2<f64>) -> Self {
+        Self {
+            y: Array1::from_elem(x.nrows(), ()),
+            x,
+            feature_names: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone> Dataset<T> {
+    /// Observations d'indices `indices`, dans cet ordre.
+    pub fn select(&self, indices: &[usize]) -> Self {
+        Self {
+            x: self.x.select(Axis(0), indices),
+            y: self.y.select(Axis(0), indices),
+            feature_names: self.feature_names.clone(),
+        }
+    }
+
+    /// Mélange les observations puis les sépare en `(train, test)`, comme
+    /// [`crate::model_selection::train_test_split`].
+    pub fn train_test_split(&self, test_size: f64, seed: u64) -> (Self, Self) {
+        let (train, test) = shuffled_split(self.n_samples(), test_size, seed);
+        (self.select(&train), self.select(&test))
+    }
+}
+
+/// Charge iris : les 4 mesures des fleurs et l'espèce.
+pub fn load_iris(path: impl AsRef<Path>) -> Result<(Array2<f64>, Array1<String>), DataError> {
+    load_csv_ndarray(path, &IRIS_FEATURES, "species")
+}
+
+/// Charge diabetes : les 8 mesures cliniques et l'outcome (1 si diabétique).
+pub fn load_diabetes(path: impl AsRef<Path>) -> Result<(Array2<f64>, Array1<usize>), DataError> {
+    load_csv_ndarray(path, &DIABETES_FEATURES, "outcome")
+}
+
+/// Charge housing : surface et distance au centre, et le prix.
+pub fn load_housing(path: impl AsRef<Path>) -> Result<(Array2<f64>, Array1<f64>), DataError> {
+    load_csv_ndarray(path, &HOUSING_FEATURES, "price")
+}
+
+/// Charge un fichier CSV en un DataFrame Polars
+///
+/// # Arguments
+/// * `path` - Le chemin vers le fichier CSV
+///
+/// # Returns
+/// * `DataFrame` contenant les données
+pub fn load_csv_polars(path: impl AsRef<Path>) -> PolarsResult<DataFrame> {
+    CsvReadOptions::default()
+        .with_has_header(true)
+        .try_into_reader_with_file_path(Some(PathBuf::from(path.as_ref())))?
+        .finish()
+}
+
+/// Noms des colonnes d'un fichier CSV, dans l'ordre de l'en-tête.
+pub fn read_headers(path: impl AsRef<Path>) -> Result<Vec<String>, DataError> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(File::open(path)?);
+    Ok(reader.headers()?.iter().map(str::to_string).collect())
+}
+
+/// Charge les colonnes `features` d'un CSV en une matrice ndarray (une observation par
+/// ligne) et la colonne `target` en un vecteur.
+///
+/// # Arguments
+/// * `path` - Le chemin vers le fichier CSV (avec une ligne d'en-tête).
+/// * `features` - Les noms des colonnes de X, dans l'ordre voulu pour les colonnes de la matrice.
+/// * `target` - Le nom de la colonne cible.
+///
+/// # Example
+/// ```
+/// use rust_first_steps::data::load_csv_ndarray;
+///
+/// let (x, species) = load_csv_ndarray::<String>(
+///     "data/iris.csv",
+///     &["petal_length", "petal_width"],
+///     "species",
+/// )
+/// .unwrap();
+/// assert_eq!(x.dim(), (150, 2));
+/// assert_eq!(species[0], "Setosa");
+/// ```
+pub fn load_csv_ndarray<T: FromStr>(
+    path: impl AsRef<Path>,
+    features: &[&str],
+    target: &str,
+) -> Result<(Array2<f64>, Array1<T>), DataError> {
+    let (values, targets) = read_columns(path.as_ref(), features, target)?;
+    let x = Array2::from_shape_vec((targets.len(), features.len()), values)
+        .expect("une valeur par feature et par ligne");
+    Ok((x, Array1::from_vec(targets)))
+}
+
+/// Comme [`load_csv_ndarray`], en un [`Dataset`] qui garde les noms des features.
+///
+/// # Example
+/// ```
+/// use rust_first_steps::data::{HOUSING_FEATURES, load_dataset};
+///
+/// let housing = load_dataset::<f64>("data/housing.csv", &HOUSING_FEATURES, "price").unwrap();
+/// assert_eq!(housing.n_features(), 2);
+/// assert_eq!(housing.feature_names()[0], "area");
+/// ```
+pub fn load_dataset<T: FromStr>(
+    path: impl AsRef<Path>,
+    features: &[&str],
+    target: &str,
+) -> Result<Dataset<T>, DataError> {
+    let (x, y) = load_csv_ndarray(path, features, target)?;
+    Ok(Dataset {
+        x,
+        y,
+        feature_names: features.iter().map(|name| name.to_string()).collect(),
+    })
+}
+
+/// Charge seulement les colonnes `features` d'un CSV, sans cible (clustering, exploration).
+pub fn load_features(path: impl AsRef<Path>, features: &[&str]) -> Result<Array2<f64>, DataError> {
+    let (values, n_rows) = read_feature_columns(path.as_ref(), features)?;
+    Ok(Array2::from_shape_vec((n_rows, features.len()), values)
+        .expect("une valeur par feature et par ligne"))
+}
+
+/// Vérifie qu'aucune colonne de `x` ne contient de valeur manquante (`NaN`).
+///
+/// À appeler avant d'entraîner ou d'appliquer un modèle sans imputer : l'erreur
+/// nomme la première colonne concernée, d'après `features`.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::data::check_no_missing;
+///
+/// assert!(check_no_missing(&array![[1.0, 2.0], [3.0, 4.0]], &["a", "b"]).is_ok());
+/// let err = check_no_missing(&array![[1.0, f64::NAN], [3.0, 4.0]], &["a", "b"]).unwrap_err();
+/// assert!(err.to_string().contains("'b'"));
+/// ```
+pub fn check_no_missing(x: &Array2<f64>, features: &[&str]) -> Result<(), DataError> {
+    for (j, column) in x.columns().into_iter().enumerate() {
+        let count = column.iter().filter(|v| v.is_nan()).count();
+        if count > 0 {
+            return Err(DataError::MissingValues {
+                column: features
+                    .get(j)
+                    .map_or_else(|| j.to_string(), |name| name.to_string()),
+                count,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Charge les colonnes `columns` d'un CSV sans les convertir (valeurs catégorielles).
+///
+/// # Example
+/// ```
+/// use rust_first_steps::data::load_categorical;
+///
+/// let species = load_categorical("data/iris.csv", &["species"]).unwrap();
+/// assert_eq!(species.dim(), (150, 1));
+/// assert_eq!(species[[0, 0]], "Setosa");
+/// ```
+pub fn load_categorical(
+    path: impl AsRef<Path>,
+    columns: &[&str],
+) -> Result<Array2<String>, DataError> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(File::open(path)?);
+    let headers = reader.headers()?.clone();
+    let column_idx = columns
+        .iter()
+        .map(|name| column_index(&headers, name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut values = Vec::new();
+    for result in reader.records() {
+        let record = result?;
+        for &j in &column_idx {
+            values.push(record.get(j).unwrap_or_default().trim().to_string());
+        }
+    }
+    let n_rows = values.len() / columns.len().max(1);
+    Ok(Array2::from_shape_vec((n_rows, columns.len()), values)
+        .expect("une valeur par colonne et par ligne"))
+}
+
+/// Équivalent de [`load_csv_ndarray`] renvoyant une `DMatrix` et un `DVector` nalgebra.
+///
+/// Les valeurs sont lues ligne par ligne puis rangées avec `from_row_slice` :
+/// la matrice a bien une observation par ligne, sans transposition à faire.
+pub fn load_csv_nalgebra<T: FromStr + Scalar>(
+    path: impl AsRef<Path>,
+    features: &[&str],
+    target: &str,
+) -> Result<(DMatrix<f64>, DVector<T>), DataError> {
+    let (values, targets) = read_columns(path.as_ref(), features, target)?;
+    let x = DMatrix::from_row_slice(targets.len(), features.len(), &values);
+    Ok((x, DVector::from_vec(targets)))
+}
+
+/// Lit les features (à plat, ligne par ligne) d'un CSV et renvoie aussi le nombre de lignes.
+fn read_feature_columns(path: &Path, features: &[&str]) -> Result<(Vec<f64>, usize), DataError> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(File::open(path)?);
+    let headers = reader.headers()?.clone();
+    let feature_idx = features
+        .iter()
+        .map(|name| column_index(&headers, name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut values = Vec::new();
+    let mut n_rows = 0;
+    for result in reader.records() {
+        let record = result?;
+        for (&j, name) in feature_idx.iter().zip(features) {
+            values.push(parse_feature(&record, j, name)?);
+        }
+        n_rows += 1;
+    }
+    Ok((values, n_rows))
+}
+
+/// Lit les features (à plat, ligne par ligne) et la cible d'un CSV.
+fn read_columns<T: FromStr>(
+    path: &Path,
+    features: &[&str],
+    target: &str,
+) -> Result<(Vec<f64>, Vec<T>), DataError> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(File::open(path)?);
+    let headers = reader.headers()?.clone();
+    let feature_idx = features
+        .iter()
+        .map(|name| column_index(&headers, name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let target_idx = column_index(&headers, target)?;
+
+    let mut values = Vec::new();
+    let mut targets = Vec::new();
+    for result in reader.records() {
+        let record = result?;
+        for (&j, name) in feature_idx.iter().zip(features) {
+            values.push(parse_feature(&record, j, name)?);
+        }
+        targets.push(parse_cell(&record, target_idx, target)?);
+    }
+    Ok((values, targets))
+}
+
+fn column_index(headers: &StringRecord, name: &str) -> Result<usize, DataError> {
+    headers
+        .iter()
+        .position(|h| h == name)
+        .ok_or_else(|| DataError::MissingColumn {
+            name: name.to_string(),
+            available: headers.iter().map(str::to_string).collect(),
+        })
+}
+
+/// Valeurs textuelles lues comme manquantes dans une colonne de features.
+const MISSING_MARKERS: [&str; 4] = ["", "na", "nan", "null"];
+
+fn parse_feature(record: &StringRecord, j: usize, column: &str) -> Result<f64, DataError> {
+    let value = record.get(j).unwrap_or_default().trim();
+    if MISSING_MARKERS
+        .iter()
+        .any(|marker| value.eq_ignore_ascii_case(marker))
+    {
+        return Ok(f64::NAN);
+    }
+    parse_cell(record, j, column)
+}
+
+fn parse_cell<T: FromStr>(record: &StringRecord, j: usize, column: &str) -> Result<T, DataError> {
+    let value = record.get(j).unwrap_or_default().trim();
+    value.parse().map_err(|_| DataError::Parse {
+        line: record.position().map_or(0, |p| p.line()),
+        column: column.to_string(),
+        value: value.to_string(),
+    })
+}
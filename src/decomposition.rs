@@ -0,0 +1,210 @@
+//! Réduction de dimension par analyse en composantes principales (ACP / PCA).
+//!
+//! Les composantes principales sont les directions orthogonales de variance
+//! maximale des données centrées. Elles s'obtiennent par la SVD X = U Σ Vᵀ :
+//! les lignes de Vᵀ sont les composantes et la variance expliquée par la k-ième
+//! vaut σₖ² / (n - 1).
+
+use nalgebra::DMatrix;
+use ndarray::{Array1, Array2, Axis, s};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features};
+
+/// Nombre de composantes conservées par [`Pca`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum NComponents {
+    /// min(n_observations, n_features) composantes.
+    #[default]
+    All,
+    /// Un nombre fixe de composantes.
+    Count(usize),
+    /// Le plus petit nombre de composantes qui explique au moins cette part de la variance (dans ]0, 1]).
+    VarianceRatio(f64),
+}
+
+/// Analyse en composantes principales.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::decomposition::{NComponents, Pca};
+///
+/// // Points presque alignés sur la droite y = 2x
+/// let x = array![[1.0, 2.0], [2.0, 4.1], [3.0, 5.9], [4.0, 8.0]];
+/// let mut pca = Pca::new().with_n_components(NComponents::Count(1));
+/// let projected = pca.fit_transform(&x).unwrap();
+/// assert_eq!(projected.dim(), (4, 1));
+/// assert!(pca.explained_variance_ratio().unwrap()[0] > 0.99);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Pca {
+    n_components: NComponents,
+    whiten: bool,
+    mean: Option<Array1<f64>>,
+    // Une composante par ligne (k × n_features)
+    components: Array2<f64>,
+    explained_variance: Array1<f64>,
+    explained_variance_ratio: Array1<f64>,
+    singular_values: Array1<f64>,
+}
+
+impl Pca {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nombre de composantes conservées (toutes par défaut).
+    pub fn with_n_components(mut self, n_components: NComponents) -> Self {
+        self.n_components = n_components;
+        self
+    }
+
+    /// Divise chaque composante projetée par son écart-type : les sorties sont
+    /// décorrélées et de variance 1 (désactivé par défaut).
+    pub fn with_whiten(mut self, whiten: bool) -> Self {
+        self.whiten = whiten;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        let (n_samples, n_features) = x.dim();
+        if n_samples < 2 || n_features == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        let max_components = n_samples.min(n_features);
+
+        let mean = x.mean_axis(Axis(0)).ok_or(ModelError::EmptyDataset)?;
+        let centered = x - &mean;
+        let matrix = DMatrix::from_fn(n_samples, n_features, |i, j| centered[[i, j]]);
+        let svd = matrix.svd(false, true);
+        let v_t = svd.v_t.ok_or_else(|| {
+            ModelError::InvalidParameter("la SVD n'a pas pu être calculée".to_string())
+        })?;
+
+        // Valeurs singulières par ordre décroissant
+        let mut order: Vec<usize> = (0..svd.singular_values.len()).collect();
+        order.sort_by(|&a, &b| svd.singular_values[b].total_cmp(&svd.singular_values[a]));
+        let singular_values: Array1<f64> = order.iter().map(|&k| svd.singular_values[k]).collect();
+        let explained_variance = singular_values.mapv(|s| s * s / (n_samples - 1) as f64);
+        let total_variance = explained_variance.sum();
+        let explained_variance_ratio = if total_variance > 0.0 {
+            &explained_variance / total_variance
+        } else {
+            Array1::zeros(explained_variance.len())
+        };
+
+        let k = match self.n_components {
+            NComponents::All => max_components,
+            NComponents::Count(k) if (1..=max_components).contains(&k) => k,
+            NComponents::Count(k) => {
+                return Err(ModelError::InvalidParameter(format!(
+                    "n_components = {} doit être entre 1 et {}",
+                    k, max_components
+                )));
+            }
+            NComponents::VarianceRatio(ratio) if ratio > 0.0 && ratio <= 1.0 => {
+                let mut cumulated = 0.0;
+                explained_variance_ratio
+                    .iter()
+                    .position(|&r| {
+                        cumulated += r;
+                        cumulated >= ratio - 1e-12
+                    })
+                    .map_or(max_components, |k| k + 1)
+            }
+            NComponents::VarianceRatio(_) => {
+                return Err(ModelError::InvalidParameter(
+                    "la part de variance doit être dans ]0, 1]".to_string(),
+                ));
+            }
+        };
+
+        let mut components = Array2::from_shape_fn((k, n_features), |(c, j)| v_t[(order[c], j)]);
+        // Convention de signe : le coefficient le plus grand en valeur absolue est positif,
+        // pour que le résultat ne dépende pas de l'implémentation de la SVD
+        for mut component in components.rows_mut() {
+            let largest = component
+                .iter()
+                .fold(0.0_f64, |acc, &v| if v.abs() > acc.abs() { v } else { acc });
+            if largest < 0.0 {
+                component.mapv_inplace(|v| -v);
+            }
+        }
+
+        self.mean = Some(mean);
+        self.components = components;
+        self.explained_variance = explained_variance.slice_move(s![..k]);
+        self.explained_variance_ratio = explained_variance_ratio.slice_move(s![..k]);
+        self.singular_values = singular_values.slice_move(s![..k]);
+        Ok(())
+    }
+
+    /// Coordonnées des observations dans la base des composantes principales.
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mean = self.mean.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(mean.len(), x.ncols())?;
+        let mut projected = (x - mean).dot(&self.components.t());
+        if self.whiten {
+            projected /= &self.explained_variance.mapv(|v| v.sqrt().max(1e-12));
+        }
+        Ok(projected)
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Ramène des coordonnées projetées dans l'espace d'origine (reconstruction
+    /// exacte si toutes les composantes sont conservées).
+    pub fn inverse_transform(&self, projected: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mean = self.mean.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(self.components.nrows(), projected.ncols())?;
+        let mut projected = projected.clone();
+        if self.whiten {
+            projected *= &self.explained_variance.mapv(|v| v.sqrt().max(1e-12));
+        }
+        Ok(projected.dot(&self.components) + mean)
+    }
+
+    /// Composantes principales, une par ligne (n_components × n_features).
+    pub fn components(&self) -> Result<&Array2<f64>, ModelError> {
+        self.mean
+            .as_ref()
+            .map(|_| &self.components)
+            .ok_or(ModelError::NotFitted)
+    }
+
+    /// Variance des données le long de chaque composante.
+    pub fn explained_variance(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean
+            .as_ref()
+            .map(|_| &self.explained_variance)
+            .ok_or(ModelError::NotFitted)
+    }
+
+    /// Part de la variance totale expliquée par chaque composante.
+    pub fn explained_variance_ratio(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean
+            .as_ref()
+            .map(|_| &self.explained_variance_ratio)
+            .ok_or(ModelError::NotFitted)
+    }
+
+    pub fn singular_values(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean
+            .as_ref()
+            .map(|_| &self.singular_values)
+            .ok_or(ModelError::NotFitted)
+    }
+
+    /// Moyenne de chaque feature, retirée avant la projection.
+    pub fn mean(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    pub fn n_components(&self) -> usize {
+        self.components.nrows()
+    }
+}
@@ -0,0 +1,280 @@
+//! Résumé statistique d'un jeu de données, colonne par colonne (l'équivalent de
+//! `DataFrame.describe()` de pandas).
+//!
+//! Les valeurs manquantes (`NaN` dans une matrice, `null` dans un `DataFrame`
+//! Polars) sont comptées à part puis ignorées par les statistiques. Les conventions
+//! sont celles de pandas : écart-type corrigé (n − 1), quartiles interpolés
+//! linéairement, skewness et kurtosis (en excès) corrigées du biais. Une colonne
+//! non numérique n'a que `count`, `missing` et `unique`.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use ndarray::{Array2, ArrayView1};
+use polars::prelude::{DataFrame, DataType, PolarsResult};
+use serde::Serialize;
+
+use crate::data::DataError;
+use crate::error::ModelError;
+
+/// Statistiques d'une colonne ; `None` si elles ne sont pas définies (colonne non
+/// numérique, trop peu de valeurs observées).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnSummary {
+    pub name: String,
+    /// Nombre de valeurs observées.
+    pub count: usize,
+    pub missing: usize,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    #[serde(rename = "25%")]
+    pub q1: Option<f64>,
+    #[serde(rename = "50%")]
+    pub median: Option<f64>,
+    #[serde(rename = "75%")]
+    pub q3: Option<f64>,
+    pub max: Option<f64>,
+    pub skewness: Option<f64>,
+    pub kurtosis: Option<f64>,
+    /// Nombre de valeurs distinctes observées.
+    pub unique: usize,
+}
+
+impl ColumnSummary {
+    /// Résume une colonne numérique, les `NaN` étant comptés comme manquants.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::array;
+    /// use rust_first_steps::describe::ColumnSummary;
+    ///
+    /// let summary = ColumnSummary::numeric("x", array![1.0, 2.0, f64::NAN, 3.0, 4.0].view());
+    /// assert_eq!((summary.count, summary.missing, summary.unique), (4, 1, 4));
+    /// assert_eq!(summary.mean, Some(2.5));
+    /// assert_eq!(summary.q1, Some(1.75));
+    /// assert_eq!(summary.skewness, Some(0.0));
+    /// ```
+    pub fn numeric(name: &str, values: ArrayView1<f64>) -> Self {
+        let mut observed: Vec<f64> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+        observed.sort_by(f64::total_cmp);
+        let count = observed.len();
+        let mut distinct = observed.clone();
+        distinct.dedup();
+
+        let moments = Moments::new(&observed);
+        Self {
+            name: name.to_string(),
+            count,
+            missing: values.len() - count,
+            mean: moments.map(|m| m.mean),
+            std: moments.and_then(|m| m.std()),
+            min: observed.first().copied(),
+            q1: quantile(&observed, 0.25),
+            median: quantile(&observed, 0.5),
+            q3: quantile(&observed, 0.75),
+            max: observed.last().copied(),
+            skewness: moments.and_then(|m| m.skewness()),
+            kurtosis: moments.and_then(|m| m.kurtosis()),
+            unique: distinct.len(),
+        }
+    }
+
+    /// Résume une colonne non numérique (textes, catégories).
+    pub fn categorical(name: &str, count: usize, missing: usize, unique: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            count,
+            missing,
+            mean: None,
+            std: None,
+            min: None,
+            q1: None,
+            median: None,
+            q3: None,
+            max: None,
+            skewness: None,
+            kurtosis: None,
+            unique,
+        }
+    }
+}
+
+/// Moyenne et moments centrés d'ordre 2 à 4 des valeurs observées.
+#[derive(Debug, Clone, Copy)]
+struct Moments {
+    n: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl Moments {
+    fn new(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let central = |k: i32| values.iter().map(|v| (v - mean).powi(k)).sum::<f64>() / n;
+        Some(Self {
+            n,
+            mean,
+            m2: central(2),
+            m3: central(3),
+            m4: central(4),
+        })
+    }
+
+    fn std(&self) -> Option<f64> {
+        (self.n >= 2.0).then(|| (self.m2 * self.n / (self.n - 1.0)).sqrt())
+    }
+
+    /// Coefficient d'asymétrie de Fisher-Pearson ajusté (G1).
+    fn skewness(&self) -> Option<f64> {
+        let n = self.n;
+        if n < 3.0 {
+            return None;
+        }
+        if self.m2 == 0.0 {
+            return Some(0.0);
+        }
+        let g1 = self.m3 / self.m2.powf(1.5);
+        Some(g1 * (n * (n - 1.0)).sqrt() / (n - 2.0))
+    }
+
+    /// Kurtosis en excès ajustée (G2), nulle pour une loi normale.
+    fn kurtosis(&self) -> Option<f64> {
+        let n = self.n;
+        if n < 4.0 {
+            return None;
+        }
+        if self.m2 == 0.0 {
+            return Some(0.0);
+        }
+        let g2 = self.m4 / self.m2.powi(2) - 3.0;
+        Some((n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0))
+    }
+}
+
+/// Quantile `q` de valeurs triées, par interpolation linéaire entre les deux rangs voisins.
+fn quantile(sorted: &[f64], q: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let position = q * last as f64;
+    let (below, fraction) = (position.floor() as usize, position.fract());
+    let above = (below + 1).min(last);
+    Some(sorted[below] + fraction * (sorted[above] - sorted[below]))
+}
+
+/// Résume chaque colonne d'une matrice de features, nommées par `names`.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::describe::describe;
+///
+/// let x = array![[1.0, 10.0], [3.0, f64::NAN]];
+/// let summaries = describe(&x, &["a", "b"]).unwrap();
+/// assert_eq!(summaries[0].mean, Some(2.0));
+/// assert_eq!(summaries[1].missing, 1);
+/// assert!(describe(&x, &["a"]).is_err());
+/// ```
+pub fn describe(x: &Array2<f64>, names: &[&str]) -> Result<Vec<ColumnSummary>, ModelError> {
+    if x.ncols() != names.len() {
+        return Err(ModelError::ShapeMismatch(format!(
+            "{} colonnes mais {} noms",
+            x.ncols(),
+            names.len()
+        )));
+    }
+    Ok(names
+        .iter()
+        .zip(x.columns())
+        .map(|(name, column)| ColumnSummary::numeric(name, column))
+        .collect())
+}
+
+/// Résume toutes les colonnes d'un `DataFrame` Polars : les colonnes numériques
+/// sont converties en `f64` (les `null` deviennent des valeurs manquantes), les
+/// autres sont traitées comme catégorielles.
+pub fn describe_dataframe(df: &DataFrame) -> PolarsResult<Vec<ColumnSummary>> {
+    df.get_columns()
+        .iter()
+        .map(|column| {
+            let series = column.as_materialized_series();
+            let name = series.name().to_string();
+            if series.dtype().is_primitive_numeric() {
+                let values: Vec<f64> = series
+                    .cast(&DataType::Float64)?
+                    .f64()?
+                    .into_iter()
+                    .map(|v| v.unwrap_or(f64::NAN))
+                    .collect();
+                Ok(ColumnSummary::numeric(&name, ArrayView1::from(&values)))
+            } else {
+                let missing = series.null_count();
+                // n_unique compte aussi null comme une valeur
+                let unique = series.n_unique()? - usize::from(missing > 0);
+                Ok(ColumnSummary::categorical(
+                    &name,
+                    series.len() - missing,
+                    missing,
+                    unique,
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Tableau texte des résumés, une ligne par colonne.
+pub fn format_table(summaries: &[ColumnSummary]) -> String {
+    let width = summaries
+        .iter()
+        .map(|s| s.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("colonne".len());
+    let headers = [
+        "count", "missing", "mean", "std", "min", "25%", "50%", "75%", "max", "skew", "kurt",
+        "unique",
+    ];
+    let mut table = format!("{:<width$}", "colonne");
+    for header in headers {
+        table.push_str(&format!(" {:>10}", header));
+    }
+    for s in summaries {
+        table.push_str(&format!(
+            "\n{:<width$} {:>10} {:>10}",
+            s.name, s.count, s.missing
+        ));
+        for value in [
+            s.mean, s.std, s.min, s.q1, s.median, s.q3, s.max, s.skewness, s.kurtosis,
+        ] {
+            match value {
+                Some(v) => table.push_str(&format!(" {:>10.3}", v)),
+                None => table.push_str(&format!(" {:>10}", "-")),
+            }
+        }
+        table.push_str(&format!(" {:>10}", s.unique));
+    }
+    table
+}
+
+/// Exporte les résumés en CSV, une ligne par colonne (cellule vide si non défini).
+pub fn write_csv(summaries: &[ColumnSummary], path: impl AsRef<Path>) -> Result<(), DataError> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for summary in summaries {
+        writer.serialize(summary)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Exporte les résumés en JSON (un tableau d'objets, `null` si non défini).
+pub fn write_json(summaries: &[ColumnSummary], path: impl AsRef<Path>) -> Result<(), DataError> {
+    let writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(writer, summaries).map_err(io::Error::from)?;
+    Ok(())
+}
@@ -0,0 +1,423 @@
+//! Analyse discriminante linéaire (LDA) et quadratique (QDA).
+//!
+//! Chaque classe est modélisée par une loi normale multivariée N(μₖ, Σₖ) ; la classe
+//! prédite maximise la probabilité a posteriori par la règle de Bayes.
+//!
+//! - [`LinearDiscriminantAnalysis`] : covariance commune Σ à toutes les classes,
+//!   d'où des frontières linéaires. Sert aussi de réduction de dimension supervisée.
+//! - [`QuadraticDiscriminantAnalysis`] : une covariance par classe, d'où des
+//!   frontières quadratiques.
+
+use nalgebra::{DMatrix, SymmetricEigen};
+use ndarray::{Array1, Array2, Axis, s};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, class_log_prior, unique_classes};
+
+// Valeurs propres relatives en dessous desquelles une direction est considérée comme dégénérée
+const RANK_TOL: f64 = 1e-10;
+
+/// Analyse discriminante linéaire.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::discriminant_analysis::LinearDiscriminantAnalysis;
+///
+/// let x = array![[1.0, 2.0], [1.5, 1.8], [2.0, 2.2], [6.0, 8.0], [6.5, 7.5], [7.0, 8.2]];
+/// let y = array![0, 0, 0, 1, 1, 1];
+/// let mut lda = LinearDiscriminantAnalysis::new();
+/// lda.fit(&x, &y).unwrap();
+/// assert_eq!(lda.predict(&array![[1.2, 2.1], [6.8, 7.9]]).unwrap(), array![0, 1]);
+/// // Deux classes : une seule composante discriminante
+/// assert_eq!(lda.transform(&x).unwrap().ncols(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LinearDiscriminantAnalysis {
+    n_components: Option<usize>,
+    priors: Option<Vec<f64>>,
+    classes: Vec<usize>,
+    class_log_prior: Array1<f64>,
+    means: Array2<f64>,
+    overall_mean: Array1<f64>,
+    // Blanchiment de la covariance commune : (x W) a une covariance intra-classe identité
+    whitening: Array2<f64>,
+    // Directions discriminantes, une par colonne (n_features × n_components)
+    scalings: Array2<f64>,
+    explained_variance_ratio: Array1<f64>,
+}
+
+impl LinearDiscriminantAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nombre de composantes gardées par `transform` (au plus n_classes - 1, valeur par défaut).
+    pub fn with_n_components(mut self, n_components: usize) -> Self {
+        self.n_components = Some(n_components);
+        self
+    }
+
+    /// Probabilités a priori des classes (fréquences observées par défaut).
+    pub fn with_priors(mut self, priors: Vec<f64>) -> Self {
+        self.priors = Some(priors);
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        let stats = ClassStats::new(x, y)?;
+        let n_classes = stats.classes.len();
+        if x.nrows() <= n_classes {
+            return Err(ModelError::InvalidParameter(
+                "il faut plus d'observations que de classes".to_string(),
+            ));
+        }
+
+        // Covariance intra-classe commune : Σ = Σₖ Σᵢ∈ₖ (xᵢ - μₖ)(xᵢ - μₖ)ᵀ / (n - K)
+        let centered = x - &stats.means.select(Axis(0), &stats.encoded);
+        let within = centered.t().dot(&centered) / (x.nrows() - n_classes) as f64;
+
+        // Σ = U D Uᵀ, donc W = U D^(-1/2) blanchit les données (directions dégénérées ignorées)
+        let (values, vectors) = sorted_eigen(&within);
+        let max_value = values.first().copied().unwrap_or(0.0);
+        let rank = values
+            .iter()
+            .take_while(|&&v| v > RANK_TOL * max_value)
+            .count();
+        if rank == 0 {
+            return Err(ModelError::InvalidParameter(
+                "la covariance intra-classe est nulle".to_string(),
+            ));
+        }
+        let whitening = Array2::from_shape_fn((x.ncols(), rank), |(j, k)| {
+            vectors[[j, k]] / values[k].sqrt()
+        });
+
+        // Directions discriminantes : axes principaux de la dispersion inter-classes,
+        // dans l'espace blanchi (problème aux valeurs propres généralisé Σ_b v = λ Σ v)
+        let overall_mean = x.mean_axis(Axis(0)).ok_or(ModelError::EmptyDataset)?;
+        let whitened_means = (&stats.means - &overall_mean).dot(&whitening);
+        let weighted = &whitened_means * &stats.counts.mapv(f64::sqrt).insert_axis(Axis(1));
+        let between = weighted.t().dot(&weighted) / x.nrows() as f64;
+        let (between_values, between_vectors) = sorted_eigen(&between);
+
+        let max_components = (n_classes - 1).min(rank);
+        let n_components = self.n_components.unwrap_or(max_components);
+        if n_components == 0 || n_components > max_components {
+            return Err(ModelError::InvalidParameter(format!(
+                "n_components = {} doit être entre 1 et {}",
+                n_components, max_components
+            )));
+        }
+        let mut scalings = whitening.dot(&between_vectors.slice(s![.., ..n_components]));
+        flip_signs(&mut scalings);
+        let discriminant_values: Array1<f64> = between_values
+            .iter()
+            .take(max_components)
+            .map(|v| v.max(0.0))
+            .collect();
+        let total = discriminant_values.sum();
+
+        self.class_log_prior = class_log_prior(
+            self.priors.as_deref(),
+            stats.counts.as_slice().expect("contigu"),
+            true,
+        )?;
+        self.classes = stats.classes;
+        self.means = stats.means;
+        self.overall_mean = overall_mean;
+        self.whitening = whitening;
+        self.scalings = scalings;
+        self.explained_variance_ratio = if total > 0.0 {
+            discriminant_values
+                .slice(s![..n_components])
+                .mapv(|v| v / total)
+        } else {
+            Array1::zeros(n_components)
+        };
+        Ok(())
+    }
+
+    /// Log-probabilités a posteriori non normalisées : -½ (x - μₖ)ᵀ Σ⁻¹ (x - μₖ) + ln πₖ.
+    pub fn decision_function(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.means.ncols(), x.ncols())?;
+        let z = x.dot(&self.whitening);
+        let whitened_means = self.means.dot(&self.whitening);
+        Ok(Array2::from_shape_fn(
+            (x.nrows(), self.classes.len()),
+            |(i, k)| {
+                let distance: f64 = z
+                    .row(i)
+                    .iter()
+                    .zip(whitened_means.row(k))
+                    .map(|(a, b)| (a - b) * (a - b))
+                    .sum();
+                -0.5 * distance + self.class_log_prior[k]
+            },
+        ))
+    }
+
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        Ok(softmax_rows(self.decision_function(x)?))
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        Ok(predict_from_scores(
+            &self.decision_function(x)?,
+            &self.classes,
+        ))
+    }
+
+    /// Projette les observations sur les directions discriminantes.
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.means.ncols(), x.ncols())?;
+        Ok((x - &self.overall_mean).dot(&self.scalings))
+    }
+
+    pub fn fit_transform(
+        &mut self,
+        x: &Array2<f64>,
+        y: &Array1<usize>,
+    ) -> Result<Array2<f64>, ModelError> {
+        self.fit(x, y)?;
+        self.transform(x)
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    /// Moyenne de chaque classe (une ligne par classe).
+    pub fn means(&self) -> &Array2<f64> {
+        &self.means
+    }
+
+    /// Directions discriminantes, une par colonne.
+    pub fn scalings(&self) -> &Array2<f64> {
+        &self.scalings
+    }
+
+    /// Part de la dispersion inter-classes portée par chaque direction discriminante.
+    pub fn explained_variance_ratio(&self) -> &Array1<f64> {
+        &self.explained_variance_ratio
+    }
+}
+
+/// Analyse discriminante quadratique.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuadraticDiscriminantAnalysis {
+    reg_param: f64,
+    priors: Option<Vec<f64>>,
+    classes: Vec<usize>,
+    class_log_prior: Array1<f64>,
+    means: Array2<f64>,
+    // Σₖ = Uₖ diag(sₖ) Uₖᵀ pour chaque classe
+    rotations: Vec<Array2<f64>>,
+    scalings: Vec<Array1<f64>>,
+}
+
+impl QuadraticDiscriminantAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Régularisation Σₖ ← (1 - r) Σₖ + r I, utile quand une classe a des features
+    /// colinéaires ou moins d'observations que de features (0 par défaut).
+    pub fn with_reg_param(mut self, reg_param: f64) -> Self {
+        self.reg_param = reg_param;
+        self
+    }
+
+    /// Probabilités a priori des classes (fréquences observées par défaut).
+    pub fn with_priors(mut self, priors: Vec<f64>) -> Self {
+        self.priors = Some(priors);
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        if !(0.0..=1.0).contains(&self.reg_param) {
+            return Err(ModelError::InvalidParameter(
+                "reg_param doit être dans [0, 1]".to_string(),
+            ));
+        }
+        let stats = ClassStats::new(x, y)?;
+
+        let mut rotations = Vec::with_capacity(stats.classes.len());
+        let mut scalings = Vec::with_capacity(stats.classes.len());
+        for (k, class) in stats.classes.iter().enumerate() {
+            let rows: Vec<usize> = (0..y.len()).filter(|&i| stats.encoded[i] == k).collect();
+            if rows.len() < 2 {
+                return Err(ModelError::InvalidParameter(format!(
+                    "la classe {} a moins de deux observations",
+                    class
+                )));
+            }
+            let centered = &x.select(Axis(0), &rows) - &stats.means.row(k);
+            let covariance = centered.t().dot(&centered) / (rows.len() - 1) as f64
+                * (1.0 - self.reg_param)
+                + Array2::<f64>::eye(x.ncols()) * self.reg_param;
+            let (values, vectors) = sorted_eigen(&covariance);
+            if values
+                .iter()
+                .any(|&v| v <= RANK_TOL * values[0].max(f64::MIN_POSITIVE))
+            {
+                return Err(ModelError::InvalidParameter(format!(
+                    "covariance singulière pour la classe {} : augmenter reg_param",
+                    class
+                )));
+            }
+            rotations.push(vectors);
+            scalings.push(values);
+        }
+
+        self.class_log_prior = class_log_prior(
+            self.priors.as_deref(),
+            stats.counts.as_slice().expect("contigu"),
+            true,
+        )?;
+        self.classes = stats.classes;
+        self.means = stats.means;
+        self.rotations = rotations;
+        self.scalings = scalings;
+        Ok(())
+    }
+
+    /// Log-probabilités a posteriori non normalisées :
+    /// -½ ln|Σₖ| - ½ (x - μₖ)ᵀ Σₖ⁻¹ (x - μₖ) + ln πₖ.
+    pub fn decision_function(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.means.ncols(), x.ncols())?;
+        let mut scores = Array2::zeros((x.nrows(), self.classes.len()));
+        for (k, (rotation, scaling)) in self.rotations.iter().zip(&self.scalings).enumerate() {
+            // Dans la base propre de Σₖ, la distance de Mahalanobis est une somme pondérée
+            let z = (x - &self.means.row(k)).dot(rotation);
+            let log_det: f64 = scaling.mapv(f64::ln).sum();
+            for (i, row) in z.rows().into_iter().enumerate() {
+                let mahalanobis: f64 = row.iter().zip(scaling).map(|(v, s)| v * v / s).sum();
+                scores[[i, k]] = -0.5 * (log_det + mahalanobis) + self.class_log_prior[k];
+            }
+        }
+        Ok(scores)
+    }
+
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        Ok(softmax_rows(self.decision_function(x)?))
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        Ok(predict_from_scores(
+            &self.decision_function(x)?,
+            &self.classes,
+        ))
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    /// Moyenne de chaque classe (une ligne par classe).
+    pub fn means(&self) -> &Array2<f64> {
+        &self.means
+    }
+
+    /// Matrice de covariance estimée pour la classe d'indice `k`.
+    pub fn covariance(&self, k: usize) -> Option<Array2<f64>> {
+        let (rotation, scaling) = (self.rotations.get(k)?, self.scalings.get(k)?);
+        Some((rotation * scaling).dot(&rotation.t()))
+    }
+}
+
+/// Classes, effectifs et moyennes par classe.
+struct ClassStats {
+    classes: Vec<usize>,
+    // Indice de la classe de chaque observation
+    encoded: Vec<usize>,
+    counts: Array1<f64>,
+    means: Array2<f64>,
+}
+
+impl ClassStats {
+    fn new(x: &Array2<f64>, y: &Array1<usize>) -> Result<Self, ModelError> {
+        let classes = unique_classes(y);
+        if classes.len() < 2 {
+            return Err(ModelError::InvalidParameter(
+                "au moins deux classes sont nécessaires".to_string(),
+            ));
+        }
+        let encoded: Vec<usize> = y
+            .iter()
+            .map(|c| {
+                classes
+                    .binary_search(c)
+                    .expect("classe vue pendant unique_classes")
+            })
+            .collect();
+        let mut counts = Array1::zeros(classes.len());
+        let mut means = Array2::zeros((classes.len(), x.ncols()));
+        for (row, &k) in x.rows().into_iter().zip(&encoded) {
+            counts[k] += 1.0;
+            let mut mean = means.row_mut(k);
+            mean += &row;
+        }
+        means /= &counts.view().insert_axis(Axis(1));
+        Ok(Self {
+            classes,
+            encoded,
+            counts,
+            means,
+        })
+    }
+}
+
+/// Valeurs et vecteurs propres (en colonnes) d'une matrice symétrique, par valeur propre décroissante.
+fn sorted_eigen(matrix: &Array2<f64>) -> (Array1<f64>, Array2<f64>) {
+    let n = matrix.nrows();
+    let eigen = SymmetricEigen::new(DMatrix::from_fn(n, n, |i, j| matrix[[i, j]]));
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| eigen.eigenvalues[b].total_cmp(&eigen.eigenvalues[a]));
+    let values = order.iter().map(|&k| eigen.eigenvalues[k]).collect();
+    let vectors = Array2::from_shape_fn((n, n), |(i, k)| eigen.eigenvectors[(i, order[k])]);
+    (values, vectors)
+}
+
+/// Le plus grand coefficient (en valeur absolue) de chaque colonne est rendu positif.
+fn flip_signs(vectors: &mut Array2<f64>) {
+    for mut column in vectors.columns_mut() {
+        let largest = column
+            .iter()
+            .fold(0.0_f64, |acc, &v| if v.abs() > acc.abs() { v } else { acc });
+        if largest < 0.0 {
+            column.mapv_inplace(|v| -v);
+        }
+    }
+}
+
+/// Normalise des log-probabilités en probabilités, ligne par ligne.
+fn softmax_rows(mut scores: Array2<f64>) -> Array2<f64> {
+    for mut row in scores.rows_mut() {
+        let max = row.fold(f64::NEG_INFINITY, |acc, &v| acc.max(v));
+        row.mapv_inplace(|v| (v - max).exp());
+        let sum = row.sum();
+        row /= sum;
+    }
+    scores
+}
+
+fn predict_from_scores(scores: &Array2<f64>, classes: &[usize]) -> Array1<usize> {
+    scores
+        .rows()
+        .into_iter()
+        .map(|row| classes[argmax(row.iter().copied())])
+        .collect()
+}
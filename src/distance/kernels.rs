@@ -0,0 +1,97 @@
+//! Noyaux de calcul bas niveau (produit scalaire, distance euclidienne au carré)
+//! pour `f32` et `f64`.
+//!
+//! Une boucle `zip().map().sum()` sur des flottants ne peut pas être vectorisée par le
+//! compilateur : l'addition flottante n'est pas associative, il doit donc respecter
+//! l'ordre des opérations. Ici on découpe les vecteurs en blocs de [`LANES`] éléments
+//! et on garde un accumulateur par position dans le bloc : les accumulateurs sont
+//! indépendants, LLVM peut les placer dans des registres SIMD (SSE/AVX/NEON).
+
+use std::ops::{Add, Mul, Sub};
+
+use super::DistanceError;
+
+/// Nombre d'accumulateurs indépendants (8 f32 = un registre AVX, 8 f64 = deux registres AVX).
+pub const LANES: usize = 8;
+
+/// Type flottant supporté par les noyaux (`f32` ou `f64`).
+pub trait KernelFloat:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Send + Sync
+{
+    const ZERO: Self;
+}
+
+impl KernelFloat for f32 {
+    const ZERO: Self = 0.0;
+}
+
+impl KernelFloat for f64 {
+    const ZERO: Self = 0.0;
+}
+
+/// Produit scalaire x · y.
+///
+/// # Example
+/// ```
+/// use rust_first_steps::distance::kernels::dot;
+///
+/// let x = [1.0_f32, 2.0, 3.0];
+/// let y = [4.0_f32, 5.0, 6.0];
+/// assert_eq!(dot(&x, &y).unwrap(), 32.0);
+/// ```
+pub fn dot<T: KernelFloat>(x: &[T], y: &[T]) -> Result<T, DistanceError> {
+    check_len(x, y)?;
+    Ok(chunked_sum(x, y, |a, b| a * b))
+}
+
+/// Distance euclidienne au carré Σ (xᵢ - yᵢ)².
+pub fn squared_euclidean<T: KernelFloat>(x: &[T], y: &[T]) -> Result<T, DistanceError> {
+    check_len(x, y)?;
+    Ok(chunked_sum(x, y, |a, b| {
+        let diff = a - b;
+        diff * diff
+    }))
+}
+
+/// Somme de `f(xᵢ, yᵢ)` avec [`LANES`] accumulateurs indépendants.
+#[inline(always)]
+fn chunked_sum<T, F>(x: &[T], y: &[T], f: F) -> T
+where
+    T: KernelFloat,
+    F: Fn(T, T) -> T,
+{
+    let x_chunks = x.chunks_exact(LANES);
+    let y_chunks = y.chunks_exact(LANES);
+    let (x_rest, y_rest) = (x_chunks.remainder(), y_chunks.remainder());
+
+    let mut acc = [T::ZERO; LANES];
+    for (xs, ys) in x_chunks.zip(y_chunks) {
+        // La taille des blocs est connue à la compilation : pas de vérification de bornes
+        for k in 0..LANES {
+            acc[k] = acc[k] + f(xs[k], ys[k]);
+        }
+    }
+
+    // Réduction des accumulateurs deux à deux, puis des éléments restants
+    let mut width = LANES;
+    while width > 1 {
+        width /= 2;
+        for k in 0..width {
+            acc[k] = acc[k] + acc[k + width];
+        }
+    }
+    x_rest
+        .iter()
+        .zip(y_rest)
+        .fold(acc[0], |total, (&a, &b)| total + f(a, b))
+}
+
+fn check_len<T>(x: &[T], y: &[T]) -> Result<(), DistanceError> {
+    if x.len() != y.len() {
+        return Err(DistanceError::DimensionMismatch {
+            left: x.len(),
+            right: y.len(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array2, ArrayView2};
+
+use super::{Distance, DistanceError, check_dims, kernels, pairwise};
+
+/// Distance euclidienne (norme ℓ2) : sqrt(Σ (xᵢ - yᵢ)²).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Euclidean;
+
+impl Distance for Euclidean {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        Ok(SquaredEuclidean.eval(x, y)?.sqrt())
+    }
+
+    fn pairwise(
+        &self,
+        x: ArrayView2<'_, f64>,
+        y: ArrayView2<'_, f64>,
+    ) -> Result<Array2<f64>, DistanceError> {
+        pairwise::euclidean_gram(x, y, false)
+    }
+}
+
+/// Distance euclidienne au carré : Σ (xᵢ - yᵢ)².
+///
+/// Évite la racine carrée quand seul l'ordre des distances compte (k-means, KNN...).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SquaredEuclidean;
+
+impl Distance for SquaredEuclidean {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        kernels::squared_euclidean(x, y)
+    }
+
+    fn pairwise(
+        &self,
+        x: ArrayView2<'_, f64>,
+        y: ArrayView2<'_, f64>,
+    ) -> Result<Array2<f64>, DistanceError> {
+        pairwise::euclidean_gram(x, y, true)
+    }
+}
+
+/// Distance de Manhattan (norme ℓ1) : Σ |xᵢ - yᵢ|.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Manhattan;
+
+impl Distance for Manhattan {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        check_dims(x, y)?;
+        Ok(x.iter().zip(y).map(|(xi, yi)| (xi - yi).abs()).sum())
+    }
+}
+
+/// Distance de Chebyshev (norme ℓ∞) : maxᵢ |xᵢ - yᵢ|.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Chebyshev;
+
+impl Distance for Chebyshev {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        check_dims(x, y)?;
+        Ok(x.iter()
+            .zip(y)
+            .map(|(xi, yi)| (xi - yi).abs())
+            .fold(0.0, f64::max))
+    }
+}
+
+/// Distance de Minkowski d'ordre p : (Σ |xᵢ - yᵢ|^p)^(1/p).
+///
+/// p = 1 correspond à Manhattan, p = 2 à la distance euclidienne et p = ∞ à Chebyshev.
+#[derive(Debug, Clone, Copy)]
+pub struct Minkowski {
+    p: f64,
+}
+
+impl Minkowski {
+    /// Crée une distance de Minkowski, p doit être supérieur ou égal à 1
+    /// (en dessous, l'inégalité triangulaire n'est plus respectée).
+    pub fn new(p: f64) -> Result<Self, DistanceError> {
+        if p.is_nan() || p < 1.0 {
+            return Err(DistanceError::InvalidParameter(format!(
+                "Minkowski attend p >= 1, reçu {}",
+                p
+            )));
+        }
+        Ok(Self { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Distance for Minkowski {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        if self.p.is_infinite() {
+            return Chebyshev.eval(x, y);
+        }
+        check_dims(x, y)?;
+        let sum: f64 = x
+            .iter()
+            .zip(y)
+            .map(|(xi, yi)| (xi - yi).abs().powf(self.p))
+            .sum();
+        Ok(sum.powf(1.0 / self.p))
+    }
+}
+
+/// Distance cosinus : 1 - (x · y) / (‖x‖ ‖y‖).
+///
+/// Vaut 0 pour deux vecteurs de même direction et 2 pour deux vecteurs opposés.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cosine;
+
+impl Distance for Cosine {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        check_dims(x, y)?;
+        let (dot, norm_x, norm_y) = x
+            .iter()
+            .zip(y)
+            .fold((0.0, 0.0, 0.0), |(dot, nx, ny), (xi, yi)| {
+                (dot + xi * yi, nx + xi * xi, ny + yi * yi)
+            });
+        if norm_x == 0.0 || norm_y == 0.0 {
+            return Err(DistanceError::Undefined(
+                "la distance cosinus n'est pas définie pour un vecteur nul".to_string(),
+            ));
+        }
+        Ok(1.0 - dot / (norm_x.sqrt() * norm_y.sqrt()))
+    }
+}
+
+/// Distance de Hamming : proportion de coordonnées différentes entre les deux vecteurs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hamming;
+
+impl Distance for Hamming {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        check_dims(x, y)?;
+        if x.is_empty() {
+            return Err(DistanceError::Undefined(
+                "la distance de Hamming n'est pas définie pour des vecteurs vides".to_string(),
+            ));
+        }
+        let n_diff = x.iter().zip(y).filter(|(xi, yi)| xi != yi).count();
+        Ok(n_diff as f64 / x.len() as f64)
+    }
+}
+
+/// Distance de Mahalanobis : sqrt((x - y)ᵀ S⁻¹ (x - y)), avec S la matrice de covariance.
+///
+/// Tient compte de l'échelle et de la corrélation des features, contrairement à la
+/// distance euclidienne.
+#[derive(Debug, Clone)]
+pub struct Mahalanobis {
+    inv_cov: DMatrix<f64>,
+}
+
+impl Mahalanobis {
+    /// Crée la métrique à partir de l'inverse de la matrice de covariance.
+    pub fn new(inv_cov: DMatrix<f64>) -> Result<Self, DistanceError> {
+        if !inv_cov.is_square() {
+            return Err(DistanceError::InvalidParameter(format!(
+                "la matrice de covariance inverse doit être carrée ({}x{})",
+                inv_cov.nrows(),
+                inv_cov.ncols()
+            )));
+        }
+        Ok(Self { inv_cov })
+    }
+
+    /// Estime la matrice de covariance à partir des données (une ligne par observation)
+    /// puis l'inverse.
+    pub fn from_data(data: &DMatrix<f64>) -> Result<Self, DistanceError> {
+        let n_rows = data.nrows();
+        if n_rows < 2 {
+            return Err(DistanceError::InvalidParameter(
+                "au moins deux observations sont nécessaires pour estimer la covariance"
+                    .to_string(),
+            ));
+        }
+        let mean = data.row_mean();
+        let mut centered = data.clone();
+        for mut row in centered.row_iter_mut() {
+            row -= &mean;
+        }
+        let cov = centered.transpose() * &centered / (n_rows as f64 - 1.0);
+        let inv_cov = cov.try_inverse().ok_or_else(|| {
+            DistanceError::InvalidParameter(
+                "la matrice de covariance n'est pas inversible".to_string(),
+            )
+        })?;
+        Self::new(inv_cov)
+    }
+
+    pub fn inv_cov(&self) -> &DMatrix<f64> {
+        &self.inv_cov
+    }
+}
+
+impl Distance for Mahalanobis {
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+        check_dims(x, y)?;
+        if x.len() != self.inv_cov.nrows() {
+            return Err(DistanceError::DimensionMismatch {
+                left: x.len(),
+                right: self.inv_cov.nrows(),
+            });
+        }
+        let diff = DVector::from_iterator(x.len(), x.iter().zip(y).map(|(xi, yi)| xi - yi));
+        // max(0) protège contre les petites valeurs négatives dues aux erreurs d'arrondi
+        Ok(diff.dot(&(&self.inv_cov * &diff)).max(0.0).sqrt())
+    }
+}
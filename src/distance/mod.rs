@@ -0,0 +1,164 @@
+//! Métriques de distance entre vecteurs.
+//!
+//! Toutes les métriques implémentent le trait [`Distance`] et acceptent aussi bien
+//! des slices que des `Array1` (ndarray) ou des `DVector` (nalgebra) grâce au trait
+//! [`AsVector`]. Les erreurs (tailles différentes, paramètre invalide...) sont
+//! remontées via [`DistanceError`] au lieu de faire paniquer le programme.
+//!
+//! [`pairwise_distances`] calcule la matrice de distances complète entre deux jeux
+//! d'observations, et [`kernels`] regroupe les boucles vectorisables (f32 et f64)
+//! sur lesquelles reposent les métriques euclidiennes.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use nalgebra::DVector;
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+
+pub mod kernels;
+mod metrics;
+mod pairwise;
+
+pub use metrics::{
+    Chebyshev, Cosine, Euclidean, Hamming, Mahalanobis, Manhattan, Minkowski, SquaredEuclidean,
+};
+pub use pairwise::pairwise_distances;
+
+/// Erreurs possibles lors du calcul d'une distance.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistanceError {
+    /// Les deux vecteurs n'ont pas la même taille.
+    DimensionMismatch { left: usize, right: usize },
+    /// Un paramètre de la métrique est invalide (p < 1 pour Minkowski, matrice non carrée...).
+    InvalidParameter(String),
+    /// La distance n'est pas définie pour ces vecteurs (vecteur nul pour le cosinus...).
+    Undefined(String),
+}
+
+impl fmt::Display for DistanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistanceError::DimensionMismatch { left, right } => write!(
+                f,
+                "les vecteurs doivent avoir la même taille ({} != {})",
+                left, right
+            ),
+            DistanceError::InvalidParameter(msg) => write!(f, "paramètre invalide : {}", msg),
+            DistanceError::Undefined(msg) => write!(f, "distance non définie : {}", msg),
+        }
+    }
+}
+
+impl Error for DistanceError {}
+
+/// Vue d'un vecteur sous forme de slice de f64.
+///
+/// Évite de dupliquer chaque métrique pour les slices, ndarray et nalgebra :
+/// les types contigus sont simplement empruntés, les autres sont copiés.
+pub trait AsVector {
+    fn as_values(&self) -> Cow<'_, [f64]>;
+}
+
+impl AsVector for [f64] {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsVector for Vec<f64> {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        Cow::Borrowed(self.as_slice())
+    }
+}
+
+impl<const N: usize> AsVector for [f64; N] {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        Cow::Borrowed(self.as_slice())
+    }
+}
+
+impl AsVector for Array1<f64> {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        // as_slice() renvoie None si le tableau n'est pas contigu en mémoire (ex : une colonne)
+        match self.as_slice() {
+            Some(values) => Cow::Borrowed(values),
+            None => Cow::Owned(self.to_vec()),
+        }
+    }
+}
+
+impl AsVector for ArrayView1<'_, f64> {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        match self.as_slice() {
+            Some(values) => Cow::Borrowed(values),
+            None => Cow::Owned(self.to_vec()),
+        }
+    }
+}
+
+impl AsVector for DVector<f64> {
+    fn as_values(&self) -> Cow<'_, [f64]> {
+        Cow::Borrowed(self.as_slice())
+    }
+}
+
+/// Une métrique de distance entre deux vecteurs de même taille.
+pub trait Distance {
+    /// Calcule la distance entre deux slices.
+    ///
+    /// # Returns
+    /// * `DistanceError::DimensionMismatch` si les slices n'ont pas la même taille.
+    fn eval(&self, x: &[f64], y: &[f64]) -> Result<f64, DistanceError>;
+
+    /// Calcule la distance entre deux vecteurs quelconques (slice, `Array1`, `DVector`...).
+    fn distance<A, B>(&self, x: &A, y: &B) -> Result<f64, DistanceError>
+    where
+        A: AsVector + ?Sized,
+        B: AsVector + ?Sized,
+        Self: Sized,
+    {
+        self.eval(&x.as_values(), &y.as_values())
+    }
+
+    /// Calcule la matrice des distances entre les lignes de `x` et celles de `y`.
+    ///
+    /// Par défaut les lignes sont calculées en parallèle avec `eval` ; une métrique
+    /// peut fournir un calcul plus rapide (ex : produit matriciel pour l'euclidienne).
+    fn pairwise(
+        &self,
+        x: ArrayView2<'_, f64>,
+        y: ArrayView2<'_, f64>,
+    ) -> Result<Array2<f64>, DistanceError>
+    where
+        Self: Sync,
+    {
+        pairwise::rowwise(self, x, y)
+    }
+}
+
+/// Calcule la distance euclidienne entre deux vecteurs de même taille.
+///
+/// # Example
+/// ```
+/// use rust_first_steps::distance::euclidean_distance;
+///
+/// let x = vec![1.0, 2.0];
+/// let y = vec![4.0, 6.0];
+/// let dist = euclidean_distance(&x, &y).unwrap();
+/// assert!((dist - 5.0).abs() < 1e-6);
+/// ```
+pub fn euclidean_distance(x: &[f64], y: &[f64]) -> Result<f64, DistanceError> {
+    Euclidean.eval(x, y)
+}
+
+/// Vérifie que deux vecteurs ont la même taille.
+pub(crate) fn check_dims(x: &[f64], y: &[f64]) -> Result<(), DistanceError> {
+    if x.len() != y.len() {
+        return Err(DistanceError::DimensionMismatch {
+            left: x.len(),
+            right: y.len(),
+        });
+    }
+    Ok(())
+}
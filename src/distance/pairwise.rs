@@ -0,0 +1,95 @@
+use ndarray::{Array1, Array2, ArrayView2, Zip};
+use rayon::prelude::*;
+
+use super::{AsVector, Distance, DistanceError};
+
+/// Calcule la matrice des distances entre chaque ligne de `x` et chaque ligne de `y`.
+///
+/// Le résultat est une matrice n×m où l'élément (i, j) est la distance entre `x[i]` et `y[j]`.
+/// Les lignes sont calculées en parallèle ; les distances euclidiennes passent par un
+/// produit matriciel (voir [`Distance::pairwise`]).
+///
+/// # Arguments
+/// * `x` - Matrice n×d (une observation par ligne).
+/// * `y` - Matrice m×d (une observation par ligne).
+/// * `metric` - La métrique à utiliser.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::distance::{pairwise_distances, Manhattan};
+///
+/// let x = array![[0.0, 0.0], [1.0, 1.0]];
+/// let d = pairwise_distances(&x, &x, &Manhattan).unwrap();
+/// assert_eq!(d, array![[0.0, 2.0], [2.0, 0.0]]);
+/// ```
+pub fn pairwise_distances<D>(
+    x: &Array2<f64>,
+    y: &Array2<f64>,
+    metric: &D,
+) -> Result<Array2<f64>, DistanceError>
+where
+    D: Distance + Sync + ?Sized,
+{
+    metric.pairwise(x.view(), y.view())
+}
+
+/// Implémentation générique : chaque ligne de `x` est traitée par un thread rayon.
+pub(crate) fn rowwise<D>(
+    metric: &D,
+    x: ArrayView2<'_, f64>,
+    y: ArrayView2<'_, f64>,
+) -> Result<Array2<f64>, DistanceError>
+where
+    D: Distance + Sync + ?Sized,
+{
+    check_ncols(x, y)?;
+    let (n, m) = (x.nrows(), y.nrows());
+
+    let rows: Vec<Vec<f64>> = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            let xi = x.row(i);
+            let xi = xi.as_values();
+            y.rows()
+                .into_iter()
+                .map(|yj| metric.eval(&xi, &yj.as_values()))
+                .collect::<Result<Vec<f64>, DistanceError>>()
+        })
+        .collect::<Result<_, _>>()?;
+
+    let flat: Vec<f64> = rows.into_iter().flatten().collect();
+    Ok(Array2::from_shape_vec((n, m), flat).expect("n * m distances calculées"))
+}
+
+/// Distances euclidiennes via ‖x - y‖² = ‖x‖² + ‖y‖² - 2 x·y.
+///
+/// Le terme x·yᵀ est un seul produit matriciel (gemm BLAS), bien plus rapide que
+/// n×m boucles quand d est grand.
+pub(crate) fn euclidean_gram(
+    x: ArrayView2<'_, f64>,
+    y: ArrayView2<'_, f64>,
+    squared: bool,
+) -> Result<Array2<f64>, DistanceError> {
+    check_ncols(x, y)?;
+    let x_norms: Array1<f64> = x.rows().into_iter().map(|r| r.dot(&r)).collect();
+    let y_norms: Array1<f64> = y.rows().into_iter().map(|r| r.dot(&r)).collect();
+
+    let mut dist = x.dot(&y.t());
+    Zip::indexed(&mut dist).par_for_each(|(i, j), d| {
+        // max(0) : les erreurs d'arrondi peuvent donner un carré très légèrement négatif
+        let sq = (x_norms[i] + y_norms[j] - 2.0 * *d).max(0.0);
+        *d = if squared { sq } else { sq.sqrt() };
+    });
+    Ok(dist)
+}
+
+fn check_ncols(x: ArrayView2<'_, f64>, y: ArrayView2<'_, f64>) -> Result<(), DistanceError> {
+    if x.ncols() != y.ncols() {
+        return Err(DistanceError::DimensionMismatch {
+            left: x.ncols(),
+            right: y.ncols(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,1024 @@
+//! Gradient boosting d'arbres à histogrammes.
+//!
+//! Le modèle est une somme d'arbres F(x) = f₀ + η Σₘ hₘ(x) : chaque nouvel arbre hₘ est
+//! ajusté sur le gradient de la fonction de perte par rapport aux prédictions
+//! courantes, puis ajouté avec un taux d'apprentissage η (shrinkage).
+//!
+//! Comme LightGBM ou `HistGradientBoosting` de scikit-learn, les features sont d'abord
+//! discrétisées en au plus 255 intervalles (bins). Chercher la meilleure coupure revient
+//! alors à parcourir un histogramme des gradients par feature, en O(n_bins) au lieu de
+//! trier les observations à chaque nœud.
+
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, unique_classes};
+
+/// Fonction de perte d'un modèle de régression.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RegressionLoss {
+    /// ½ (y - F)², sensible aux valeurs extrêmes.
+    #[default]
+    SquaredError,
+    /// |y - F|, robuste : le modèle estime la médiane conditionnelle.
+    AbsoluteError,
+    /// Quadratique pour |y - F| <= delta, linéaire au-delà.
+    Huber { delta: f64 },
+}
+
+impl RegressionLoss {
+    /// Gradient de la perte par rapport à la prédiction, et hessienne associée.
+    fn gradient(self, y: f64, raw: f64) -> (f64, f64) {
+        let residual = raw - y;
+        match self {
+            RegressionLoss::SquaredError => (residual, 1.0),
+            RegressionLoss::AbsoluteError => (residual.signum(), 1.0),
+            RegressionLoss::Huber { delta } => (residual.clamp(-delta, delta), 1.0),
+        }
+    }
+
+    fn loss(self, y: f64, raw: f64) -> f64 {
+        let residual = (y - raw).abs();
+        match self {
+            RegressionLoss::SquaredError => 0.5 * residual * residual,
+            RegressionLoss::AbsoluteError => residual,
+            RegressionLoss::Huber { delta } if residual <= delta => 0.5 * residual * residual,
+            RegressionLoss::Huber { delta } => delta * (residual - 0.5 * delta),
+        }
+    }
+
+    /// Prédiction initiale f₀ qui minimise la perte sur les données.
+    fn initial_prediction(self, y: &[f64]) -> f64 {
+        match self {
+            RegressionLoss::SquaredError => y.iter().sum::<f64>() / y.len() as f64,
+            RegressionLoss::AbsoluteError | RegressionLoss::Huber { .. } => median(y.to_vec()),
+        }
+    }
+
+    /// Valeur d'une feuille à partir des résidus y - F de ses observations.
+    ///
+    /// `None` : on garde le pas de Newton -G / (H + λ) calculé par l'arbre.
+    fn leaf_value(self, mut residuals: Vec<f64>) -> Option<f64> {
+        match self {
+            RegressionLoss::SquaredError => None,
+            // La médiane minimise exactement la perte absolue dans la feuille
+            RegressionLoss::AbsoluteError => Some(median(residuals)),
+            // Approximation de Friedman : médiane + moyenne des écarts écrêtés
+            RegressionLoss::Huber { delta } => {
+                let med = median(residuals.clone());
+                let n = residuals.len() as f64;
+                residuals
+                    .iter_mut()
+                    .for_each(|r| *r = (*r - med).clamp(-delta, delta));
+                Some(med + residuals.iter().sum::<f64>() / n)
+            }
+        }
+    }
+}
+
+/// Hyperparamètres communs au régresseur et au classifieur.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BoostingParams {
+    n_estimators: usize,
+    learning_rate: f64,
+    max_depth: usize,
+    min_samples_leaf: usize,
+    l2_regularization: f64,
+    subsample: f64,
+    max_bins: usize,
+    validation_fraction: f64,
+    n_iter_no_change: Option<usize>,
+    tol: f64,
+    random_state: u64,
+}
+
+impl Default for BoostingParams {
+    fn default() -> Self {
+        Self {
+            n_estimators: 100,
+            learning_rate: 0.1,
+            max_depth: 3,
+            min_samples_leaf: 20,
+            l2_regularization: 0.0,
+            subsample: 1.0,
+            max_bins: 255,
+            validation_fraction: 0.1,
+            n_iter_no_change: None,
+            tol: 1e-7,
+            random_state: 0,
+        }
+    }
+}
+
+impl BoostingParams {
+    fn validate(&self) -> Result<(), ModelError> {
+        let invalid = |msg: &str| Err(ModelError::InvalidParameter(msg.to_string()));
+        if self.n_estimators == 0 {
+            return invalid("n_estimators doit être >= 1");
+        }
+        if !(self.learning_rate > 0.0 && self.learning_rate.is_finite()) {
+            return invalid("learning_rate doit être un réel strictement positif");
+        }
+        if self.min_samples_leaf == 0 {
+            return invalid("min_samples_leaf doit être >= 1");
+        }
+        if !(self.l2_regularization >= 0.0 && self.l2_regularization.is_finite()) {
+            return invalid("l2_regularization doit être un réel positif");
+        }
+        if !(self.subsample > 0.0 && self.subsample <= 1.0) {
+            return invalid("subsample doit être dans ]0, 1]");
+        }
+        if !(2..=255).contains(&self.max_bins) {
+            return invalid("max_bins doit être entre 2 et 255");
+        }
+        if self.n_iter_no_change == Some(0) {
+            return invalid("n_iter_no_change doit être >= 1");
+        }
+        if self.n_iter_no_change.is_some()
+            && !(self.validation_fraction > 0.0 && self.validation_fraction < 1.0)
+        {
+            return invalid("validation_fraction doit être dans ]0, 1[");
+        }
+        if !(self.tol >= 0.0 && self.tol.is_finite()) {
+            return invalid("tol doit être un réel positif");
+        }
+        Ok(())
+    }
+
+    /// Sépare les indices en entraînement / validation si l'arrêt précoce est activé.
+    fn split_validation(
+        &self,
+        n_samples: usize,
+        rng: &mut StdRng,
+    ) -> Result<(Vec<usize>, Vec<usize>), ModelError> {
+        let mut indices: Vec<usize> = (0..n_samples).collect();
+        if self.n_iter_no_change.is_none() {
+            return Ok((indices, Vec::new()));
+        }
+        if n_samples < 2 {
+            return Err(ModelError::InvalidParameter(
+                "l'arrêt précoce demande au moins 2 observations (entraînement et validation)"
+                    .to_string(),
+            ));
+        }
+        indices.shuffle(rng);
+        let n_val = ((n_samples as f64 * self.validation_fraction).round() as usize)
+            .clamp(1, n_samples - 1);
+        let val = indices.split_off(n_samples - n_val);
+        Ok((indices, val))
+    }
+
+    /// Observations utilisées pour l'arbre courant (toutes, ou un sous-échantillon sans remise).
+    fn sample_rows(&self, train: &[usize], rng: &mut StdRng) -> Vec<usize> {
+        if self.subsample >= 1.0 {
+            return train.to_vec();
+        }
+        let n = ((train.len() as f64 * self.subsample).round() as usize).max(1);
+        let mut rows = train.to_vec();
+        rows.shuffle(rng);
+        rows.truncate(n);
+        rows
+    }
+}
+
+/*
+DISCRÉTISATION
+*/
+
+/// Bornes des intervalles de chaque feature, calculées sur les quantiles des données.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BinMapper {
+    // upper_bounds[j][b] = plus grande valeur du bin b de la feature j (le dernier vaut
+    // f64::MAX plutôt que +∞, que JSON ne sait pas représenter)
+    upper_bounds: Vec<Vec<f64>>,
+}
+
+impl BinMapper {
+    fn fit(x: &Array2<f64>, max_bins: usize) -> Self {
+        let upper_bounds = x
+            .columns()
+            .into_iter()
+            .map(|column| {
+                let mut values = column.to_vec();
+                values.sort_by(f64::total_cmp);
+                values.dedup();
+                let mut bounds: Vec<f64> = if values.len() <= max_bins {
+                    // Peu de valeurs distinctes : une valeur par bin
+                    values.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect()
+                } else {
+                    (1..max_bins)
+                        .map(|b| values[b * values.len() / max_bins])
+                        .collect()
+                };
+                bounds.dedup();
+                bounds.push(f64::MAX);
+                bounds
+            })
+            .collect();
+        Self { upper_bounds }
+    }
+
+    fn n_bins(&self, feature: usize) -> usize {
+        self.upper_bounds[feature].len()
+    }
+
+    /// Indice du bin de chaque valeur, stocké par feature (colonne) pour parcourir vite les histogrammes.
+    fn transform(&self, x: &Array2<f64>) -> Vec<Vec<u8>> {
+        x.columns()
+            .into_iter()
+            .zip(&self.upper_bounds)
+            .map(|(column, bounds)| {
+                column
+                    .iter()
+                    .map(|&v| bounds.partition_point(|&b| b < v).min(bounds.len() - 1) as u8)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/*
+ARBRE À HISTOGRAMMES
+*/
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum HistNode {
+    Split {
+        feature: usize,
+        // Les observations avec bin <= bin_threshold (x <= threshold) vont à gauche
+        bin_threshold: u8,
+        threshold: f64,
+        left: usize,
+        right: usize,
+    },
+    Leaf {
+        value: f64,
+    },
+}
+
+/// Arbre de régression ajusté sur les gradients, avec des coupures sur les bins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HistTree {
+    nodes: Vec<HistNode>,
+}
+
+impl HistTree {
+    fn predict_row(&self, row: ArrayView1<'_, f64>) -> f64 {
+        let mut id = 0;
+        loop {
+            match &self.nodes[id] {
+                HistNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                    ..
+                } => {
+                    id = if row[*feature] <= *threshold {
+                        *left
+                    } else {
+                        *right
+                    };
+                }
+                HistNode::Leaf { value } => return *value,
+            }
+        }
+    }
+
+    /// Valeur de la feuille atteinte par l'observation `i` des données discrétisées.
+    fn predict_binned(&self, bins: &[Vec<u8>], i: usize) -> f64 {
+        let mut id = 0;
+        loop {
+            match &self.nodes[id] {
+                HistNode::Split {
+                    feature,
+                    bin_threshold,
+                    left,
+                    right,
+                    ..
+                } => {
+                    id = if bins[*feature][i] <= *bin_threshold {
+                        *left
+                    } else {
+                        *right
+                    };
+                }
+                HistNode::Leaf { value } => return *value,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct HistBin {
+    grad: f64,
+    hess: f64,
+    count: usize,
+}
+
+struct HistTreeBuilder<'a> {
+    bins: &'a [Vec<u8>],
+    mapper: &'a BinMapper,
+    grad: &'a [f64],
+    hess: &'a [f64],
+    max_depth: usize,
+    min_samples_leaf: usize,
+    lambda: f64,
+    nodes: Vec<HistNode>,
+    // Observations de chaque feuille, pour recalculer sa valeur selon la perte
+    leaves: Vec<(usize, Vec<usize>)>,
+}
+
+impl HistTreeBuilder<'_> {
+    fn grow(&mut self, rows: Vec<usize>, depth: usize) -> usize {
+        let (g, h) = rows.iter().fold((0.0, 0.0), |(g, h), &i| {
+            (g + self.grad[i], h + self.hess[i])
+        });
+        let node_id = self.nodes.len();
+        self.nodes.push(HistNode::Leaf {
+            value: -g / (h + self.lambda),
+        });
+
+        if depth >= self.max_depth || rows.len() < 2 * self.min_samples_leaf {
+            self.leaves.push((node_id, rows));
+            return node_id;
+        }
+        let Some((feature, bin_threshold)) = self.best_split(&rows, g, h) else {
+            self.leaves.push((node_id, rows));
+            return node_id;
+        };
+
+        let (left_rows, right_rows): (Vec<usize>, Vec<usize>) = rows
+            .iter()
+            .partition(|&&i| self.bins[feature][i] <= bin_threshold);
+        let left = self.grow(left_rows, depth + 1);
+        let right = self.grow(right_rows, depth + 1);
+        self.nodes[node_id] = HistNode::Split {
+            feature,
+            bin_threshold,
+            threshold: self.mapper.upper_bounds[feature][bin_threshold as usize],
+            left,
+            right,
+        };
+        node_id
+    }
+
+    /// Meilleure coupure au sens du gain de second ordre (XGBoost) :
+    /// G_L² / (H_L + λ) + G_R² / (H_R + λ) - G² / (H + λ).
+    fn best_split(&self, rows: &[usize], g: f64, h: f64) -> Option<(usize, u8)> {
+        let parent_score = g * g / (h + self.lambda);
+        let mut best: Option<(usize, u8, f64)> = None;
+
+        for feature in 0..self.bins.len() {
+            // Histogramme des gradients : une seule passe sur les observations du nœud
+            let mut hist = vec![HistBin::default(); self.mapper.n_bins(feature)];
+            for &i in rows {
+                let bin = &mut hist[self.bins[feature][i] as usize];
+                bin.grad += self.grad[i];
+                bin.hess += self.hess[i];
+                bin.count += 1;
+            }
+
+            let mut left = HistBin::default();
+            for (b, bin) in hist.iter().enumerate().take(hist.len() - 1) {
+                left.grad += bin.grad;
+                left.hess += bin.hess;
+                left.count += bin.count;
+                let right_count = rows.len() - left.count;
+                if left.count < self.min_samples_leaf || bin.count == 0 {
+                    continue;
+                }
+                if right_count < self.min_samples_leaf {
+                    break;
+                }
+                let (right_grad, right_hess) = (g - left.grad, h - left.hess);
+                let gain = left.grad * left.grad / (left.hess + self.lambda)
+                    + right_grad * right_grad / (right_hess + self.lambda)
+                    - parent_score;
+                if gain > 1e-12 && best.is_none_or(|(_, _, best_gain)| gain > best_gain) {
+                    best = Some((feature, b as u8, gain));
+                }
+            }
+        }
+        best.map(|(feature, bin, _)| (feature, bin))
+    }
+}
+
+/// Ajuste un arbre sur les gradients des observations `rows`.
+fn fit_hist_tree(
+    bins: &[Vec<u8>],
+    mapper: &BinMapper,
+    grad: &[f64],
+    hess: &[f64],
+    rows: Vec<usize>,
+    params: &BoostingParams,
+) -> (HistTree, Vec<(usize, Vec<usize>)>) {
+    let mut builder = HistTreeBuilder {
+        bins,
+        mapper,
+        grad,
+        hess,
+        max_depth: params.max_depth,
+        min_samples_leaf: params.min_samples_leaf,
+        lambda: params.l2_regularization,
+        nodes: Vec::new(),
+        leaves: Vec::new(),
+    };
+    builder.grow(rows, 0);
+    (
+        HistTree {
+            nodes: builder.nodes,
+        },
+        builder.leaves,
+    )
+}
+
+/// Suivi de l'arrêt précoce : meilleure perte de validation et itérations sans progrès.
+struct EarlyStopping {
+    patience: Option<usize>,
+    tol: f64,
+    best_loss: f64,
+    best_iter: usize,
+}
+
+impl EarlyStopping {
+    fn new(params: &BoostingParams) -> Self {
+        Self {
+            patience: params.n_iter_no_change,
+            tol: params.tol,
+            best_loss: f64::INFINITY,
+            best_iter: 0,
+        }
+    }
+
+    /// Enregistre la perte après `n_trees` arbres, renvoie `true` s'il faut s'arrêter.
+    fn should_stop(&mut self, loss: f64, n_trees: usize) -> bool {
+        let Some(patience) = self.patience else {
+            return false;
+        };
+        if loss < self.best_loss - self.tol {
+            self.best_loss = loss;
+            self.best_iter = n_trees;
+        }
+        n_trees - self.best_iter >= patience
+    }
+}
+
+/*
+RÉGRESSION
+*/
+
+/// Gradient boosting pour la régression.
+///
+/// # Example
+/// ```
+/// use ndarray::{Array1, Array2};
+/// use rust_first_steps::ensemble::GradientBoostingRegressor;
+///
+/// let x = Array2::from_shape_fn((100, 1), |(i, _)| i as f64);
+/// let y: Array1<f64> = x.column(0).mapv(|v| 2.0 * v);
+/// let mut model = GradientBoostingRegressor::new().with_min_samples_leaf(1);
+/// model.fit(&x, &y).unwrap();
+/// let pred = model.predict(&x).unwrap();
+/// assert!((pred[50] - 100.0).abs() < 5.0);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GradientBoostingRegressor {
+    params: BoostingParams,
+    loss: RegressionLoss,
+    mapper: Option<BinMapper>,
+    init: f64,
+    trees: Vec<HistTree>,
+    n_features: usize,
+    train_loss: Vec<f64>,
+    validation_loss: Vec<f64>,
+}
+
+impl GradientBoostingRegressor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fonction de perte (erreur quadratique par défaut).
+    pub fn with_loss(mut self, loss: RegressionLoss) -> Self {
+        self.loss = loss;
+        self
+    }
+
+    pub fn with_n_estimators(mut self, n_estimators: usize) -> Self {
+        self.params.n_estimators = n_estimators;
+        self
+    }
+
+    /// Shrinkage η appliqué à chaque arbre (0.1 par défaut).
+    pub fn with_learning_rate(mut self, learning_rate: f64) -> Self {
+        self.params.learning_rate = learning_rate;
+        self
+    }
+
+    /// Profondeur de chaque arbre (3 par défaut).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.max_depth = max_depth;
+        self
+    }
+
+    /// Nombre minimal d'observations par feuille (20 par défaut).
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Régularisation L2 λ des valeurs des feuilles (0 par défaut).
+    pub fn with_l2_regularization(mut self, l2_regularization: f64) -> Self {
+        self.params.l2_regularization = l2_regularization;
+        self
+    }
+
+    /// Proportion des observations tirées (sans remise) pour chaque arbre (1.0 par défaut).
+    pub fn with_subsample(mut self, subsample: f64) -> Self {
+        self.params.subsample = subsample;
+        self
+    }
+
+    /// Nombre maximal de bins par feature (255 par défaut).
+    pub fn with_max_bins(mut self, max_bins: usize) -> Self {
+        self.params.max_bins = max_bins;
+        self
+    }
+
+    /// Active l'arrêt précoce : on met de côté `validation_fraction` des données et on
+    /// s'arrête après `n_iter_no_change` arbres sans amélioration de la perte de validation.
+    pub fn with_early_stopping(
+        mut self,
+        n_iter_no_change: usize,
+        validation_fraction: f64,
+    ) -> Self {
+        self.params.n_iter_no_change = Some(n_iter_no_change);
+        self.params.validation_fraction = validation_fraction;
+        self
+    }
+
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        if let RegressionLoss::Huber { delta } = self.loss
+            && delta <= 0.0
+        {
+            return Err(ModelError::InvalidParameter(
+                "delta doit être strictement positif".to_string(),
+            ));
+        }
+        let params = &self.params;
+        let loss = self.loss;
+        let mut rng = StdRng::seed_from_u64(params.random_state);
+        let (train, val) = params.split_validation(x.nrows(), &mut rng)?;
+
+        let mapper = BinMapper::fit(&x.select(Axis(0), &train), params.max_bins);
+        let bins = mapper.transform(x);
+        let y_train: Vec<f64> = train.iter().map(|&i| y[i]).collect();
+        let init = loss.initial_prediction(&y_train);
+
+        let mut raw = vec![init; x.nrows()];
+        let mut grad = vec![0.0; x.nrows()];
+        let mut hess = vec![0.0; x.nrows()];
+        let mut trees = Vec::new();
+        let mut train_loss = Vec::new();
+        let mut validation_loss = Vec::new();
+        let mut early_stopping = EarlyStopping::new(params);
+
+        for _ in 0..params.n_estimators {
+            for &i in &train {
+                (grad[i], hess[i]) = loss.gradient(y[i], raw[i]);
+            }
+            let rows = params.sample_rows(&train, &mut rng);
+            let (mut tree, leaves) = fit_hist_tree(&bins, &mapper, &grad, &hess, rows, params);
+
+            // Pour les pertes robustes, la valeur de chaque feuille est recalculée sur les résidus
+            for (leaf, leaf_rows) in leaves {
+                let residuals = leaf_rows.iter().map(|&i| y[i] - raw[i]).collect();
+                if let Some(value) = loss.leaf_value(residuals) {
+                    tree.nodes[leaf] = HistNode::Leaf { value };
+                }
+            }
+            for (i, r) in raw.iter_mut().enumerate() {
+                *r += params.learning_rate * tree.predict_binned(&bins, i);
+            }
+            trees.push(tree);
+
+            let mean_loss = |rows: &[usize]| {
+                rows.iter().map(|&i| loss.loss(y[i], raw[i])).sum::<f64>() / rows.len() as f64
+            };
+            train_loss.push(mean_loss(&train));
+            if !val.is_empty() {
+                validation_loss.push(mean_loss(&val));
+                if early_stopping.should_stop(mean_loss(&val), trees.len()) {
+                    break;
+                }
+            }
+        }
+
+        // On garde le nombre d'arbres qui minimise la perte de validation, et les
+        // courbes de perte correspondantes
+        if params.n_iter_no_change.is_some() {
+            let n_kept = early_stopping.best_iter.max(1);
+            trees.truncate(n_kept);
+            train_loss.truncate(n_kept);
+            validation_loss.truncate(n_kept);
+        }
+        self.mapper = Some(mapper);
+        self.init = init;
+        self.trees = trees;
+        self.n_features = x.ncols();
+        self.train_loss = train_loss;
+        self.validation_loss = validation_loss;
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        if self.mapper.is_none() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.n_features, x.ncols())?;
+        let eta = self.params.learning_rate;
+        Ok(x.rows()
+            .into_iter()
+            .map(|row| self.init + eta * self.trees.iter().map(|t| t.predict_row(row)).sum::<f64>())
+            .collect())
+    }
+
+    /// Nombre d'arbres conservés (inférieur à `n_estimators` en cas d'arrêt précoce).
+    pub fn n_trees(&self) -> usize {
+        self.trees.len()
+    }
+
+    /// Perte moyenne sur le jeu d'entraînement après chaque arbre conservé.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::{Array1, Array2, array};
+    /// use rust_first_steps::ensemble::GradientBoostingRegressor;
+    ///
+    /// let x = Array2::from_shape_fn((100, 1), |(i, _)| i as f64);
+    /// let y: Array1<f64> = x.column(0).mapv(|v| (v / 10.0).sin());
+    /// let mut model = GradientBoostingRegressor::new()
+    ///     .with_n_estimators(500)
+    ///     .with_early_stopping(5, 0.2);
+    /// model.fit(&x, &y).unwrap();
+    /// assert!(model.n_trees() < 500);
+    /// // Les courbes s'arrêtent au dernier arbre conservé
+    /// assert_eq!(model.train_loss().len(), model.n_trees());
+    /// assert_eq!(model.validation_loss().len(), model.n_trees());
+    ///
+    /// // Une seule observation ne peut pas être séparée en entraînement et validation
+    /// assert!(model.fit(&array![[1.0]], &array![1.0]).is_err());
+    /// ```
+    pub fn train_loss(&self) -> &[f64] {
+        &self.train_loss
+    }
+
+    /// Perte moyenne sur le jeu de validation après chaque arbre conservé (vide sans
+    /// arrêt précoce).
+    pub fn validation_loss(&self) -> &[f64] {
+        &self.validation_loss
+    }
+}
+
+/*
+CLASSIFICATION
+*/
+
+/// Gradient boosting pour la classification (perte logistique / log-loss).
+///
+/// En binaire, un seul arbre par itération modélise le log-odds de la seconde classe ;
+/// avec K > 2 classes, K arbres par itération modélisent les logits d'un softmax.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GradientBoostingClassifier {
+    params: BoostingParams,
+    mapper: Option<BinMapper>,
+    classes: Vec<usize>,
+    init: Vec<f64>,
+    // trees[m][k] : arbre de l'itération m pour la sortie k
+    trees: Vec<Vec<HistTree>>,
+    n_features: usize,
+    train_loss: Vec<f64>,
+    validation_loss: Vec<f64>,
+}
+
+impl GradientBoostingClassifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_n_estimators(mut self, n_estimators: usize) -> Self {
+        self.params.n_estimators = n_estimators;
+        self
+    }
+
+    /// Shrinkage η appliqué à chaque arbre (0.1 par défaut).
+    pub fn with_learning_rate(mut self, learning_rate: f64) -> Self {
+        self.params.learning_rate = learning_rate;
+        self
+    }
+
+    /// Profondeur de chaque arbre (3 par défaut).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.max_depth = max_depth;
+        self
+    }
+
+    /// Nombre minimal d'observations par feuille (20 par défaut).
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Régularisation L2 λ des valeurs des feuilles (0 par défaut).
+    pub fn with_l2_regularization(mut self, l2_regularization: f64) -> Self {
+        self.params.l2_regularization = l2_regularization;
+        self
+    }
+
+    /// Proportion des observations tirées (sans remise) pour chaque arbre (1.0 par défaut).
+    pub fn with_subsample(mut self, subsample: f64) -> Self {
+        self.params.subsample = subsample;
+        self
+    }
+
+    /// Nombre maximal de bins par feature (255 par défaut).
+    pub fn with_max_bins(mut self, max_bins: usize) -> Self {
+        self.params.max_bins = max_bins;
+        self
+    }
+
+    /// Active l'arrêt précoce sur la log-loss d'un jeu de validation
+    /// (voir [`GradientBoostingRegressor::with_early_stopping`]).
+    pub fn with_early_stopping(
+        mut self,
+        n_iter_no_change: usize,
+        validation_fraction: f64,
+    ) -> Self {
+        self.params.n_iter_no_change = Some(n_iter_no_change);
+        self.params.validation_fraction = validation_fraction;
+        self
+    }
+
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        let classes = unique_classes(y);
+        if classes.len() < 2 {
+            return Err(ModelError::InvalidParameter(
+                "au moins deux classes sont nécessaires".to_string(),
+            ));
+        }
+        let encoded: Vec<usize> = y
+            .iter()
+            .map(|c| {
+                classes
+                    .binary_search(c)
+                    .expect("classe vue pendant unique_classes")
+            })
+            .collect();
+        let n_outputs = if classes.len() == 2 { 1 } else { classes.len() };
+
+        let params = &self.params;
+        let mut rng = StdRng::seed_from_u64(params.random_state);
+        let (train, val) = params.split_validation(x.nrows(), &mut rng)?;
+        let mapper = BinMapper::fit(&x.select(Axis(0), &train), params.max_bins);
+        let bins = mapper.transform(x);
+
+        // Initialisation sur les fréquences des classes (log-odds ou log-priors)
+        let mut counts = vec![0.0; classes.len()];
+        for &i in &train {
+            counts[encoded[i]] += 1.0;
+        }
+        let freq: Vec<f64> = counts
+            .iter()
+            .map(|c| (c / train.len() as f64).max(1e-12))
+            .collect();
+        let init: Vec<f64> = if n_outputs == 1 {
+            vec![(freq[1] / freq[0]).ln()]
+        } else {
+            freq.iter().map(|p| p.ln()).collect()
+        };
+
+        let mut raw = Array2::from_shape_fn((x.nrows(), n_outputs), |(_, k)| init[k]);
+        let mut grad = vec![0.0; x.nrows()];
+        let mut hess = vec![0.0; x.nrows()];
+        let mut trees = Vec::new();
+        let mut train_loss = Vec::new();
+        let mut validation_loss = Vec::new();
+        let mut early_stopping = EarlyStopping::new(params);
+
+        for _ in 0..params.n_estimators {
+            let proba = raw_to_proba(&raw);
+            let rows = params.sample_rows(&train, &mut rng);
+            let mut iteration_trees = Vec::with_capacity(n_outputs);
+            for k in 0..n_outputs {
+                // Gradient de la log-loss : p - y ; hessienne : p (1 - p)
+                let class = if n_outputs == 1 { 1 } else { k };
+                for &i in &train {
+                    let p = proba[[i, class]];
+                    let target = if encoded[i] == class { 1.0 } else { 0.0 };
+                    grad[i] = p - target;
+                    hess[i] = (p * (1.0 - p)).max(1e-12);
+                }
+                let (tree, _) = fit_hist_tree(&bins, &mapper, &grad, &hess, rows.clone(), params);
+                iteration_trees.push(tree);
+            }
+            for (k, tree) in iteration_trees.iter().enumerate() {
+                for (i, r) in raw.column_mut(k).iter_mut().enumerate() {
+                    *r += params.learning_rate * tree.predict_binned(&bins, i);
+                }
+            }
+            trees.push(iteration_trees);
+
+            let proba = raw_to_proba(&raw);
+            let mean_log_loss = |rows: &[usize]| {
+                -rows
+                    .iter()
+                    .map(|&i| proba[[i, encoded[i]]].max(1e-15).ln())
+                    .sum::<f64>()
+                    / rows.len() as f64
+            };
+            train_loss.push(mean_log_loss(&train));
+            if !val.is_empty() {
+                validation_loss.push(mean_log_loss(&val));
+                if early_stopping.should_stop(mean_log_loss(&val), trees.len()) {
+                    break;
+                }
+            }
+        }
+
+        if params.n_iter_no_change.is_some() {
+            let n_kept = early_stopping.best_iter.max(1);
+            trees.truncate(n_kept);
+            train_loss.truncate(n_kept);
+            validation_loss.truncate(n_kept);
+        }
+        self.mapper = Some(mapper);
+        self.classes = classes;
+        self.init = init;
+        self.trees = trees;
+        self.n_features = x.ncols();
+        self.train_loss = train_loss;
+        self.validation_loss = validation_loss;
+        Ok(())
+    }
+
+    /// Scores bruts (log-odds en binaire, logits en multiclasse) avant sigmoïde / softmax.
+    pub fn decision_function(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.mapper.is_none() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.n_features, x.ncols())?;
+        let eta = self.params.learning_rate;
+        let mut raw = Array2::from_shape_fn((x.nrows(), self.init.len()), |(_, k)| self.init[k]);
+        for (i, row) in x.rows().into_iter().enumerate() {
+            for iteration_trees in &self.trees {
+                for (k, tree) in iteration_trees.iter().enumerate() {
+                    raw[[i, k]] += eta * tree.predict_row(row);
+                }
+            }
+        }
+        Ok(raw)
+    }
+
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        Ok(raw_to_proba(&self.decision_function(x)?))
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let proba = self.predict_proba(x)?;
+        Ok(proba
+            .rows()
+            .into_iter()
+            .map(|row| self.classes[argmax(row.iter().copied())])
+            .collect())
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    /// Nombre d'itérations conservées (inférieur à `n_estimators` en cas d'arrêt précoce).
+    pub fn n_trees(&self) -> usize {
+        self.trees.len()
+    }
+
+    /// Log-loss moyenne sur le jeu d'entraînement après chaque itération conservée.
+    pub fn train_loss(&self) -> &[f64] {
+        &self.train_loss
+    }
+
+    /// Log-loss moyenne sur le jeu de validation après chaque itération conservée (vide
+    /// sans arrêt précoce).
+    pub fn validation_loss(&self) -> &[f64] {
+        &self.validation_loss
+    }
+}
+
+/// Convertit les scores bruts en probabilités : sigmoïde (1 sortie) ou softmax (K sorties).
+fn raw_to_proba(raw: &Array2<f64>) -> Array2<f64> {
+    if raw.ncols() == 1 {
+        let mut proba = Array2::zeros((raw.nrows(), 2));
+        for (i, &r) in raw.column(0).iter().enumerate() {
+            let p = sigmoid(r);
+            proba[[i, 0]] = 1.0 - p;
+            proba[[i, 1]] = p;
+        }
+        return proba;
+    }
+    let mut proba = raw.clone();
+    for mut row in proba.rows_mut() {
+        let max = row.fold(f64::NEG_INFINITY, |acc, &v| acc.max(v));
+        row.mapv_inplace(|v| (v - max).exp());
+        let sum = row.sum();
+        row /= sum;
+    }
+    proba
+}
+
+/// Sigmoïde stable numériquement (pas de exp() d'un grand nombre positif).
+fn sigmoid(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    fn is_invalid(result: Result<(), ModelError>) -> bool {
+        matches!(result, Err(ModelError::InvalidParameter(_)))
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_parameters() {
+        let params = |update: fn(&mut BoostingParams)| {
+            let mut params = BoostingParams::default();
+            update(&mut params);
+            params.validate()
+        };
+        assert!(params(|_| {}).is_ok());
+        assert!(is_invalid(params(|p| p.learning_rate = 0.0)));
+        assert!(is_invalid(params(|p| p.learning_rate = f64::NAN)));
+        assert!(is_invalid(params(|p| p.l2_regularization = f64::NAN)));
+        assert!(is_invalid(params(|p| p.tol = f64::NAN)));
+        assert!(is_invalid(params(|p| p.max_bins = 256)));
+        assert!(is_invalid(params(|p| p.n_iter_no_change = Some(0))));
+    }
+
+    #[test]
+    fn validation_split_keeps_both_sides_non_empty() {
+        let params = BoostingParams {
+            n_iter_no_change: Some(5),
+            validation_fraction: 0.99,
+            ..BoostingParams::default()
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let (train, val) = params.split_validation(10, &mut rng).unwrap();
+        assert_eq!((train.len(), val.len()), (1, 9));
+        assert!(params.split_validation(1, &mut rng).is_err());
+    }
+
+    #[test]
+    fn early_stopping_on_a_single_row_is_an_error() {
+        let x = array![[1.0]];
+        let mut model = GradientBoostingRegressor::new().with_early_stopping(5, 0.1);
+        assert!(model.fit(&x, &array![1.0]).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+//! Méthodes d'ensemble : combiner plusieurs arbres de décision pour obtenir un
+//! modèle plus précis et plus stable qu'un arbre seul.
+
+mod gradient_boosting;
+mod random_forest;
+
+pub use gradient_boosting::{
+    GradientBoostingClassifier, GradientBoostingRegressor, RegressionLoss,
+};
+pub use random_forest::{MaxFeatures, RandomForestClassifier, RandomForestRegressor};
@@ -0,0 +1,519 @@
+//! Forêts aléatoires (bagging d'arbres CART).
+//!
+//! Chaque arbre est entraîné sur un échantillon bootstrap (tirage avec remise de n
+//! observations parmi n) et ne considère qu'un sous-ensemble aléatoire de features à
+//! chaque coupure. Les arbres sont ainsi peu corrélés et leur moyenne réduit la variance.
+//!
+//! Environ 37 % des observations ne sont pas tirées pour un arbre donné : elles forment
+//! son échantillon « out-of-bag » (OOB), qui sert d'ensemble de validation gratuit.
+
+use ndarray::{Array1, Array2};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::metrics::{accuracy, r2_score};
+use crate::tree::{Classification, Criterion, Regression, Tree, TreeParams, build_tree};
+use crate::utils::{argmax, unique_classes};
+
+/// Nombre de features candidates à chaque coupure.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MaxFeatures {
+    /// Toutes les features (bagging simple).
+    All,
+    /// √p features, valeur par défaut en classification.
+    Sqrt,
+    /// log₂(p) features.
+    Log2,
+    /// Un nombre fixe de features.
+    Count(usize),
+    /// Une proportion des features (entre 0 et 1).
+    Fraction(f64),
+}
+
+impl MaxFeatures {
+    fn resolve(self, n_features: usize) -> usize {
+        let k = match self {
+            MaxFeatures::All => n_features,
+            MaxFeatures::Sqrt => (n_features as f64).sqrt() as usize,
+            MaxFeatures::Log2 => (n_features as f64).log2() as usize,
+            MaxFeatures::Count(k) => k,
+            MaxFeatures::Fraction(f) => (f * n_features as f64) as usize,
+        };
+        k.clamp(1, n_features.max(1))
+    }
+}
+
+/// Hyperparamètres communs aux deux forêts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ForestParams {
+    n_estimators: usize,
+    tree: TreeParams,
+    max_features: MaxFeatures,
+    bootstrap: bool,
+    oob_score: bool,
+    random_state: u64,
+}
+
+impl ForestParams {
+    fn new(max_features: MaxFeatures) -> Self {
+        Self {
+            n_estimators: 100,
+            tree: TreeParams::default(),
+            max_features,
+            bootstrap: true,
+            oob_score: false,
+            random_state: 0,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ModelError> {
+        if self.n_estimators == 0 {
+            return Err(ModelError::InvalidParameter(
+                "n_estimators doit être >= 1".to_string(),
+            ));
+        }
+        if self.oob_score && !self.bootstrap {
+            return Err(ModelError::InvalidParameter(
+                "le score OOB nécessite bootstrap = true".to_string(),
+            ));
+        }
+        self.tree.validate()
+    }
+}
+
+/// Un arbre de la forêt et le masque des observations utilisées pour l'entraîner.
+struct FittedTree {
+    tree: Tree,
+    in_bag: Vec<bool>,
+}
+
+/// Entraîne les arbres en parallèle, chacun avec son propre générateur aléatoire
+/// dérivé de `random_state` : le résultat ne dépend pas de l'ordre d'exécution des threads.
+fn fit_trees<F>(
+    params: &ForestParams,
+    n_samples: usize,
+    n_features: usize,
+    build: F,
+) -> Vec<FittedTree>
+where
+    F: Fn(&TreeParams, Vec<usize>) -> Tree + Sync,
+{
+    (0..params.n_estimators)
+        .into_par_iter()
+        .map(|t| {
+            let mut rng = StdRng::seed_from_u64(params.random_state.wrapping_add(t as u64));
+            let indices: Vec<usize> = if params.bootstrap {
+                (0..n_samples)
+                    .map(|_| rng.random_range(0..n_samples))
+                    .collect()
+            } else {
+                (0..n_samples).collect()
+            };
+            let mut in_bag = vec![false; n_samples];
+            for &i in &indices {
+                in_bag[i] = true;
+            }
+            let tree_params = TreeParams {
+                max_features: Some(params.max_features.resolve(n_features)),
+                seed: rng.random(),
+                ..params.tree.clone()
+            };
+            FittedTree {
+                tree: build(&tree_params, indices),
+                in_bag,
+            }
+        })
+        .collect()
+}
+
+/// Forêt aléatoire pour la classification : moyenne des probabilités des arbres.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::ensemble::RandomForestClassifier;
+///
+/// let x = array![[1.0, 0.0], [2.0, 0.0], [10.0, 1.0], [11.0, 1.0]];
+/// let y = array![0, 0, 1, 1];
+/// let mut forest = RandomForestClassifier::new().with_n_estimators(10).with_random_state(42);
+/// forest.fit(&x, &y).unwrap();
+/// assert_eq!(forest.predict(&array![[0.0, 0.0], [12.0, 1.0]]).unwrap(), array![0, 1]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomForestClassifier {
+    params: ForestParams,
+    criterion: Criterion,
+    classes: Vec<usize>,
+    trees: Vec<Tree>,
+    oob_score: Option<f64>,
+}
+
+impl Default for RandomForestClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomForestClassifier {
+    pub fn new() -> Self {
+        Self {
+            params: ForestParams::new(MaxFeatures::Sqrt),
+            criterion: Criterion::Gini,
+            classes: Vec::new(),
+            trees: Vec::new(),
+            oob_score: None,
+        }
+    }
+
+    /// Nombre d'arbres (100 par défaut).
+    pub fn with_n_estimators(mut self, n_estimators: usize) -> Self {
+        self.params.n_estimators = n_estimators;
+        self
+    }
+
+    pub fn with_criterion(mut self, criterion: Criterion) -> Self {
+        self.criterion = criterion;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.tree.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn with_min_samples_split(mut self, min_samples_split: usize) -> Self {
+        self.params.tree.min_samples_split = min_samples_split;
+        self
+    }
+
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.tree.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Features candidates à chaque coupure (√p par défaut).
+    pub fn with_max_features(mut self, max_features: MaxFeatures) -> Self {
+        self.params.max_features = max_features;
+        self
+    }
+
+    /// Si `false`, chaque arbre voit toutes les observations (true par défaut).
+    pub fn with_bootstrap(mut self, bootstrap: bool) -> Self {
+        self.params.bootstrap = bootstrap;
+        self
+    }
+
+    /// Calcule l'accuracy out-of-bag pendant `fit` (désactivé par défaut).
+    pub fn with_oob_score(mut self, oob_score: bool) -> Self {
+        self.params.oob_score = oob_score;
+        self
+    }
+
+    /// Graine des tirages aléatoires, pour des résultats reproductibles.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        let classes = unique_classes(y);
+        let encoded: Vec<usize> = y
+            .iter()
+            .map(|c| {
+                classes
+                    .binary_search(c)
+                    .expect("classe vue pendant unique_classes")
+            })
+            .collect();
+        let task = Classification {
+            y: &encoded,
+            n_classes: classes.len(),
+            criterion: self.criterion,
+        };
+
+        let fitted = fit_trees(&self.params, x.nrows(), x.ncols(), |params, indices| {
+            build_tree(x.view(), &task, params, indices)
+        });
+
+        self.oob_score = if self.params.oob_score {
+            // Pour chaque observation, on ne moyenne que les arbres qui ne l'ont pas vue
+            let mut votes = Array2::<f64>::zeros((x.nrows(), classes.len()));
+            for fitted_tree in &fitted {
+                for (i, row) in x.rows().into_iter().enumerate() {
+                    if !fitted_tree.in_bag[i] {
+                        let value = &fitted_tree.tree.leaf(row).value;
+                        votes
+                            .row_mut(i)
+                            .zip_mut_with(&Array1::from_vec(value.clone()), |v, p| *v += p);
+                    }
+                }
+            }
+            let scored: Vec<usize> = (0..x.nrows())
+                .filter(|&i| votes.row(i).sum() > 0.0)
+                .collect();
+            let y_true: Array1<usize> = scored.iter().map(|&i| encoded[i]).collect();
+            let y_oob: Array1<usize> = scored
+                .iter()
+                .map(|&i| argmax(votes.row(i).iter().copied()))
+                .collect();
+            accuracy(&y_true, &y_oob).ok()
+        } else {
+            None
+        };
+        self.trees = fitted.into_iter().map(|f| f.tree).collect();
+        self.classes = classes;
+        Ok(())
+    }
+
+    /// Probabilités des classes : moyenne des probabilités données par chaque arbre.
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let first = self.trees.first().ok_or(ModelError::NotFitted)?;
+        check_n_features(first.n_features(), x.ncols())?;
+
+        let mut proba = Array2::zeros((x.nrows(), self.classes.len()));
+        for tree in &self.trees {
+            for (mut out, row) in proba.rows_mut().into_iter().zip(x.rows()) {
+                out.zip_mut_with(&Array1::from_vec(tree.leaf(row).value.clone()), |p, v| {
+                    *p += v
+                });
+            }
+        }
+        proba /= self.trees.len() as f64;
+        Ok(proba)
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let proba = self.predict_proba(x)?;
+        Ok(proba
+            .rows()
+            .into_iter()
+            .map(|row| self.classes[argmax(row.iter().copied())])
+            .collect())
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    pub fn trees(&self) -> &[Tree] {
+        &self.trees
+    }
+
+    /// Accuracy out-of-bag, disponible si `with_oob_score(true)` a été utilisé et qu'au
+    /// moins une observation est restée hors d'un échantillon bootstrap.
+    pub fn oob_score(&self) -> Option<f64> {
+        self.oob_score
+    }
+
+    /// Importances basées sur la diminution d'impureté, moyennées sur les arbres.
+    pub fn feature_importances(&self) -> Result<Array1<f64>, ModelError> {
+        mean_importances(&self.trees)
+    }
+
+    /// Importances par permutation : baisse moyenne de l'accuracy sur (x, y) quand
+    /// on mélange les valeurs d'une feature, ce qui casse son lien avec la cible.
+    ///
+    /// Contrairement aux importances d'impureté, elles peuvent être calculées sur un jeu
+    /// de test et ne favorisent pas les features avec beaucoup de valeurs distinctes.
+    pub fn permutation_importances(
+        &self,
+        x: &Array2<f64>,
+        y: &Array1<usize>,
+        n_repeats: usize,
+        seed: u64,
+    ) -> Result<Array1<f64>, ModelError> {
+        check_x_y(x, y.len())?;
+        permutation_importances(x, n_repeats, seed, |x| accuracy(y, &self.predict(x)?))
+    }
+}
+
+/// Forêt aléatoire pour la régression : moyenne des prédictions des arbres.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomForestRegressor {
+    params: ForestParams,
+    trees: Vec<Tree>,
+    oob_score: Option<f64>,
+}
+
+impl Default for RandomForestRegressor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomForestRegressor {
+    pub fn new() -> Self {
+        Self {
+            params: ForestParams::new(MaxFeatures::All),
+            trees: Vec::new(),
+            oob_score: None,
+        }
+    }
+
+    /// Nombre d'arbres (100 par défaut).
+    pub fn with_n_estimators(mut self, n_estimators: usize) -> Self {
+        self.params.n_estimators = n_estimators;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.tree.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn with_min_samples_split(mut self, min_samples_split: usize) -> Self {
+        self.params.tree.min_samples_split = min_samples_split;
+        self
+    }
+
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.tree.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Features candidates à chaque coupure (toutes par défaut).
+    pub fn with_max_features(mut self, max_features: MaxFeatures) -> Self {
+        self.params.max_features = max_features;
+        self
+    }
+
+    pub fn with_bootstrap(mut self, bootstrap: bool) -> Self {
+        self.params.bootstrap = bootstrap;
+        self
+    }
+
+    /// Calcule le R² out-of-bag pendant `fit` (désactivé par défaut).
+    pub fn with_oob_score(mut self, oob_score: bool) -> Self {
+        self.params.oob_score = oob_score;
+        self
+    }
+
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        let y_vec = y.to_vec();
+        let task = Regression { y: &y_vec };
+
+        let fitted = fit_trees(&self.params, x.nrows(), x.ncols(), |params, indices| {
+            build_tree(x.view(), &task, params, indices)
+        });
+
+        self.oob_score = if self.params.oob_score {
+            let mut sums = vec![0.0; x.nrows()];
+            let mut counts = vec![0usize; x.nrows()];
+            for fitted_tree in &fitted {
+                for (i, row) in x.rows().into_iter().enumerate() {
+                    if !fitted_tree.in_bag[i] {
+                        sums[i] += fitted_tree.tree.leaf(row).value[0];
+                        counts[i] += 1;
+                    }
+                }
+            }
+            let scored: Vec<usize> = (0..x.nrows()).filter(|&i| counts[i] > 0).collect();
+            let y_true: Array1<f64> = scored.iter().map(|&i| y[i]).collect();
+            let y_oob: Array1<f64> = scored.iter().map(|&i| sums[i] / counts[i] as f64).collect();
+            r2_score(&y_true, &y_oob).ok()
+        } else {
+            None
+        };
+        self.trees = fitted.into_iter().map(|f| f.tree).collect();
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        let first = self.trees.first().ok_or(ModelError::NotFitted)?;
+        check_n_features(first.n_features(), x.ncols())?;
+        let n_trees = self.trees.len() as f64;
+        Ok(x.rows()
+            .into_iter()
+            .map(|row| {
+                self.trees
+                    .iter()
+                    .map(|tree| tree.leaf(row).value[0])
+                    .sum::<f64>()
+                    / n_trees
+            })
+            .collect())
+    }
+
+    pub fn trees(&self) -> &[Tree] {
+        &self.trees
+    }
+
+    /// R² out-of-bag, disponible si `with_oob_score(true)` a été utilisé et qu'au moins
+    /// une observation est restée hors d'un échantillon bootstrap.
+    pub fn oob_score(&self) -> Option<f64> {
+        self.oob_score
+    }
+
+    /// Importances basées sur la diminution d'impureté, moyennées sur les arbres.
+    pub fn feature_importances(&self) -> Result<Array1<f64>, ModelError> {
+        mean_importances(&self.trees)
+    }
+
+    /// Importances par permutation : baisse moyenne du R² sur (x, y) quand on mélange
+    /// les valeurs d'une feature.
+    pub fn permutation_importances(
+        &self,
+        x: &Array2<f64>,
+        y: &Array1<f64>,
+        n_repeats: usize,
+        seed: u64,
+    ) -> Result<Array1<f64>, ModelError> {
+        check_x_y(x, y.len())?;
+        permutation_importances(x, n_repeats, seed, |x| r2_score(y, &self.predict(x)?))
+    }
+}
+
+fn mean_importances(trees: &[Tree]) -> Result<Array1<f64>, ModelError> {
+    let first = trees.first().ok_or(ModelError::NotFitted)?;
+    let mut total = Array1::zeros(first.n_features());
+    for tree in trees {
+        total += tree.feature_importances();
+    }
+    Ok(total / trees.len() as f64)
+}
+
+/// Baisse moyenne de `score` quand chaque colonne de `x` est mélangée `n_repeats` fois.
+fn permutation_importances<F>(
+    x: &Array2<f64>,
+    n_repeats: usize,
+    seed: u64,
+    score: F,
+) -> Result<Array1<f64>, ModelError>
+where
+    F: Fn(&Array2<f64>) -> Result<f64, ModelError>,
+{
+    if n_repeats == 0 {
+        return Err(ModelError::InvalidParameter(
+            "n_repeats doit être >= 1".to_string(),
+        ));
+    }
+    let baseline = score(x)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut importances = Array1::zeros(x.ncols());
+
+    for j in 0..x.ncols() {
+        let mut drop = 0.0;
+        for _ in 0..n_repeats {
+            let mut shuffled = x.clone();
+            let mut column = x.column(j).to_vec();
+            column.shuffle(&mut rng);
+            shuffled.column_mut(j).assign(&Array1::from_vec(column));
+            drop += baseline - score(&shuffled)?;
+        }
+        importances[j] = drop / n_repeats as f64;
+    }
+    Ok(importances)
+}
@@ -0,0 +1,76 @@
+//! Erreurs communes aux modèles de la bibliothèque.
+
+use std::error::Error;
+use std::fmt;
+
+use ndarray::ArrayBase;
+
+use crate::distance::DistanceError;
+
+/// Erreurs renvoyées par l'entraînement ou la prédiction d'un modèle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelError {
+    /// Le jeu de données ne contient aucune observation.
+    EmptyDataset,
+    /// Les dimensions des données ne sont pas cohérentes (nombre de lignes, de features...).
+    ShapeMismatch(String),
+    /// Un hyperparamètre ou une donnée d'entrée est invalide.
+    InvalidParameter(String),
+    /// Le modèle doit être entraîné avec `fit` avant de prédire.
+    NotFitted,
+    /// Une catégorie (ou une classe) n'a pas été vue pendant l'entraînement.
+    UnknownCategory(String),
+    /// Erreur remontée par le calcul d'une distance.
+    Distance(DistanceError),
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelError::EmptyDataset => write!(f, "le jeu de données est vide"),
+            ModelError::ShapeMismatch(msg) => write!(f, "dimensions incohérentes : {}", msg),
+            ModelError::InvalidParameter(msg) => write!(f, "paramètre invalide : {}", msg),
+            ModelError::NotFitted => write!(f, "le modèle n'a pas été entraîné (appeler fit)"),
+            ModelError::UnknownCategory(msg) => write!(f, "catégorie inconnue : {}", msg),
+            ModelError::Distance(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ModelError {}
+
+impl From<DistanceError> for ModelError {
+    fn from(e: DistanceError) -> Self {
+        ModelError::Distance(e)
+    }
+}
+
+/// Vérifie que `x` (n lignes) et la cible (n valeurs) sont cohérents et non vides.
+pub(crate) fn check_x_y<S, D>(x: &ArrayBase<S, D>, n_targets: usize) -> Result<(), ModelError>
+where
+    S: ndarray::Data,
+    D: ndarray::Dimension,
+{
+    let n_rows = x.shape()[0];
+    if n_rows == 0 {
+        return Err(ModelError::EmptyDataset);
+    }
+    if n_rows != n_targets {
+        return Err(ModelError::ShapeMismatch(format!(
+            "{} lignes dans X mais {} valeurs cibles",
+            n_rows, n_targets
+        )));
+    }
+    Ok(())
+}
+
+/// Vérifie que `x` a le nombre de features vu pendant l'entraînement.
+pub(crate) fn check_n_features(expected: usize, found: usize) -> Result<(), ModelError> {
+    if expected != found {
+        return Err(ModelError::ShapeMismatch(format!(
+            "le modèle attend {} features, reçu {}",
+            expected, found
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,405 @@
+//! Imputation des valeurs manquantes.
+//!
+//! Une valeur est manquante si elle vaut `NaN` (cellule vide d'un CSV, voir
+//! [`crate::data`]) ou si elle est égale à la valeur sentinelle déclarée pour sa
+//! colonne : dans diabetes, un 0 pour `insulin` ou `skin_thickness` signifie
+//! « non mesuré ». Les statistiques de remplacement sont apprises par `fit` sur le
+//! jeu d'entraînement seulement, puis appliquées telles quelles par `transform`.
+//!
+//! Avec `with_add_indicator(true)`, une colonne 0/1 est ajoutée à droite de X pour
+//! chaque feature qui avait des valeurs manquantes pendant `fit` : le modèle peut
+//! ainsi exploiter le fait même qu'une mesure manque.
+
+use ndarray::{Array1, Array2, ArrayView1, Axis, concatenate};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features};
+
+/// Valeur utilisée pour remplacer les valeurs manquantes d'une colonne.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ImputeStrategy {
+    /// Moyenne des valeurs observées.
+    #[default]
+    Mean,
+    /// Médiane des valeurs observées, moins sensible aux valeurs extrêmes.
+    Median,
+    /// Valeur la plus fréquente (la plus petite en cas d'égalité).
+    MostFrequent,
+    /// Valeur fixe, identique pour toutes les colonnes.
+    Constant(f64),
+}
+
+/// Valeurs considérées comme manquantes, colonne par colonne.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct MissingValues {
+    sentinels: Vec<(usize, f64)>,
+}
+
+impl MissingValues {
+    fn is_missing(&self, j: usize, value: f64) -> bool {
+        value.is_nan() || self.sentinels.iter().any(|&(c, s)| c == j && s == value)
+    }
+
+    /// Masque des valeurs manquantes de `x`.
+    fn mask(&self, x: &Array2<f64>) -> Array2<bool> {
+        Array2::from_shape_fn(x.dim(), |(i, j)| self.is_missing(j, x[[i, j]]))
+    }
+
+    fn validate(&self, n_features: usize) -> Result<(), ModelError> {
+        match self.sentinels.iter().find(|&&(c, _)| c >= n_features) {
+            Some(&(c, _)) => Err(ModelError::InvalidParameter(format!(
+                "sentinelle déclarée pour la colonne {} mais X n'a que {} colonnes",
+                c, n_features
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Colonnes indicatrices (1.0 si la valeur manquait) des features `columns`.
+fn indicator_columns(mask: &Array2<bool>, columns: &[usize]) -> Array2<f64> {
+    Array2::from_shape_fn((mask.nrows(), columns.len()), |(i, k)| {
+        if mask[[i, columns[k]]] { 1.0 } else { 0.0 }
+    })
+}
+
+/// Ajoute les indicatrices à droite de `x` si elles sont demandées.
+fn append_indicators(
+    x: Array2<f64>,
+    mask: &Array2<bool>,
+    add_indicator: bool,
+    columns: &[usize],
+) -> Array2<f64> {
+    if !add_indicator || columns.is_empty() {
+        return x;
+    }
+    concatenate![Axis(1), x, indicator_columns(mask, columns)]
+}
+
+/// Features ayant au moins une valeur manquante.
+fn columns_with_missing(mask: &Array2<bool>) -> Vec<usize> {
+    (0..mask.ncols())
+        .filter(|&j| mask.column(j).iter().any(|&m| m))
+        .collect()
+}
+
+/// Remplace les valeurs manquantes par une statistique de leur colonne.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::impute::{ImputeStrategy, SimpleImputer};
+///
+/// // Dans la 2e colonne, 0 signifie « non mesuré »
+/// let x = array![[1.0, 0.0], [f64::NAN, 4.0], [3.0, 8.0]];
+/// let mut imputer = SimpleImputer::new()
+///     .with_strategy(ImputeStrategy::Median)
+///     .with_sentinel(1, 0.0)
+///     .with_add_indicator(true);
+/// let imputed = imputer.fit_transform(&x).unwrap();
+/// assert_eq!(
+///     imputed,
+///     array![[1.0, 6.0, 0.0, 1.0], [2.0, 4.0, 1.0, 0.0], [3.0, 8.0, 0.0, 0.0]]
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SimpleImputer {
+    strategy: ImputeStrategy,
+    missing: MissingValues,
+    add_indicator: bool,
+    statistics: Option<Array1<f64>>,
+    indicator_features: Vec<usize>,
+}
+
+impl SimpleImputer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Statistique de remplacement (moyenne par défaut).
+    pub fn with_strategy(mut self, strategy: ImputeStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Considère aussi `value` comme manquante dans la colonne `column` (en plus de NaN).
+    pub fn with_sentinel(mut self, column: usize, value: f64) -> Self {
+        self.missing.sentinels.push((column, value));
+        self
+    }
+
+    /// Ajoute une colonne indicatrice par feature qui avait des valeurs manquantes
+    /// pendant `fit` (désactivé par défaut).
+    pub fn with_add_indicator(mut self, add_indicator: bool) -> Self {
+        self.add_indicator = add_indicator;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        if x.nrows() == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        self.missing.validate(x.ncols())?;
+        let mask = self.missing.mask(x);
+
+        let mut statistics = Array1::zeros(x.ncols());
+        for (j, stat) in statistics.iter_mut().enumerate() {
+            let observed: Vec<f64> = x
+                .column(j)
+                .iter()
+                .zip(mask.column(j))
+                .filter(|&(_, &missing)| !missing)
+                .map(|(&v, _)| v)
+                .collect();
+            *stat = match self.strategy {
+                ImputeStrategy::Constant(value) => value,
+                _ if observed.is_empty() => {
+                    return Err(ModelError::InvalidParameter(format!(
+                        "la colonne {} n'a aucune valeur observée : utiliser ImputeStrategy::Constant",
+                        j
+                    )));
+                }
+                ImputeStrategy::Mean => observed.iter().sum::<f64>() / observed.len() as f64,
+                ImputeStrategy::Median => median(observed),
+                ImputeStrategy::MostFrequent => most_frequent(observed),
+            };
+        }
+
+        self.indicator_features = columns_with_missing(&mask);
+        self.statistics = Some(statistics);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let statistics = self.statistics()?;
+        check_n_features(statistics.len(), x.ncols())?;
+        let mask = self.missing.mask(x);
+        let mut imputed = x.clone();
+        for ((i, j), value) in imputed.indexed_iter_mut() {
+            if mask[[i, j]] {
+                *value = statistics[j];
+            }
+        }
+        Ok(append_indicators(
+            imputed,
+            &mask,
+            self.add_indicator,
+            &self.indicator_features,
+        ))
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Valeur de remplacement apprise pour chaque colonne.
+    pub fn statistics(&self) -> Result<&Array1<f64>, ModelError> {
+        self.statistics.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Features qui reçoivent une colonne indicatrice, dans l'ordre des colonnes ajoutées.
+    pub fn indicator_features(&self) -> &[usize] {
+        &self.indicator_features
+    }
+}
+
+/// Remplace chaque valeur manquante par la moyenne de cette feature chez les
+/// `n_neighbors` observations d'entraînement les plus proches qui l'ont mesurée.
+///
+/// La distance ignore les coordonnées manquantes de l'une ou l'autre observation et
+/// compense leur absence : d(a, b) = √(p / p_obs · Σ (aⱼ − bⱼ)²) sur les p_obs
+/// coordonnées présentes des deux côtés. Les features étant comparées directement,
+/// mieux vaut les standardiser avant l'imputation quand leurs échelles diffèrent.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::impute::KnnImputer;
+///
+/// let x = array![[1.0, 10.0], [2.0, 20.0], [10.0, 100.0], [1.5, f64::NAN]];
+/// let mut imputer = KnnImputer::new(2);
+/// let imputed = imputer.fit_transform(&x).unwrap();
+/// assert_eq!(imputed[[3, 1]], 15.0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnnImputer {
+    n_neighbors: usize,
+    missing: MissingValues,
+    add_indicator: bool,
+    /// Données d'entraînement, les valeurs manquantes remplacées par NaN.
+    #[serde(with = "crate::persistence::nan_as_null")]
+    fit_x: Option<Array2<f64>>,
+    /// Moyenne de chaque colonne, utilisée quand aucun voisin n'a la valeur.
+    means: Array1<f64>,
+    indicator_features: Vec<usize>,
+}
+
+impl KnnImputer {
+    pub fn new(n_neighbors: usize) -> Self {
+        Self {
+            n_neighbors,
+            missing: MissingValues::default(),
+            add_indicator: false,
+            fit_x: None,
+            means: Array1::zeros(0),
+            indicator_features: Vec::new(),
+        }
+    }
+
+    /// Considère aussi `value` comme manquante dans la colonne `column` (en plus de NaN).
+    pub fn with_sentinel(mut self, column: usize, value: f64) -> Self {
+        self.missing.sentinels.push((column, value));
+        self
+    }
+
+    /// Ajoute une colonne indicatrice par feature qui avait des valeurs manquantes
+    /// pendant `fit` (désactivé par défaut).
+    pub fn with_add_indicator(mut self, add_indicator: bool) -> Self {
+        self.add_indicator = add_indicator;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        if x.nrows() == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        if self.n_neighbors == 0 {
+            return Err(ModelError::InvalidParameter(
+                "n_neighbors doit être au moins 1".to_string(),
+            ));
+        }
+        self.missing.validate(x.ncols())?;
+        let mask = self.missing.mask(x);
+        let fit_x = with_nan(x, &mask);
+
+        let mut means = Array1::zeros(x.ncols());
+        for (j, mean) in means.iter_mut().enumerate() {
+            let observed: Vec<f64> = fit_x
+                .column(j)
+                .iter()
+                .copied()
+                .filter(|v| !v.is_nan())
+                .collect();
+            if observed.is_empty() {
+                return Err(ModelError::InvalidParameter(format!(
+                    "la colonne {} n'a aucune valeur observée",
+                    j
+                )));
+            }
+            *mean = observed.iter().sum::<f64>() / observed.len() as f64;
+        }
+
+        self.indicator_features = columns_with_missing(&mask);
+        self.means = means;
+        self.fit_x = Some(fit_x);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let fit_x = self.fit_x.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(fit_x.ncols(), x.ncols())?;
+        let mask = self.missing.mask(x);
+        let mut imputed = with_nan(x, &mask);
+
+        for (i, mut row) in imputed.rows_mut().into_iter().enumerate() {
+            if !mask.row(i).iter().any(|&m| m) {
+                continue;
+            }
+            // Distances aux observations d'entraînement, calculées une fois par ligne
+            let distances: Vec<f64> = fit_x
+                .rows()
+                .into_iter()
+                .map(|donor| nan_euclidean(row.view(), donor))
+                .collect();
+            for j in (0..x.ncols()).filter(|&j| mask[[i, j]]) {
+                row[j] = self.neighbors_mean(fit_x, &distances, j);
+            }
+        }
+        Ok(append_indicators(
+            imputed,
+            &mask,
+            self.add_indicator,
+            &self.indicator_features,
+        ))
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Features qui reçoivent une colonne indicatrice, dans l'ordre des colonnes ajoutées.
+    pub fn indicator_features(&self) -> &[usize] {
+        &self.indicator_features
+    }
+
+    /// Moyenne de la feature `j` chez les plus proches donneurs qui l'ont mesurée.
+    fn neighbors_mean(&self, fit_x: &Array2<f64>, distances: &[f64], j: usize) -> f64 {
+        let mut donors: Vec<(f64, f64)> = distances
+            .iter()
+            .zip(fit_x.column(j))
+            .filter(|&(d, v)| !d.is_nan() && !v.is_nan())
+            .map(|(&d, &v)| (d, v))
+            .collect();
+        if donors.is_empty() {
+            return self.means[j];
+        }
+        let k = self.n_neighbors.min(donors.len());
+        donors.select_nth_unstable_by(k - 1, |a, b| a.0.total_cmp(&b.0));
+        donors[..k].iter().map(|&(_, v)| v).sum::<f64>() / k as f64
+    }
+}
+
+/// Copie de `x` où les valeurs manquantes (sentinelles comprises) valent NaN.
+fn with_nan(x: &Array2<f64>, mask: &Array2<bool>) -> Array2<f64> {
+    let mut out = x.clone();
+    out.zip_mut_with(mask, |v, &missing| {
+        if missing {
+            *v = f64::NAN;
+        }
+    });
+    out
+}
+
+/// Distance euclidienne sur les coordonnées présentes des deux côtés, remise à
+/// l'échelle du nombre total de coordonnées (NaN si aucune n'est commune).
+fn nan_euclidean(a: ArrayView1<'_, f64>, b: ArrayView1<'_, f64>) -> f64 {
+    let (sum, n_present) = a
+        .iter()
+        .zip(b)
+        .filter(|(u, v)| !u.is_nan() && !v.is_nan())
+        .fold((0.0, 0usize), |(s, n), (u, v)| {
+            (s + (u - v) * (u - v), n + 1)
+        });
+    if n_present == 0 {
+        return f64::NAN;
+    }
+    (a.len() as f64 / n_present as f64 * sum).sqrt()
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let n = values.len();
+    if !n.is_multiple_of(2) {
+        values[n / 2]
+    } else {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    }
+}
+
+fn most_frequent(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let mut best = (values[0], 0);
+    let mut start = 0;
+    for end in 1..=values.len() {
+        if end == values.len() || values[end] != values[start] {
+            // Seule une fréquence strictement plus grande remplace : la plus petite valeur gagne
+            if end - start > best.1 {
+                best = (values[start], end - start);
+            }
+            start = end;
+        }
+    }
+    best.0
+}
@@ -0,0 +1,51 @@
+//! Briques réutilisables par les expériences de `src/bin/`.
+//!
+//! Chaque binaire de `src/bin/` reste un exemple, mais le chargement des données,
+//! les modèles, les métriques et les graphiques sont regroupés ici et utilisables
+//! depuis d'autres crates :
+//!
+//! - [`data`] : chargement des CSV par noms de colonnes, type [`data::Dataset`] ;
+//! - [`traits`] : `Fit`, `Predict`, `PredictProba`, `Transform` et `Score`, communs
+//!   à tous les modèles ;
+//! - [`describe`] : résumé statistique des colonnes (count, quartiles, skewness...) ;
+//! - [`correlation`] : matrices de corrélation de Pearson, Spearman et Kendall ;
+//! - [`preprocessing`] : encodage des labels et des variables catégorielles ;
+//! - [`distance`] : métriques de distance et matrices de distances ;
+//! - [`linear_model`] : régression linéaire par SVD ;
+//! - [`pipeline`] : prétraitement et modèle entraînés et sauvegardés ensemble ;
+//! - [`model_selection`] : découpage train/test et validation croisée ;
+//! - [`cluster`] : k-means ;
+//! - [`metrics`] : accuracy, MSE, R² ;
+//! - [`plot`] : graphiques Plotly communs ;
+//! - [`persistence`] : sauvegarde des modèles entraînés en JSON ou en binaire ;
+//! - les autres modules regroupent les modèles (arbres, SVM, réseaux de neurones...).
+
+// Fournit l'implémentation BLAS (OpenBLAS) utilisée par ndarray pour les produits matriciels
+extern crate blas_src;
+
+pub mod autograd;
+pub mod cluster;
+pub mod correlation;
+pub mod data;
+pub mod decomposition;
+pub mod describe;
+pub mod discriminant_analysis;
+pub mod distance;
+pub mod ensemble;
+pub mod error;
+pub mod impute;
+pub mod linear_model;
+pub mod manifold;
+pub mod metrics;
+pub mod model_selection;
+pub mod naive_bayes;
+pub mod neural_network;
+pub mod persistence;
+pub mod pipeline;
+pub mod plot;
+pub mod preprocessing;
+pub mod svm;
+pub mod traits;
+pub mod tree;
+
+mod utils;
@@ -0,0 +1,170 @@
+//! Régression linéaire par moindres carrés.
+//!
+//! Le modèle est résolu avec la SVD de `nalgebra` : la pseudo-inverse reste
+//! définie quand les colonnes de X sont colinéaires, là où les équations normales
+//! (XᵀX)⁻¹Xᵀy échoueraient.
+//!
+//! [`LinearRegression`] enveloppe ces fonctions pour des données ndarray, avec la même
+//! interface `fit` / `predict` que les autres modèles.
+
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+
+/// Valeurs singulières en dessous de ce seuil traitées comme nulles dans la pseudo-inverse.
+const SINGULAR_VALUE_TOL: f64 = 1e-10;
+
+/// Entraîne un modèle de régression linéaire en utilisant la SVD.
+///
+/// Résout X θ = y au sens des moindres carrés avec θ = V Σ⁺ Uᵀ y. Pour avoir une
+/// ordonnée à l'origine, X doit contenir une colonne de 1 (voir [`add_bias_column`]).
+///
+/// # Arguments
+/// * `x` - La matrice de design, une observation par ligne.
+/// * `y` - La cible, une valeur par observation.
+///
+/// # Returns
+/// * Les paramètres θ, un par colonne de X.
+///
+/// # Example
+/// ```
+/// use nalgebra::{DMatrix, DVector};
+/// use rust_first_steps::linear_model::{add_bias_column, linear_regression_svd};
+///
+/// // y = 1 + 2x
+/// let x = DMatrix::from_row_slice(3, 1, &[0.0, 1.0, 2.0]);
+/// let y = DVector::from_vec(vec![1.0, 3.0, 5.0]);
+/// let theta = linear_regression_svd(&add_bias_column(&x), &y).unwrap();
+/// assert!((theta[0] - 1.0).abs() < 1e-10 && (theta[1] - 2.0).abs() < 1e-10);
+/// ```
+pub fn linear_regression_svd(
+    x: &DMatrix<f64>,
+    y: &DVector<f64>,
+) -> Result<DVector<f64>, ModelError> {
+    if x.nrows() == 0 {
+        return Err(ModelError::EmptyDataset);
+    }
+    if x.nrows() != y.len() {
+        return Err(ModelError::ShapeMismatch(format!(
+            "{} lignes dans X mais {} valeurs cibles",
+            x.nrows(),
+            y.len()
+        )));
+    }
+
+    // On applique la SVD sur la matrice X
+    let svd = x.clone().svd(true, true);
+    let u = svd.u.expect("U demandée à la SVD");
+    let v_t = svd.v_t.expect("V^T demandée à la SVD");
+
+    // Σ⁺ : on inverse les valeurs singulières non nulles
+    let sigma_pinv = DMatrix::from_diagonal(&svd.singular_values.map(|s| {
+        if s.abs() > SINGULAR_VALUE_TOL {
+            1.0 / s
+        } else {
+            0.0
+        }
+    }));
+
+    Ok(v_t.transpose() * sigma_pinv * (u.transpose() * y))
+}
+
+/// Prédictions X θ d'un modèle linéaire.
+pub fn predict_linear(x: &DMatrix<f64>, theta: &DVector<f64>) -> Result<DVector<f64>, ModelError> {
+    if x.ncols() != theta.len() {
+        return Err(ModelError::ShapeMismatch(format!(
+            "{} colonnes dans X mais {} paramètres",
+            x.ncols(),
+            theta.len()
+        )));
+    }
+    Ok(x * theta)
+}
+
+/// Construit la matrice de design X avec une colonne de biais (des 1) en première position.
+pub fn add_bias_column(x: &DMatrix<f64>) -> DMatrix<f64> {
+    x.clone().insert_column(0, 1.0)
+}
+
+/// Régression linéaire par moindres carrés sur des données ndarray.
+///
+/// Par défaut une colonne de biais est ajoutée à X pour estimer l'ordonnée à
+/// l'origine ; on la désactive quand X en contient déjà une, par exemple après
+/// [`crate::preprocessing::BiasColumn`] dans un pipeline.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::linear_model::LinearRegression;
+///
+/// // y = 1 + 2x
+/// let mut model = LinearRegression::new();
+/// model.fit(&array![[0.0], [1.0], [2.0]], &array![1.0, 3.0, 5.0]).unwrap();
+/// assert!((model.intercept().unwrap() - 1.0).abs() < 1e-10);
+/// assert!((model.predict(&array![[3.0]]).unwrap()[0] - 7.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearRegression {
+    fit_intercept: bool,
+    /// θ, l'ordonnée à l'origine en premier si `fit_intercept`
+    theta: Option<DVector<f64>>,
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinearRegression {
+    pub fn new() -> Self {
+        Self {
+            fit_intercept: true,
+            theta: None,
+        }
+    }
+
+    /// Estime une ordonnée à l'origine (`true` par défaut).
+    pub fn with_fit_intercept(mut self, fit_intercept: bool) -> Self {
+        self.fit_intercept = fit_intercept;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        let y = DVector::from_iterator(y.len(), y.iter().copied());
+        self.theta = Some(linear_regression_svd(&self.design_matrix(x), &y)?);
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        let theta = self.theta.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(self.coefficients()?.len(), x.ncols())?;
+        let y_pred = predict_linear(&self.design_matrix(x), theta)?;
+        Ok(Array1::from_iter(y_pred.iter().copied()))
+    }
+
+    /// Ordonnée à l'origine (0 sans `fit_intercept`).
+    pub fn intercept(&self) -> Result<f64, ModelError> {
+        let theta = self.theta.as_ref().ok_or(ModelError::NotFitted)?;
+        Ok(if self.fit_intercept { theta[0] } else { 0.0 })
+    }
+
+    /// Coefficient de chaque feature de X.
+    pub fn coefficients(&self) -> Result<Array1<f64>, ModelError> {
+        let theta = self.theta.as_ref().ok_or(ModelError::NotFitted)?;
+        let skip = usize::from(self.fit_intercept);
+        Ok(theta.iter().skip(skip).copied().collect())
+    }
+
+    fn design_matrix(&self, x: &Array2<f64>) -> DMatrix<f64> {
+        let x = DMatrix::from_row_iterator(x.nrows(), x.ncols(), x.iter().copied());
+        if self.fit_intercept {
+            add_bias_column(&x)
+        } else {
+            x
+        }
+    }
+}
@@ -1,3 +1,987 @@
-fn main(){
-    println!("Hello world!");
-}
\ No newline at end of file
+//! Interface en ligne de commande : applique les modèles de la bibliothèque à
+//! n'importe quel CSV, sans recompiler.
+//!
+//! ```text
+//! cargo run -- fit --data data/iris.csv --target species --model random-forest --save-model models/iris.json
+//! cargo run -- predict --model models/iris.json --data new_iris.csv
+//! cargo run -- cluster --data data/iris.csv --features petal_length,petal_width --k 3
+//! cargo run -- describe --data data/diabetes.csv --target outcome
+//! cargo run -- plot --data data/housing.csv --target price
+//! ```
+
+use std::error::Error;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use csv::{ReaderBuilder, Writer};
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2, Axis};
+use plotly::Plot;
+use plotly::layout::{Axis as PlotAxis, Layout};
+use serde::{Deserialize, Serialize};
+
+use rust_first_steps::cluster::KMeans;
+use rust_first_steps::correlation::{CorrelationMethod, correlation_matrix};
+use rust_first_steps::data::{
+    Dataset, check_no_missing, load_csv_ndarray, load_csv_polars, load_features, read_headers,
+};
+use rust_first_steps::describe::{
+    ColumnSummary, describe_dataframe, format_table, write_csv, write_json,
+};
+use rust_first_steps::discriminant_analysis::{
+    LinearDiscriminantAnalysis, QuadraticDiscriminantAnalysis,
+};
+use rust_first_steps::ensemble::{
+    GradientBoostingClassifier, GradientBoostingRegressor, RandomForestClassifier,
+    RandomForestRegressor,
+};
+use rust_first_steps::error::ModelError;
+use rust_first_steps::impute::{ImputeStrategy, KnnImputer, SimpleImputer};
+use rust_first_steps::linear_model::LinearRegression;
+use rust_first_steps::metrics::{accuracy, mean_squared_error, r2_score};
+use rust_first_steps::model_selection::train_test_split;
+use rust_first_steps::naive_bayes::{GaussianNB, NaiveBayes};
+use rust_first_steps::neural_network::{MlpClassifier, MlpRegressor};
+use rust_first_steps::persistence::{Persist, SavedModel};
+use rust_first_steps::pipeline::{Pipeline, Step};
+use rust_first_steps::plot::{
+    add_class_scatter, plot_correlation_heatmap, plot_features_and_target, plot_pair_plot,
+    save_html,
+};
+use rust_first_steps::preprocessing::{LabelEncoder, StandardScaler};
+use rust_first_steps::svm::{SvmClassifier, SvmRegressor};
+use rust_first_steps::traits::{Fit, Predict};
+use rust_first_steps::tree::{DecisionTreeClassifier, DecisionTreeRegressor};
+
+/// Au-delà de ce nombre de valeurs distinctes, une cible entière est traitée comme continue.
+const MAX_AUTO_CLASSES: usize = 20;
+
+#[derive(Parser)]
+#[command(
+    name = "rust_first_steps",
+    about = "Entraîne, applique et explore les modèles de rust_first_steps sur un fichier CSV"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Entraîne un modèle sur une partie du CSV et l'évalue sur le reste
+    Fit(FitArgs),
+    /// Prédit la cible des lignes d'un CSV avec un modèle sauvegardé par `fit --save-model`
+    Predict(PredictArgs),
+    /// Regroupe les observations avec k-means
+    Cluster(ClusterArgs),
+    /// Statistiques descriptives des colonnes
+    Describe(DescribeArgs),
+    /// Trace chaque feature en fonction de la cible
+    Plot(PlotArgs),
+}
+
+#[derive(Args)]
+struct DataArgs {
+    /// Fichier CSV, avec une ligne d'en-tête
+    #[arg(long)]
+    data: PathBuf,
+    /// Colonnes utilisées comme features, séparées par des virgules
+    /// (par défaut : toutes les colonnes sauf la cible)
+    #[arg(long, value_delimiter = ',')]
+    features: Vec<String>,
+}
+
+#[derive(Args)]
+struct ModelArgs {
+    /// Colonne à prédire
+    #[arg(long)]
+    target: String,
+    /// Modèle à entraîner (SVM et MLP reçoivent des features standardisées)
+    #[arg(long, value_enum, default_value_t = ModelKind::RandomForest)]
+    model: ModelKind,
+    /// Type de problème ; `auto` choisit la classification pour une cible textuelle
+    /// ou entière avec peu de valeurs distinctes
+    #[arg(long, value_enum, default_value_t = Task::Auto)]
+    task: Task,
+    /// Graine des modèles aléatoires et du découpage train/test
+    #[arg(long, default_value_t = 42)]
+    seed: u64,
+    /// Remplace les valeurs manquantes (cellules vides) avant l'entraînement
+    #[arg(long, value_enum)]
+    impute: Option<Imputation>,
+    /// Colonnes où un 0 signifie « non mesuré », séparées par des virgules (avec --impute)
+    #[arg(long, value_delimiter = ',', requires = "impute")]
+    zero_as_missing: Vec<String>,
+}
+
+#[derive(Args)]
+struct FitArgs {
+    #[command(flatten)]
+    data: DataArgs,
+    #[command(flatten)]
+    model: ModelArgs,
+    /// Proportion des lignes gardées pour l'évaluation, strictement entre 0 et 1
+    #[arg(long, default_value_t = 0.2)]
+    test_size: f64,
+    /// Dossier où sont écrites les prédictions sur le jeu de test
+    #[arg(long, default_value = "output")]
+    output_dir: PathBuf,
+    /// Réentraîne le modèle sur toutes les lignes puis le sauvegarde avec son
+    /// prétraitement (JSON si l'extension est `.json`, binaire sinon) pour la
+    /// commande `predict`
+    #[arg(long)]
+    save_model: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct PredictArgs {
+    /// Modèle sauvegardé par `fit --save-model`
+    #[arg(long)]
+    model: PathBuf,
+    /// CSV à prédire : il doit contenir les features du modèle, dans le même ordre
+    #[arg(long)]
+    data: PathBuf,
+    /// Dossier où est écrit `predictions.csv`
+    #[arg(long, default_value = "output")]
+    output_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct ClusterArgs {
+    #[command(flatten)]
+    data: DataArgs,
+    /// Nombre de clusters
+    #[arg(long, default_value_t = 3)]
+    k: usize,
+    /// Graine de l'initialisation k-means++
+    #[arg(long, default_value_t = 42)]
+    seed: u64,
+    /// Dossier où sont écrits `clusters.csv` et `clusters.html`
+    #[arg(long, default_value = "output")]
+    output_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct DescribeArgs {
+    #[command(flatten)]
+    data: DataArgs,
+    /// Colonne cible, résumée par le nombre d'observations de chaque valeur
+    #[arg(long)]
+    target: Option<String>,
+    /// Dossier où exporter le résumé (describe.json et describe.csv)
+    #[arg(long)]
+    output_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct PlotArgs {
+    #[command(flatten)]
+    data: DataArgs,
+    /// Colonne placée en ordonnée
+    #[arg(long)]
+    target: String,
+    /// Dossier où sont écrits les graphiques
+    #[arg(long, default_value = "plots")]
+    output_dir: PathBuf,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+enum ModelKind {
+    /// Régression linéaire par SVD
+    Linear,
+    DecisionTree,
+    RandomForest,
+    GradientBoosting,
+    Svm,
+    Mlp,
+    /// Naive Bayes gaussien
+    NaiveBayes,
+    Lda,
+    Qda,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Imputation {
+    Mean,
+    Median,
+    MostFrequent,
+    /// Moyenne des 5 plus proches voisins
+    Knn,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Task {
+    Auto,
+    Classification,
+    Regression,
+}
+
+/// Cible chargée depuis le CSV, encodée selon le type de problème.
+enum Target {
+    /// Indices de classe et encodeur qui les a produits (classes triées du CSV).
+    Classes(Array1<usize>, LabelEncoder),
+    Values(Array1<f64>),
+}
+
+fn main() {
+    let result = match Cli::parse().command {
+        Command::Fit(args) => fit(args),
+        Command::Predict(args) => predict(args),
+        Command::Cluster(args) => cluster(args),
+        Command::Describe(args) => describe(args),
+        Command::Plot(args) => plot(args),
+    };
+    // On affiche le message d'erreur (Display) plutôt que la structure (Debug)
+    if let Err(e) = result {
+        eprintln!("Erreur : {}", e);
+        std::process::exit(1);
+    }
+}
+
+/*
+COMMANDES
+*/
+
+fn fit(args: FitArgs) -> Result<(), Box<dyn Error>> {
+    // Vérifié avant de charger les données : `train_test_split` panique hors de [0, 1]
+    if !(args.test_size > 0.0 && args.test_size < 1.0) {
+        return Err(format!(
+            "--test-size doit être strictement entre 0 et 1 (reçu {})",
+            args.test_size
+        )
+        .into());
+    }
+    let features = feature_names(&args.data, Some(&args.model.target))?;
+    let features: Vec<&str> = features.iter().map(String::as_str).collect();
+    let (x, labels) = load_csv_ndarray::<String>(&args.data.data, &features, &args.model.target)?;
+    // Sans --impute, une cellule manquante arriverait telle quelle (NaN) dans le modèle
+    if args.model.impute.is_none() {
+        check_no_missing(&x, &features)?;
+    }
+    let target = encode_target(&labels, resolve_task(&args.model, &labels)?)?;
+
+    // Les lignes (vraie valeur, prédiction) ne sont écrites qu'une fois le modèle
+    // entraîné, pour ne pas laisser de fichier partiel après une erreur
+    let mut rows: Vec<[String; 2]> = Vec::new();
+    match &target {
+        Target::Classes(y, encoder) => {
+            let (x_train, x_test, y_train, y_test) =
+                train_test_split(&x, y, args.test_size, args.model.seed);
+            let model = train(
+                &args.model,
+                &features,
+                &x_train,
+                &Target::Classes(y_train, encoder.clone()),
+            )?;
+            let Prediction::Classes {
+                indices, labels, ..
+            } = model.predict(&x_test)?
+            else {
+                unreachable!("un classifieur prédit des classes");
+            };
+            println!(
+                "[{}] {} observations d'entraînement, {} de test, {} classes",
+                args.model.model.name(),
+                x_train.nrows(),
+                x_test.nrows(),
+                encoder.classes()?.len()
+            );
+            println!("Accuracy test : {:.3}", accuracy(&y_test, &indices)?);
+            for (t, p) in encoder.inverse_transform(&y_test)?.iter().zip(&labels) {
+                rows.push([t.clone(), p.clone()]);
+            }
+        }
+        Target::Values(y) => {
+            let (x_train, x_test, y_train, y_test) =
+                train_test_split(&x, y, args.test_size, args.model.seed);
+            let model = train(&args.model, &features, &x_train, &Target::Values(y_train))?;
+            let Prediction::Values(y_pred) = model.predict(&x_test)? else {
+                unreachable!("un régresseur prédit des valeurs");
+            };
+            println!(
+                "[{}] {} observations d'entraînement, {} de test",
+                args.model.model.name(),
+                x_train.nrows(),
+                x_test.nrows()
+            );
+            println!(
+                "R² test : {:.3}, MSE test : {:.4e}",
+                r2_score(&y_test, &y_pred)?,
+                mean_squared_error(&y_test, &y_pred)?
+            );
+            for (t, p) in y_test.iter().zip(&y_pred) {
+                rows.push([t.to_string(), p.to_string()]);
+            }
+        }
+    }
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let output = args.output_dir.join("fit_predictions.csv");
+    let mut writer = Writer::from_path(&output)?;
+    writer.write_record(["y_true", "y_pred"])?;
+    for row in &rows {
+        writer.write_record(row)?;
+    }
+    writer.flush()?;
+    println!(
+        "Prédictions du jeu de test écrites dans {}",
+        output.display()
+    );
+
+    // Le découpage ne sert qu'à l'évaluation : le modèle sauvegardé est réentraîné
+    // sur toutes les lignes
+    if let Some(path) = &args.save_model {
+        let model = train(&args.model, &features, &x, &target)?;
+        SavedModel::new(model, &features)
+            .with_target(&args.model.target)
+            .save(path)?;
+        println!(
+            "Modèle (réentraîné sur les {} observations) sauvegardé dans {}",
+            x.nrows(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn predict(args: PredictArgs) -> Result<(), Box<dyn Error>> {
+    let saved = SavedModel::<TrainedModel>::load(&args.model)?;
+    let metadata = &saved.metadata;
+    metadata.check_columns(&read_headers(&args.data)?)?;
+    let features: Vec<&str> = metadata.feature_names.iter().map(String::as_str).collect();
+    let x_new = load_features(&args.data, &features)?;
+    if !saved.model.imputes() {
+        check_no_missing(&x_new, &features)?;
+    }
+
+    let target = metadata.target.as_deref().unwrap_or("target");
+    let mut columns = Vec::new();
+    match saved.model.predict(&x_new)? {
+        Prediction::Classes { labels, proba, .. } => {
+            columns.push((format!("{}_predicted", target), labels.to_vec()));
+            // Une colonne de probabilité par classe, pour les modèles qui les estiment
+            if let Some((classes, proba)) = proba {
+                for (class, column) in classes.iter().zip(proba.columns()) {
+                    columns.push((
+                        format!("proba_{}", class),
+                        column.iter().map(f64::to_string).collect(),
+                    ));
+                }
+            }
+        }
+        Prediction::Values(values) => columns.push((
+            format!("{}_predicted", target),
+            values.iter().map(f64::to_string).collect(),
+        )),
+    }
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let output = args.output_dir.join("predictions.csv");
+    write_with_columns(&args.data, &output, &columns)?;
+    println!(
+        "[{}] {} prédictions écrites dans {} (colonnes {})",
+        saved.model.kind.name(),
+        x_new.nrows(),
+        output.display(),
+        columns
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(())
+}
+
+fn cluster(args: ClusterArgs) -> Result<(), Box<dyn Error>> {
+    let features = feature_names(&args.data, None)?;
+    let features: Vec<&str> = features.iter().map(String::as_str).collect();
+    let x = load_features(&args.data.data, &features)?;
+    check_no_missing(&x, &features)?;
+
+    let mut kmeans = KMeans::new(args.k).with_random_state(args.seed);
+    let clusters = kmeans.fit_predict(&x)?;
+    println!(
+        "[KMEANS] k = {} : inertie = {:.4}, {} itérations",
+        args.k,
+        kmeans.inertia()?,
+        kmeans.n_iter()
+    );
+    for k in 0..args.k {
+        let size = clusters.iter().filter(|&&c| c == k).count();
+        println!("Cluster {} : {} observations", k, size);
+    }
+    println!(
+        "Centroïdes ({}) :\n{:.4}",
+        features.join(", "),
+        kmeans.centroids()?
+    );
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let output = args.output_dir.join("clusters.csv");
+    let labels: Vec<String> = clusters.iter().map(usize::to_string).collect();
+    write_with_columns(&args.data.data, &output, &[("cluster".to_string(), labels)])?;
+    println!("Affectations écrites dans {}", output.display());
+
+    // Clusters sur les deux premières features
+    if features.len() >= 2 {
+        let names: Vec<String> = (0..args.k).map(|k| format!("Cluster {}", k)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let mut plot = Plot::new();
+        add_class_scatter(&mut plot, &x, &clusters, &names);
+        plot.set_layout(
+            Layout::new()
+                .title(format!("K-means (k = {})", args.k))
+                .x_axis(PlotAxis::new().title(features[0]))
+                .y_axis(PlotAxis::new().title(features[1])),
+        );
+        save_html(&plot, args.output_dir.join("clusters.html"))?;
+    }
+    Ok(())
+}
+
+fn describe(args: DescribeArgs) -> Result<(), Box<dyn Error>> {
+    let features = feature_names(&args.data, args.target.as_ref())?;
+    let features: Vec<&str> = features.iter().map(String::as_str).collect();
+    let summaries: Vec<ColumnSummary> = describe_dataframe(&load_csv_polars(&args.data.data)?)?
+        .into_iter()
+        .filter(|s| features.contains(&s.name.as_str()) || args.target.as_ref() == Some(&s.name))
+        .collect();
+    println!("{}", format_table(&summaries));
+
+    if let Some(output_dir) = &args.output_dir {
+        std::fs::create_dir_all(output_dir)?;
+        write_json(&summaries, output_dir.join("describe.json"))?;
+        write_csv(&summaries, output_dir.join("describe.csv"))?;
+        println!("\nRésumé exporté dans {}", output_dir.display());
+    }
+
+    if let Some(target) = &args.target {
+        let (_, labels) = load_csv_ndarray::<String>(&args.data.data, &[], target)?;
+        let mut values: Vec<&String> = labels.iter().collect();
+        values.sort();
+        values.dedup();
+        println!("\nCible {} :", target);
+        for value in values {
+            let count = labels.iter().filter(|&l| l == value).count();
+            println!("{:<28} {:>8}", value, count);
+        }
+    }
+    Ok(())
+}
+
+fn plot(args: PlotArgs) -> Result<(), Box<dyn Error>> {
+    let features = feature_names(&args.data, Some(&args.target))?;
+    let features: Vec<&str> = features.iter().map(String::as_str).collect();
+    let (x, labels) = load_csv_ndarray::<String>(&args.data.data, &features, &args.target)?;
+    check_no_missing(&x, &features)?;
+
+    let target = encode_target(&labels, Task::Auto)?;
+    // Une cible textuelle est tracée par son indice de classe
+    let y = match &target {
+        Target::Classes(y, _) if labels.iter().any(|l| l.parse::<f64>().is_err()) => {
+            y.mapv(|k| k as f64)
+        }
+        _ => labels
+            .iter()
+            .map(|l| l.parse::<f64>())
+            .collect::<Result<Array1<f64>, _>>()?,
+    };
+    plot_features_and_target(
+        &to_dmatrix(&x),
+        &DVector::from_iterator(y.len(), y.iter().copied()),
+        &features,
+        &args.target,
+        &args.output_dir,
+    )?;
+    let mut n_plots = features.len();
+
+    for method in [
+        CorrelationMethod::Pearson,
+        CorrelationMethod::Spearman,
+        CorrelationMethod::Kendall,
+    ] {
+        plot_correlation_heatmap(
+            &correlation_matrix(&x, method),
+            &features,
+            &format!("Corrélation de {}", method.name()),
+            args.output_dir
+                .join(format!("correlation_{}.html", method.name())),
+        )?;
+        n_plots += 1;
+    }
+
+    // Le pair plot n'a de sens que coloré par classe
+    if let Target::Classes(y, encoder) = &target {
+        let classes: Vec<&str> = encoder.classes()?.iter().map(String::as_str).collect();
+        plot_pair_plot(
+            &x,
+            y,
+            &features,
+            &classes,
+            &format!("Pair plot par {}", args.target),
+            args.output_dir.join("pair_plot.html"),
+        )?;
+        n_plots += 1;
+    }
+    println!(
+        "{} graphiques écrits dans {}",
+        n_plots,
+        args.output_dir.display()
+    );
+    Ok(())
+}
+
+/*
+MODÈLES
+*/
+
+impl ModelKind {
+    fn name(self) -> &'static str {
+        match self {
+            ModelKind::Linear => "linear",
+            ModelKind::DecisionTree => "decision-tree",
+            ModelKind::RandomForest => "random-forest",
+            ModelKind::GradientBoosting => "gradient-boosting",
+            ModelKind::Svm => "svm",
+            ModelKind::Mlp => "mlp",
+            ModelKind::NaiveBayes => "naive-bayes",
+            ModelKind::Lda => "lda",
+            ModelKind::Qda => "qda",
+        }
+    }
+
+    /// Les SVM et les réseaux de neurones sont sensibles à l'échelle des features.
+    fn needs_scaling(self) -> bool {
+        matches!(self, ModelKind::Svm | ModelKind::Mlp)
+    }
+}
+
+/// Modèle entraîné par `fit`, tel qu'il est sauvegardé : un pipeline (imputation,
+/// standardisation pour SVM et MLP, modèle) appris sur toutes les lignes du CSV et
+/// réappliqué tel quel avant chaque prédiction.
+#[derive(Serialize, Deserialize)]
+struct TrainedModel {
+    kind: ModelKind,
+    model: Fitted,
+}
+
+impl Persist for TrainedModel {
+    fn model_type() -> String {
+        "TrainedModel".to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum Fitted {
+    /// Classifieur et noms des classes
+    Classifier(Pipeline<Classifier>, LabelEncoder),
+    /// Régresseur et standardisation de la cible (SVM et MLP)
+    Regressor(Pipeline<Regressor>, Option<StandardScaler>),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum Classifier {
+    DecisionTree(DecisionTreeClassifier),
+    RandomForest(RandomForestClassifier),
+    GradientBoosting(GradientBoostingClassifier),
+    Svm(SvmClassifier),
+    Mlp(MlpClassifier),
+    NaiveBayes(GaussianNB),
+    Lda(LinearDiscriminantAnalysis),
+    Qda(QuadraticDiscriminantAnalysis),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum Regressor {
+    Linear(LinearRegression),
+    DecisionTree(DecisionTreeRegressor),
+    RandomForest(RandomForestRegressor),
+    GradientBoosting(GradientBoostingRegressor),
+    Svm(SvmRegressor),
+    Mlp(MlpRegressor),
+}
+
+enum Prediction {
+    Classes {
+        indices: Array1<usize>,
+        labels: Array1<String>,
+        /// Noms des classes et probabilité de chacune (une colonne par classe)
+        proba: Option<(Vec<String>, Array2<f64>)>,
+    },
+    Values(Array1<f64>),
+}
+
+// Applique la même expression au classifieur, quel que soit son type
+macro_rules! with_classifier {
+    ($classifier:expr, $model:ident => $body:expr) => {
+        match $classifier {
+            Classifier::DecisionTree($model) => $body,
+            Classifier::RandomForest($model) => $body,
+            Classifier::GradientBoosting($model) => $body,
+            Classifier::Svm($model) => $body,
+            Classifier::Mlp($model) => $body,
+            Classifier::NaiveBayes($model) => $body,
+            Classifier::Lda($model) => $body,
+            Classifier::Qda($model) => $body,
+        }
+    };
+}
+
+macro_rules! with_regressor {
+    ($regressor:expr, $model:ident => $body:expr) => {
+        match $regressor {
+            Regressor::Linear($model) => $body,
+            Regressor::DecisionTree($model) => $body,
+            Regressor::RandomForest($model) => $body,
+            Regressor::GradientBoosting($model) => $body,
+            Regressor::Svm($model) => $body,
+            Regressor::Mlp($model) => $body,
+        }
+    };
+}
+
+impl Classifier {
+    /// Classifieur non entraîné correspondant à `--model`.
+    fn new(args: &ModelArgs) -> Result<Self, Box<dyn Error>> {
+        let seed = args.seed;
+        Ok(match args.model {
+            ModelKind::DecisionTree => Classifier::DecisionTree(DecisionTreeClassifier::new()),
+            ModelKind::RandomForest => {
+                Classifier::RandomForest(RandomForestClassifier::new().with_random_state(seed))
+            }
+            ModelKind::GradientBoosting => Classifier::GradientBoosting(
+                GradientBoostingClassifier::new().with_random_state(seed),
+            ),
+            ModelKind::Svm => Classifier::Svm(SvmClassifier::new()),
+            ModelKind::Mlp => Classifier::Mlp(MlpClassifier::new(&[100]).with_random_state(seed)),
+            ModelKind::NaiveBayes => Classifier::NaiveBayes(GaussianNB::new()),
+            ModelKind::Lda => Classifier::Lda(LinearDiscriminantAnalysis::new()),
+            ModelKind::Qda => Classifier::Qda(QuadraticDiscriminantAnalysis::new()),
+            ModelKind::Linear => return Err("le modèle linear ne fait que de la régression".into()),
+        })
+    }
+
+    /// Classes vues à l'entraînement, dans l'ordre des colonnes de `predict_proba`.
+    fn classes(&self) -> Vec<usize> {
+        with_classifier!(self, model => model.classes().to_vec())
+    }
+
+    /// Probabilité de chaque classe, `None` pour le SVM qui n'en estime pas.
+    fn predict_proba(&self, x: &Array2<f64>) -> Result<Option<Array2<f64>>, ModelError> {
+        let proba = match self {
+            Classifier::DecisionTree(model) => model.predict_proba(x)?,
+            Classifier::RandomForest(model) => model.predict_proba(x)?,
+            Classifier::GradientBoosting(model) => model.predict_proba(x)?,
+            Classifier::Mlp(model) => model.predict_proba(x)?,
+            Classifier::NaiveBayes(model) => model.predict_proba(x)?,
+            Classifier::Lda(model) => model.predict_proba(x)?,
+            Classifier::Qda(model) => model.predict_proba(x)?,
+            Classifier::Svm(_) => return Ok(None),
+        };
+        Ok(Some(proba))
+    }
+}
+
+impl Fit<usize> for Classifier {
+    fn fit(&mut self, data: &Dataset<usize>) -> Result<(), ModelError> {
+        with_classifier!(self, model => Fit::fit(model, data))
+    }
+}
+
+impl Predict for Classifier {
+    type Output = usize;
+
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        with_classifier!(self, model => Predict::predict(model, x))
+    }
+}
+
+impl Regressor {
+    /// Régresseur non entraîné correspondant à `--model`.
+    fn new(args: &ModelArgs) -> Result<Self, Box<dyn Error>> {
+        let seed = args.seed;
+        Ok(match args.model {
+            ModelKind::Linear => Regressor::Linear(LinearRegression::new()),
+            ModelKind::DecisionTree => Regressor::DecisionTree(DecisionTreeRegressor::new()),
+            ModelKind::RandomForest => {
+                Regressor::RandomForest(RandomForestRegressor::new().with_random_state(seed))
+            }
+            ModelKind::GradientBoosting => Regressor::GradientBoosting(
+                GradientBoostingRegressor::new().with_random_state(seed),
+            ),
+            ModelKind::Svm => Regressor::Svm(SvmRegressor::new()),
+            ModelKind::Mlp => Regressor::Mlp(MlpRegressor::new(&[100]).with_random_state(seed)),
+            ModelKind::NaiveBayes | ModelKind::Lda | ModelKind::Qda => {
+                return Err(format!(
+                    "le modèle {} ne fait que de la classification",
+                    args.model.name()
+                )
+                .into());
+            }
+        })
+    }
+}
+
+impl Fit<f64> for Regressor {
+    fn fit(&mut self, data: &Dataset<f64>) -> Result<(), ModelError> {
+        with_regressor!(self, model => Fit::fit(model, data))
+    }
+}
+
+impl Predict for Regressor {
+    type Output = f64;
+
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        with_regressor!(self, model => Predict::predict(model, x))
+    }
+}
+
+/// Entraîne le prétraitement puis le modèle sur les lignes `x`.
+fn train(
+    args: &ModelArgs,
+    features: &[&str],
+    x: &Array2<f64>,
+    target: &Target,
+) -> Result<TrainedModel, Box<dyn Error>> {
+    let model = match target {
+        Target::Classes(y, encoder) => {
+            let mut pipeline = with_preprocessing(args, features, Classifier::new(args)?)?;
+            pipeline.fit(&Dataset::new(x.clone(), y.clone())?)?;
+            Fitted::Classifier(pipeline, encoder.clone())
+        }
+        Target::Values(y) => {
+            let mut pipeline = with_preprocessing(args, features, Regressor::new(args)?)?;
+            // La cible est aussi standardisée pour les modèles sensibles à l'échelle,
+            // les prédictions sont ensuite ramenées dans l'unité d'origine
+            if args.model.needs_scaling() {
+                let mut y_scaler = StandardScaler::new();
+                let y = y_scaler.fit_transform(&y.clone().insert_axis(Axis(1)))?;
+                pipeline.fit(&Dataset::new(x.clone(), y.column(0).to_owned())?)?;
+                Fitted::Regressor(pipeline, Some(y_scaler))
+            } else {
+                pipeline.fit(&Dataset::new(x.clone(), y.clone())?)?;
+                Fitted::Regressor(pipeline, None)
+            }
+        }
+    };
+    Ok(TrainedModel {
+        kind: args.model,
+        model,
+    })
+}
+
+/// Pipeline non entraîné : imputation demandée par `--impute`, standardisation
+/// pour les modèles sensibles à l'échelle, puis `estimator`.
+fn with_preprocessing<E>(
+    args: &ModelArgs,
+    features: &[&str],
+    estimator: E,
+) -> Result<Pipeline<E>, Box<dyn Error>> {
+    let mut pipeline = Pipeline::new(estimator);
+    if let Some(imputation) = args.impute {
+        pipeline = pipeline.with_step(imputer(imputation, &args.zero_as_missing, features)?);
+    }
+    if args.model.needs_scaling() {
+        pipeline = pipeline.with_step(StandardScaler::new());
+    }
+    Ok(pipeline)
+}
+
+impl TrainedModel {
+    /// Vrai si le prétraitement remplace les valeurs manquantes (`fit --impute`).
+    fn imputes(&self) -> bool {
+        let steps = match &self.model {
+            Fitted::Classifier(pipeline, _) => pipeline.steps(),
+            Fitted::Regressor(pipeline, _) => pipeline.steps(),
+        };
+        steps
+            .iter()
+            .any(|step| matches!(step, Step::SimpleImputer(_) | Step::KnnImputer(_)))
+    }
+
+    /// Applique le prétraitement appris puis le modèle.
+    fn predict(&self, x: &Array2<f64>) -> Result<Prediction, Box<dyn Error>> {
+        Ok(match &self.model {
+            Fitted::Classifier(pipeline, encoder) => {
+                let classifier = pipeline.estimator();
+                let x = pipeline.transform(x)?;
+                let indices = classifier.predict(&x)?;
+                let proba = match classifier.predict_proba(&x)? {
+                    Some(proba) => Some((
+                        encoder
+                            .inverse_transform(&Array1::from(classifier.classes()))?
+                            .to_vec(),
+                        proba,
+                    )),
+                    None => None,
+                };
+                Prediction::Classes {
+                    labels: encoder.inverse_transform(&indices)?,
+                    indices,
+                    proba,
+                }
+            }
+            Fitted::Regressor(pipeline, y_scaler) => {
+                let y_pred = pipeline.predict(x)?;
+                Prediction::Values(match y_scaler {
+                    Some(scaler) => scaler
+                        .inverse_transform(&y_pred.insert_axis(Axis(1)))?
+                        .column(0)
+                        .to_owned(),
+                    None => y_pred,
+                })
+            }
+        })
+    }
+}
+
+/// Imputer non entraîné, les colonnes de `zero_as_missing` traitant 0 comme manquant.
+fn imputer(
+    imputation: Imputation,
+    zero_as_missing: &[String],
+    features: &[&str],
+) -> Result<Step, Box<dyn Error>> {
+    let zero_columns = zero_as_missing
+        .iter()
+        .map(|name| {
+            features
+                .iter()
+                .position(|f| f == name)
+                .ok_or_else(|| format!("--zero-as-missing : '{}' n'est pas une feature", name))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok(match imputation {
+        Imputation::Knn => zero_columns
+            .iter()
+            .fold(KnnImputer::new(5), |imputer, &j| {
+                imputer.with_sentinel(j, 0.0)
+            })
+            .into(),
+        _ => {
+            let strategy = match imputation {
+                Imputation::Mean => ImputeStrategy::Mean,
+                Imputation::Median => ImputeStrategy::Median,
+                _ => ImputeStrategy::MostFrequent,
+            };
+            zero_columns
+                .iter()
+                .fold(
+                    SimpleImputer::new().with_strategy(strategy),
+                    |imputer, &j| imputer.with_sentinel(j, 0.0),
+                )
+                .into()
+        }
+    })
+}
+
+/*
+DONNÉES
+*/
+
+/// Features demandées, ou toutes les colonnes du fichier sauf la cible.
+fn feature_names(data: &DataArgs, target: Option<&String>) -> Result<Vec<String>, Box<dyn Error>> {
+    if data.features.is_empty() {
+        columns_except(&data.data, target)
+    } else {
+        Ok(data.features.clone())
+    }
+}
+
+fn columns_except(path: &Path, target: Option<&String>) -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(read_headers(path)?
+        .into_iter()
+        .filter(|name| Some(name) != target)
+        .collect())
+}
+
+/// Classification ou régression, selon la demande, le modèle et les valeurs de la cible.
+fn resolve_task(args: &ModelArgs, labels: &Array1<String>) -> Result<Task, Box<dyn Error>> {
+    let forced = match args.model {
+        ModelKind::Linear => Some(Task::Regression),
+        ModelKind::NaiveBayes | ModelKind::Lda | ModelKind::Qda => Some(Task::Classification),
+        _ => None,
+    };
+    match (args.task, forced) {
+        (Task::Auto, Some(task)) => Ok(task),
+        (Task::Auto, None) => Ok(guess_task(labels)),
+        (task, Some(forced)) if task != forced => Err(format!(
+            "le modèle {} ne gère pas ce type de problème",
+            args.model.name()
+        )
+        .into()),
+        (task, _) => Ok(task),
+    }
+}
+
+// Une cible textuelle, ou entière avec peu de valeurs distinctes, est une classe
+fn guess_task(labels: &Array1<String>) -> Task {
+    let Ok(values) = labels
+        .iter()
+        .map(|l| l.parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+    else {
+        return Task::Classification;
+    };
+    let mut distinct: Vec<&String> = labels.iter().collect();
+    distinct.sort();
+    distinct.dedup();
+    if values.iter().all(|v| v.fract() == 0.0) && distinct.len() <= MAX_AUTO_CLASSES {
+        Task::Classification
+    } else {
+        Task::Regression
+    }
+}
+
+fn encode_target(labels: &Array1<String>, task: Task) -> Result<Target, Box<dyn Error>> {
+    match task {
+        Task::Regression => Ok(Target::Values(
+            labels
+                .iter()
+                .map(|l| {
+                    l.parse::<f64>()
+                        .map_err(|_| format!("cible non numérique : '{}'", l))
+                })
+                .collect::<Result<_, _>>()?,
+        )),
+        Task::Classification | Task::Auto => {
+            let mut encoder = LabelEncoder::new();
+            let y = encoder.fit_transform(labels)?;
+            Ok(Target::Classes(y, encoder))
+        }
+    }
+}
+
+// Le module linear_model travaille avec nalgebra
+fn to_dmatrix(x: &Array2<f64>) -> DMatrix<f64> {
+    DMatrix::from_row_iterator(x.nrows(), x.ncols(), x.iter().copied())
+}
+
+/// Recopie le CSV `input` dans `output` en ajoutant les colonnes `columns`
+/// (nom et une valeur par ligne).
+fn write_with_columns(
+    input: &Path,
+    output: &Path,
+    columns: &[(String, Vec<String>)],
+) -> Result<(), Box<dyn Error>> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(File::open(input)?);
+    let mut writer = Writer::from_path(output)?;
+
+    let mut headers = reader.headers()?.clone();
+    for (name, _) in columns {
+        headers.push_field(name);
+    }
+    writer.write_record(&headers)?;
+    for (i, record) in reader.records().enumerate() {
+        let mut record = record?;
+        for (_, values) in columns {
+            record.push_field(&values[i]);
+        }
+        writer.write_record(&record)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
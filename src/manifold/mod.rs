@@ -0,0 +1,9 @@
+//! Plongements non linéaires pour visualiser des données en 2D.
+//!
+//! - [`Tsne`] : t-SNE (van der Maaten & Hinton, 2008), accéléré par l'approximation
+//!   de Barnes–Hut (van der Maaten, 2014) en O(n log n) par itération.
+
+mod quad_tree;
+mod tsne;
+
+pub use tsne::Tsne;
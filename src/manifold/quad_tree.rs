@@ -0,0 +1,145 @@
+//! Quadtree pour l'approximation de Barnes–Hut des forces de répulsion de t-SNE.
+//!
+//! Un groupe de points lointain est remplacé par son centre de masse : si la taille
+//! de la cellule divisée par sa distance au point est inférieure à θ, toute la
+//! cellule contribue comme un seul point de masse `count`.
+
+// Au-delà, des points confondus restent ensemble dans une même feuille
+const MAX_DEPTH: usize = 50;
+
+#[derive(Debug, Clone)]
+struct Cell {
+    center: [f64; 2],
+    half_width: f64,
+    center_of_mass: [f64; 2],
+    count: usize,
+    // Indice du premier des quatre enfants (NO, NE, SO, SE), s'ils existent
+    children: Option<usize>,
+    point: Option<[f64; 2]>,
+}
+
+impl Cell {
+    fn new(center: [f64; 2], half_width: f64) -> Self {
+        Self {
+            center,
+            half_width,
+            center_of_mass: [0.0; 2],
+            count: 0,
+            children: None,
+            point: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct QuadTree {
+    cells: Vec<Cell>,
+}
+
+impl QuadTree {
+    pub(crate) fn new(points: &[[f64; 2]]) -> Self {
+        let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+        for p in points {
+            for d in 0..2 {
+                min[d] = min[d].min(p[d]);
+                max[d] = max[d].max(p[d]);
+            }
+        }
+        let center = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        // Légère marge pour que les points extrêmes soient strictement dans la racine
+        let half_width = ((max[0] - min[0]).max(max[1] - min[1]) / 2.0).max(1e-5) * (1.0 + 1e-5);
+
+        let mut tree = Self {
+            cells: vec![Cell::new(center, half_width)],
+        };
+        for &p in points {
+            tree.insert(0, p, 0);
+        }
+        tree
+    }
+
+    fn insert(&mut self, cell: usize, p: [f64; 2], depth: usize) {
+        let node = &mut self.cells[cell];
+        let count = node.count as f64;
+        for (com, &v) in node.center_of_mass.iter_mut().zip(&p) {
+            *com = (*com * count + v) / (count + 1.0);
+        }
+        node.count += 1;
+
+        if node.children.is_none() {
+            if node.count == 1 {
+                node.point = Some(p);
+                return;
+            }
+            if depth >= MAX_DEPTH {
+                return;
+            }
+            // Feuille occupée : on la divise et on redescend le point qu'elle contenait
+            let old = node
+                .point
+                .take()
+                .expect("une feuille occupée contient un point");
+            self.subdivide(cell);
+            let child = self.child_for(cell, old);
+            self.insert(child, old, depth + 1);
+        }
+        let child = self.child_for(cell, p);
+        self.insert(child, p, depth + 1);
+    }
+
+    fn subdivide(&mut self, cell: usize) {
+        let (center, half) = (self.cells[cell].center, self.cells[cell].half_width / 2.0);
+        let first = self.cells.len();
+        for (dx, dy) in [(-1.0, 1.0), (1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)] {
+            self.cells.push(Cell::new(
+                [center[0] + dx * half, center[1] + dy * half],
+                half,
+            ));
+        }
+        self.cells[cell].children = Some(first);
+    }
+
+    fn child_for(&self, cell: usize, p: [f64; 2]) -> usize {
+        let node = &self.cells[cell];
+        let first = node.children.expect("cellule divisée");
+        let east = usize::from(p[0] > node.center[0]);
+        let south = usize::from(p[1] <= node.center[1]);
+        first + 2 * south + east
+    }
+
+    /// Force de répulsion non normalisée Σⱼ qᵢⱼ² (yᵢ - yⱼ) et somme Σⱼ qᵢⱼ,
+    /// avec qᵢⱼ = 1 / (1 + ‖yᵢ - yⱼ‖²), en excluant le point lui-même.
+    pub(crate) fn repulsion(&self, y: [f64; 2], theta: f64) -> ([f64; 2], f64) {
+        let mut force = [0.0; 2];
+        let mut sum_q = 0.0;
+        let mut stack = vec![0];
+        while let Some(cell) = stack.pop() {
+            let node = &self.cells[cell];
+            if node.count == 0 {
+                continue;
+            }
+            let diff = [y[0] - node.center_of_mass[0], y[1] - node.center_of_mass[1]];
+            let dist2 = diff[0] * diff[0] + diff[1] * diff[1];
+            let width = 2.0 * node.half_width;
+
+            match node.children {
+                Some(first) if width * width >= theta * theta * dist2 => {
+                    stack.extend(first..first + 4);
+                }
+                _ => {
+                    // Une feuille à distance nulle contient le point lui-même (et ses doublons)
+                    let mass = if node.children.is_none() && dist2 < 1e-24 {
+                        node.count as f64 - 1.0
+                    } else {
+                        node.count as f64
+                    };
+                    let q = 1.0 / (1.0 + dist2);
+                    sum_q += mass * q;
+                    force[0] += mass * q * q * diff[0];
+                    force[1] += mass * q * q * diff[1];
+                }
+            }
+        }
+        (force, sum_q)
+    }
+}
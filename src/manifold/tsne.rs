@@ -0,0 +1,339 @@
+use ndarray::{Array2, ArrayView2, Axis};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::quad_tree::QuadTree;
+use crate::distance::{Distance, SquaredEuclidean};
+use crate::error::ModelError;
+
+// Itérations pendant lesquelles l'exagération précoce est appliquée, avec une inertie plus faible
+const EXAGGERATION_ITER: usize = 250;
+
+/// Similarités d'entrée symétriques pᵢⱼ, stockées par ligne sous forme creuse.
+type SparseRows = Vec<Vec<(usize, f64)>>;
+
+/// t-SNE de Barnes–Hut : plongement 2D qui préserve les voisinages.
+///
+/// Les similarités entre observations sont des gaussiennes dont la largeur est
+/// ajustée pour que chaque point ait `perplexity` voisins effectifs. Le plongement
+/// minimise la divergence de Kullback–Leibler entre ces similarités et celles,
+/// à queue lourde (loi de Student), des points en 2D.
+///
+/// # Example
+/// ```
+/// use ndarray::Array2;
+/// use rust_first_steps::manifold::Tsne;
+///
+/// // Deux groupes bien séparés en 5 dimensions
+/// let x = Array2::from_shape_fn((40, 5), |(i, j)| if i < 20 { 0.0 } else { 10.0 } + (i * j % 7) as f64 * 0.1);
+/// let embedding = Tsne::new().with_perplexity(5.0).with_n_iter(300).fit_transform(&x).unwrap();
+/// assert_eq!(embedding.dim(), (40, 2));
+///
+/// // Une seule observation n'a pas de voisin
+/// assert!(Tsne::new().with_perplexity(0.5).fit_transform(&Array2::zeros((1, 5))).is_err());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tsne {
+    perplexity: f64,
+    learning_rate: f64,
+    early_exaggeration: f64,
+    n_iter: usize,
+    theta: f64,
+    random_state: u64,
+    kl_divergence: Option<f64>,
+}
+
+impl Default for Tsne {
+    fn default() -> Self {
+        Self {
+            perplexity: 30.0,
+            learning_rate: 200.0,
+            early_exaggeration: 12.0,
+            n_iter: 1000,
+            theta: 0.5,
+            random_state: 0,
+            kl_divergence: None,
+        }
+    }
+}
+
+impl Tsne {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nombre effectif de voisins de chaque point, typiquement entre 5 et 50 (30 par défaut).
+    pub fn with_perplexity(mut self, perplexity: f64) -> Self {
+        self.perplexity = perplexity;
+        self
+    }
+
+    /// Pas de la descente de gradient (200 par défaut).
+    pub fn with_learning_rate(mut self, learning_rate: f64) -> Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+
+    /// Facteur appliqué aux pᵢⱼ pendant les 250 premières itérations pour former des
+    /// groupes compacts et bien séparés (12 par défaut).
+    pub fn with_early_exaggeration(mut self, early_exaggeration: f64) -> Self {
+        self.early_exaggeration = early_exaggeration;
+        self
+    }
+
+    /// Nombre total d'itérations (1000 par défaut).
+    pub fn with_n_iter(mut self, n_iter: usize) -> Self {
+        self.n_iter = n_iter;
+        self
+    }
+
+    /// Seuil θ de Barnes–Hut : 0 donne le calcul exact en O(n²), des valeurs plus
+    /// grandes accélèrent au prix de la précision (0.5 par défaut).
+    pub fn with_theta(mut self, theta: f64) -> Self {
+        self.theta = theta;
+        self
+    }
+
+    /// Graine de l'initialisation aléatoire du plongement.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.random_state = seed;
+        self
+    }
+
+    /// Divergence KL entre similarités d'entrée et du plongement après le dernier `fit_transform`.
+    pub fn kl_divergence(&self) -> Option<f64> {
+        self.kl_divergence
+    }
+
+    /// Calcule le plongement 2D des lignes de `x`.
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let n = x.nrows();
+        if n == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        // Chaque observation a besoin d'au moins un voisin
+        if n < 2 {
+            return Err(ModelError::InvalidParameter(
+                "t-SNE demande au moins 2 observations".to_string(),
+            ));
+        }
+        if !(self.perplexity > 0.0 && self.perplexity < n as f64) {
+            return Err(ModelError::InvalidParameter(format!(
+                "la perplexité doit être dans ]0, {}[",
+                n
+            )));
+        }
+        if self.learning_rate <= 0.0 || self.early_exaggeration <= 0.0 || self.theta < 0.0 {
+            return Err(ModelError::InvalidParameter(
+                "learning_rate et early_exaggeration doivent être strictement positifs, theta positif"
+                    .to_string(),
+            ));
+        }
+
+        let p = joint_probabilities(x.view(), self.perplexity)?;
+
+        // Initialisation gaussienne de faible variance
+        let mut rng = StdRng::seed_from_u64(self.random_state);
+        let mut y: Vec<[f64; 2]> = (0..n)
+            .map(|_| {
+                [
+                    1e-4 * standard_normal(&mut rng),
+                    1e-4 * standard_normal(&mut rng),
+                ]
+            })
+            .collect();
+        let mut update = vec![[0.0; 2]; n];
+        let mut gains = vec![[1.0_f64; 2]; n];
+
+        for iter in 0..self.n_iter {
+            let (exaggeration, momentum) = if iter < EXAGGERATION_ITER {
+                (self.early_exaggeration, 0.5)
+            } else {
+                (1.0, 0.8)
+            };
+            let (grad, _) = gradient(&p, &y, exaggeration, self.theta);
+
+            for i in 0..n {
+                for d in 0..2 {
+                    // Gains adaptatifs (delta-bar-delta) : on accélère tant que le gradient
+                    // garde la même direction que la mise à jour précédente
+                    gains[i][d] = if (grad[i][d] > 0.0) != (update[i][d] > 0.0) {
+                        gains[i][d] + 0.2
+                    } else {
+                        (gains[i][d] * 0.8).max(0.01)
+                    };
+                    update[i][d] =
+                        momentum * update[i][d] - self.learning_rate * gains[i][d] * grad[i][d];
+                    y[i][d] += update[i][d];
+                }
+            }
+            // Le plongement est recentré à chaque itération
+            let mean = y
+                .iter()
+                .fold([0.0; 2], |acc, p| [acc[0] + p[0], acc[1] + p[1]]);
+            for point in &mut y {
+                point[0] -= mean[0] / n as f64;
+                point[1] -= mean[1] / n as f64;
+            }
+        }
+
+        let (_, kl) = gradient(&p, &y, 1.0, self.theta);
+        self.kl_divergence = Some(kl);
+        Ok(Array2::from_shape_fn((n, 2), |(i, d)| y[i][d]))
+    }
+}
+
+/// Gradient de la divergence KL et valeur (approchée) de la divergence :
+/// ∂C/∂yᵢ = 4 [Σⱼ pᵢⱼ qᵢⱼ (yᵢ - yⱼ) - Σⱼ qᵢⱼ² (yᵢ - yⱼ) / Z], avec qᵢⱼ = 1 / (1 + ‖yᵢ - yⱼ‖²).
+fn gradient(p: &SparseRows, y: &[[f64; 2]], exaggeration: f64, theta: f64) -> (Vec<[f64; 2]>, f64) {
+    let tree = QuadTree::new(y);
+    // Répulsion : approximée par Barnes–Hut, calculée en parallèle sur les points
+    let repulsion: Vec<([f64; 2], f64)> =
+        y.par_iter().map(|&yi| tree.repulsion(yi, theta)).collect();
+    let z: f64 = repulsion
+        .iter()
+        .map(|(_, sum_q)| sum_q)
+        .sum::<f64>()
+        .max(f64::MIN_POSITIVE);
+
+    let mut kl = 0.0;
+    let grad = (0..y.len())
+        .map(|i| {
+            // Attraction : seulement entre voisins, là où pᵢⱼ est non nul
+            let mut attraction = [0.0; 2];
+            for &(j, pij) in &p[i] {
+                let diff = [y[i][0] - y[j][0], y[i][1] - y[j][1]];
+                let q = 1.0 / (1.0 + diff[0] * diff[0] + diff[1] * diff[1]);
+                attraction[0] += exaggeration * pij * q * diff[0];
+                attraction[1] += exaggeration * pij * q * diff[1];
+                kl += pij * (pij / (q / z).max(f64::MIN_POSITIVE)).ln();
+            }
+            let (force, _) = repulsion[i];
+            [
+                4.0 * (attraction[0] - force[0] / z),
+                4.0 * (attraction[1] - force[1] / z),
+            ]
+        })
+        .collect();
+    (grad, kl)
+}
+
+/// Similarités d'entrée pᵢⱼ = (pⱼ|ᵢ + pᵢ|ⱼ) / 2n, limitées aux ⌊3 · perplexité⌋ plus proches voisins.
+fn joint_probabilities(x: ArrayView2<'_, f64>, perplexity: f64) -> Result<SparseRows, ModelError> {
+    let n = x.nrows();
+    let k = ((3.0 * perplexity) as usize).clamp(1, n - 1);
+    let distances = SquaredEuclidean.pairwise(x, x)?;
+
+    let conditional: SparseRows = distances
+        .axis_iter(Axis(0))
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut neighbors: Vec<(usize, f64)> = row
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(j, &d)| (j, d))
+                .collect();
+            neighbors.sort_by(|a, b| a.1.total_cmp(&b.1));
+            neighbors.truncate(k);
+            let dists: Vec<f64> = neighbors.iter().map(|&(_, d)| d).collect();
+            let probs = conditional_row(&dists, perplexity);
+            neighbors.iter().map(|&(j, _)| j).zip(probs).collect()
+        })
+        .collect();
+
+    // Symétrisation : pᵢⱼ = pⱼᵢ
+    let mut joint: Vec<std::collections::HashMap<usize, f64>> = vec![Default::default(); n];
+    for (i, row) in conditional.iter().enumerate() {
+        for &(j, pji) in row {
+            *joint[i].entry(j).or_insert(0.0) += pji / (2.0 * n as f64);
+            *joint[j].entry(i).or_insert(0.0) += pji / (2.0 * n as f64);
+        }
+    }
+    Ok(joint
+        .into_iter()
+        .map(|row| {
+            let mut row: Vec<(usize, f64)> = row.into_iter().collect();
+            row.sort_by_key(|&(j, _)| j);
+            row
+        })
+        .collect())
+}
+
+/// pⱼ|ᵢ ∝ exp(-β dᵢⱼ²), où β est trouvé par dichotomie pour que l'entropie de la ligne
+/// vaille ln(perplexité).
+fn conditional_row(dists: &[f64], perplexity: f64) -> Vec<f64> {
+    let target = perplexity.ln();
+    // On retire la plus petite distance : les probabilités normalisées ne changent pas
+    let d_min = dists.iter().copied().fold(f64::INFINITY, f64::min);
+    let (mut beta, mut beta_min, mut beta_max) = (1.0, 0.0, f64::INFINITY);
+    let mut probs = vec![0.0; dists.len()];
+
+    for _ in 0..100 {
+        for (p, &d) in probs.iter_mut().zip(dists) {
+            *p = (-(d - d_min) * beta).exp();
+        }
+        let sum: f64 = probs.iter().sum();
+        let mean_dist: f64 = probs
+            .iter()
+            .zip(dists)
+            .map(|(p, &d)| p * (d - d_min))
+            .sum::<f64>()
+            / sum;
+        let entropy = sum.ln() + beta * mean_dist;
+
+        let diff = entropy - target;
+        if diff.abs() < 1e-5 {
+            break;
+        }
+        // Entropie trop grande : distribution trop étalée, il faut augmenter β
+        if diff > 0.0 {
+            beta_min = beta;
+            beta = if beta_max.is_infinite() {
+                beta * 2.0
+            } else {
+                (beta + beta_max) / 2.0
+            };
+        } else {
+            beta_max = beta;
+            beta = (beta + beta_min) / 2.0;
+        }
+    }
+    let sum: f64 = probs.iter().sum();
+    probs.iter().map(|p| p / sum).collect()
+}
+
+/// Tirage d'une loi normale centrée réduite (méthode de Box–Muller).
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.random::<f64>().max(f64::MIN_POSITIVE);
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn single_observation_is_rejected() {
+        let mut tsne = Tsne::new().with_perplexity(0.5);
+        assert!(matches!(
+            tsne.fit_transform(&array![[1.0, 2.0, 3.0]]),
+            Err(ModelError::InvalidParameter(_))
+        ));
+        assert!(tsne.kl_divergence().is_none());
+    }
+
+    #[test]
+    fn two_observations_are_embedded() {
+        let mut tsne = Tsne::new().with_perplexity(0.5).with_n_iter(300);
+        let embedding = tsne.fit_transform(&array![[0.0, 0.0], [1.0, 1.0]]).unwrap();
+        assert_eq!(embedding.dim(), (2, 2));
+        assert!(embedding.iter().all(|v| v.is_finite()));
+    }
+}
@@ -0,0 +1,100 @@
+//! Métriques d'évaluation des modèles.
+
+use ndarray::Array1;
+
+use crate::error::ModelError;
+
+/// Proportion de prédictions correctes.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::metrics::accuracy;
+///
+/// let y_true = array![0, 1, 1, 2];
+/// let y_pred = array![0, 1, 2, 2];
+/// assert_eq!(accuracy(&y_true, &y_pred).unwrap(), 0.75);
+/// assert!(accuracy(&y_true, &array![0, 1]).is_err());
+/// ```
+pub fn accuracy<T: PartialEq>(y_true: &Array1<T>, y_pred: &Array1<T>) -> Result<f64, ModelError> {
+    check_lengths(y_true, y_pred)?;
+    let n_correct = y_true.iter().zip(y_pred).filter(|(t, p)| t == p).count();
+    Ok(n_correct as f64 / y_true.len() as f64)
+}
+
+/// Erreur quadratique moyenne : (1/n) Σ (yᵢ - ŷᵢ)².
+pub fn mean_squared_error(y_true: &Array1<f64>, y_pred: &Array1<f64>) -> Result<f64, ModelError> {
+    check_lengths(y_true, y_pred)?;
+    Ok((y_true - y_pred).mapv(|e| e.powi(2)).mean().unwrap_or(0.0))
+}
+
+/// Coefficient de détermination R² = 1 - SS_res / SS_tot.
+///
+/// Vaut 1 pour une prédiction parfaite et 0 pour un modèle qui prédit toujours la moyenne.
+/// Pour une cible constante (SS_tot = 0), vaut 1 si la prédiction est exacte et 0 sinon.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::metrics::r2_score;
+///
+/// assert_eq!(r2_score(&array![1.0, 2.0, 3.0], &array![1.0, 2.0, 3.0]).unwrap(), 1.0);
+/// assert_eq!(r2_score(&array![2.0, 2.0], &array![2.0, 2.0]).unwrap(), 1.0);
+/// assert_eq!(r2_score(&array![2.0, 2.0], &array![1.0, 3.0]).unwrap(), 0.0);
+/// ```
+pub fn r2_score(y_true: &Array1<f64>, y_pred: &Array1<f64>) -> Result<f64, ModelError> {
+    check_lengths(y_true, y_pred)?;
+    let mean = y_true.mean().unwrap_or(0.0);
+    let ss_res: f64 = (y_true - y_pred).mapv(|e| e.powi(2)).sum();
+    let ss_tot: f64 = y_true.mapv(|v| (v - mean).powi(2)).sum();
+    if ss_tot == 0.0 {
+        return Ok(if ss_res == 0.0 { 1.0 } else { 0.0 });
+    }
+    Ok(1.0 - ss_res / ss_tot)
+}
+
+/// Vérifie que les vraies valeurs et les prédictions sont non vides et de même taille.
+fn check_lengths<T>(y_true: &Array1<T>, y_pred: &Array1<T>) -> Result<(), ModelError> {
+    if y_true.is_empty() {
+        return Err(ModelError::EmptyDataset);
+    }
+    if y_true.len() != y_pred.len() {
+        return Err(ModelError::ShapeMismatch(format!(
+            "{} valeurs cibles mais {} prédictions",
+            y_true.len(),
+            y_pred.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn accuracy_rejects_length_mismatch_and_empty_input() {
+        let result = accuracy(&array![0, 1, 1], &array![0, 1]);
+        assert!(matches!(result, Err(ModelError::ShapeMismatch(_))));
+        let empty = Array1::<usize>::zeros(0);
+        assert!(matches!(
+            accuracy(&empty, &empty),
+            Err(ModelError::EmptyDataset)
+        ));
+    }
+
+    #[test]
+    fn regression_metrics_reject_length_mismatch() {
+        let (y_true, y_pred) = (array![1.0, 2.0, 3.0], array![1.0, 2.0]);
+        assert!(matches!(
+            mean_squared_error(&y_true, &y_pred),
+            Err(ModelError::ShapeMismatch(_))
+        ));
+        assert!(matches!(
+            r2_score(&y_true, &y_pred),
+            Err(ModelError::ShapeMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,162 @@
+//! Découpage des données pour l'évaluation des modèles : jeu de test et validation
+//! croisée.
+
+use ndarray::{Array1, Array2, Axis};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::data::Dataset;
+use crate::error::ModelError;
+use crate::traits::{Fit, Predict};
+
+/// Mélange les observations puis les sépare en un jeu d'entraînement et un jeu de test.
+///
+/// # Arguments
+/// * `x` - Les features, une observation par ligne.
+/// * `y` - La cible, une valeur par observation.
+/// * `test_size` - Proportion des observations placées dans le jeu de test (entre 0 et 1).
+/// * `seed` - Graine du générateur aléatoire, pour un découpage reproductible.
+///
+/// # Returns
+/// * `(x_train, x_test, y_train, y_test)`
+pub fn train_test_split<T: Clone>(
+    x: &Array2<f64>,
+    y: &Array1<T>,
+    test_size: f64,
+    seed: u64,
+) -> (Array2<f64>, Array2<f64>, Array1<T>, Array1<T>) {
+    assert_eq!(
+        x.nrows(),
+        y.len(),
+        "X et y doivent avoir le même nombre de lignes !"
+    );
+    let (train_idx, test_idx) = shuffled_split(x.nrows(), test_size, seed);
+    (
+        x.select(Axis(0), &train_idx),
+        x.select(Axis(0), &test_idx),
+        y.select(Axis(0), &train_idx),
+        y.select(Axis(0), &test_idx),
+    )
+}
+
+/// Indices `(train, test)` de `n_samples` observations mélangées.
+pub(crate) fn shuffled_split(n_samples: usize, test_size: f64, seed: u64) -> Fold {
+    assert!(
+        (0.0..=1.0).contains(&test_size),
+        "test_size doit être entre 0 et 1 !"
+    );
+    let mut indices: Vec<usize> = (0..n_samples).collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+    indices.shuffle(&mut rng);
+
+    let n_test = (n_samples as f64 * test_size).round() as usize;
+    let (test_idx, train_idx) = indices.split_at(n_test);
+    (train_idx.to_vec(), test_idx.to_vec())
+}
+
+/// Indices des observations `(train, test)` d'un pli de validation croisée.
+pub type Fold = (Vec<usize>, Vec<usize>);
+
+/// Validation croisée en k plis : chaque observation est dans le jeu de test d'un
+/// seul pli. Les `n % n_splits` premiers plis ont une observation de plus.
+#[derive(Debug, Clone, Copy)]
+pub struct KFold {
+    n_splits: usize,
+    shuffle: bool,
+    random_state: u64,
+}
+
+impl KFold {
+    pub fn new(n_splits: usize) -> Self {
+        Self {
+            n_splits,
+            shuffle: false,
+            random_state: 0,
+        }
+    }
+
+    /// Mélange les observations avant de les répartir (non par défaut : les plis
+    /// suivent alors l'ordre du fichier).
+    pub fn with_shuffle(mut self, shuffle: bool) -> Self {
+        self.shuffle = shuffle;
+        self
+    }
+
+    /// Graine du mélange.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.random_state = seed;
+        self
+    }
+
+    /// Indices `(train, test)` de chaque pli pour `n_samples` observations.
+    pub fn split(&self, n_samples: usize) -> Result<Vec<Fold>, ModelError> {
+        if self.n_splits < 2 || self.n_splits > n_samples {
+            return Err(ModelError::InvalidParameter(format!(
+                "n_splits doit être entre 2 et le nombre d'observations ({}), reçu {}",
+                n_samples, self.n_splits
+            )));
+        }
+        let mut indices: Vec<usize> = (0..n_samples).collect();
+        if self.shuffle {
+            indices.shuffle(&mut StdRng::seed_from_u64(self.random_state));
+        }
+
+        let mut folds = Vec::with_capacity(self.n_splits);
+        let mut start = 0;
+        for k in 0..self.n_splits {
+            let size = n_samples / self.n_splits + usize::from(k < n_samples % self.n_splits);
+            let test = indices[start..start + size].to_vec();
+            let train = [&indices[..start], &indices[start + size..]].concat();
+            folds.push((train, test));
+            start += size;
+        }
+        Ok(folds)
+    }
+}
+
+/// Score de `estimator` sur chaque pli de `cv`.
+///
+/// Une copie non entraînée de `estimator` est entraînée sur les plis d'entraînement
+/// puis évaluée sur le pli de test. Avec un [`crate::pipeline::Pipeline`], le
+/// prétraitement est lui aussi réappris sur chaque pli : rien n'est estimé sur les
+/// observations de test.
+///
+/// # Arguments
+/// * `scoring` - Métrique `(y_true, y_pred)`, par exemple [`crate::metrics::accuracy`].
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::data::Dataset;
+/// use rust_first_steps::linear_model::LinearRegression;
+/// use rust_first_steps::metrics::r2_score;
+/// use rust_first_steps::model_selection::{KFold, cross_val_score};
+///
+/// let x = array![[0.0], [1.0], [2.0], [3.0], [4.0], [5.0]];
+/// let y = array![1.0, 3.0, 5.0, 7.0, 9.0, 11.0];
+/// let data = Dataset::new(x, y).unwrap();
+/// let scores = cross_val_score(&LinearRegression::new(), &data, &KFold::new(3), r2_score).unwrap();
+/// assert!(scores.iter().all(|&s| (s - 1.0).abs() < 1e-10));
+/// ```
+pub fn cross_val_score<T, E, F>(
+    estimator: &E,
+    data: &Dataset<T>,
+    cv: &KFold,
+    scoring: F,
+) -> Result<Array1<f64>, ModelError>
+where
+    T: Clone,
+    E: Fit<T> + Predict<Output = T> + Clone,
+    F: Fn(&Array1<T>, &Array1<T>) -> Result<f64, ModelError>,
+{
+    cv.split(data.n_samples())?
+        .into_iter()
+        .map(|(train, test)| {
+            let mut model = estimator.clone();
+            model.fit(&data.select(&train))?;
+            let test = data.select(&test);
+            scoring(test.y(), &model.predict(test.x())?)
+        })
+        .collect()
+}
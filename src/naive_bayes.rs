@@ -0,0 +1,406 @@
+//! Classifieurs bayésiens naïfs : gaussien, multinomial et de Bernoulli.
+//!
+//! Les trois modèles supposent les features indépendantes sachant la classe :
+//! log P(c | x) = log P(c) + Σⱼ log P(xⱼ | c) - log P(x).
+//! Ils ne diffèrent que par la loi choisie pour P(xⱼ | c). L'entraînement se fait en
+//! une seule passe sur les données, ce qui en fait une bonne base de comparaison.
+//!
+//! Les classes sont des entiers `usize` (ex : 0, 1, 2 pour les espèces d'iris).
+
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, class_log_prior, unique_classes};
+
+/// Méthodes de prédiction communes aux classifieurs bayésiens naïfs.
+///
+/// Chaque modèle fournit sa log-vraisemblance jointe log P(c) + log P(x | c),
+/// le trait en déduit les probabilités et les prédictions.
+pub trait NaiveBayes {
+    /// Classes vues pendant l'entraînement, triées par ordre croissant.
+    fn classes(&self) -> &[usize];
+
+    /// Log-vraisemblance jointe log P(c) + log P(x | c), matrice (n_observations × n_classes).
+    fn joint_log_likelihood(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError>;
+
+    /// Log-probabilités a posteriori log P(c | x) de chaque classe.
+    fn predict_log_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mut jll = self.joint_log_likelihood(x)?;
+        for mut row in jll.rows_mut() {
+            // log-sum-exp : on soustrait le maximum pour éviter que exp() ne déborde
+            let max = row.fold(f64::NEG_INFINITY, |acc, &v| acc.max(v));
+            let log_norm = max + row.mapv(|v| (v - max).exp()).sum().ln();
+            row -= log_norm;
+        }
+        Ok(jll)
+    }
+
+    /// Probabilités a posteriori P(c | x), chaque ligne somme à 1.
+    fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        Ok(self.predict_log_proba(x)?.mapv(f64::exp))
+    }
+
+    /// Classe la plus probable pour chaque observation.
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let jll = self.joint_log_likelihood(x)?;
+        let classes = self.classes();
+        Ok(jll
+            .rows()
+            .into_iter()
+            .map(|row| classes[argmax(row.iter().copied())])
+            .collect())
+    }
+}
+
+/// Naive Bayes gaussien : chaque feature suit une loi normale N(μ_c, σ²_c) dans chaque classe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaussianNB {
+    var_smoothing: f64,
+    priors: Option<Vec<f64>>,
+    classes: Vec<usize>,
+    class_log_prior: Array1<f64>,
+    theta: Array2<f64>,
+    var: Array2<f64>,
+}
+
+impl Default for GaussianNB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GaussianNB {
+    pub fn new() -> Self {
+        Self {
+            var_smoothing: 1e-9,
+            priors: None,
+            classes: Vec::new(),
+            class_log_prior: Array1::zeros(0),
+            theta: Array2::zeros((0, 0)),
+            var: Array2::zeros((0, 0)),
+        }
+    }
+
+    /// Part de la plus grande variance ajoutée à toutes les variances, pour éviter
+    /// une division par zéro sur une feature constante (1e-9 par défaut).
+    pub fn with_var_smoothing(mut self, var_smoothing: f64) -> Self {
+        self.var_smoothing = var_smoothing;
+        self
+    }
+
+    /// Probabilités a priori des classes (par défaut : fréquences observées).
+    pub fn with_priors(mut self, priors: Vec<f64>) -> Self {
+        self.priors = Some(priors);
+        self
+    }
+
+    /// Moyennes par classe, matrice (n_classes × n_features).
+    pub fn theta(&self) -> &Array2<f64> {
+        &self.theta
+    }
+
+    /// Variances par classe (lissage inclus), matrice (n_classes × n_features).
+    pub fn var(&self) -> &Array2<f64> {
+        &self.var
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        if self.var_smoothing < 0.0 {
+            return Err(ModelError::InvalidParameter(
+                "var_smoothing doit être positif".to_string(),
+            ));
+        }
+        let classes = unique_classes(y);
+        let n_features = x.ncols();
+
+        let mut theta = Array2::zeros((classes.len(), n_features));
+        let mut var = Array2::zeros((classes.len(), n_features));
+        let mut counts = Vec::with_capacity(classes.len());
+        for (k, &class) in classes.iter().enumerate() {
+            let x_c = select_class(x, y, class);
+            let mean = x_c
+                .mean_axis(Axis(0))
+                .expect("au moins une observation par classe");
+            let variance = x_c.var_axis(Axis(0), 0.0);
+            theta.row_mut(k).assign(&mean);
+            var.row_mut(k).assign(&variance);
+            counts.push(x_c.nrows() as f64);
+        }
+
+        // Comme scikit-learn : epsilon proportionnel à la plus grande variance des features
+        let max_var = x.var_axis(Axis(0), 0.0).fold(0.0_f64, |acc, &v| acc.max(v));
+        let epsilon = self.var_smoothing * max_var;
+        var.mapv_inplace(|v| v + epsilon);
+        if var.iter().any(|&v| v <= 0.0) {
+            return Err(ModelError::InvalidParameter(
+                "variance nulle : augmenter var_smoothing".to_string(),
+            ));
+        }
+
+        self.class_log_prior = class_log_prior(self.priors.as_deref(), &counts, true)?;
+        self.classes = classes;
+        self.theta = theta;
+        self.var = var;
+        Ok(())
+    }
+}
+
+impl NaiveBayes for GaussianNB {
+    fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    fn joint_log_likelihood(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.theta.ncols(), x.ncols())?;
+
+        let mut jll = Array2::zeros((x.nrows(), self.classes.len()));
+        for k in 0..self.classes.len() {
+            let mean = self.theta.row(k);
+            let var = self.var.row(k);
+            // Terme constant : -½ Σ log(2π σ²)
+            let log_norm = -0.5 * var.mapv(|v| (2.0 * std::f64::consts::PI * v).ln()).sum();
+            for (i, row) in x.rows().into_iter().enumerate() {
+                let mahalanobis: f64 = row
+                    .iter()
+                    .zip(mean.iter().zip(var.iter()))
+                    .map(|(xi, (mu, v))| (xi - mu).powi(2) / v)
+                    .sum();
+                jll[[i, k]] = self.class_log_prior[k] + log_norm - 0.5 * mahalanobis;
+            }
+        }
+        Ok(jll)
+    }
+}
+
+/// Naive Bayes multinomial : adapté aux features de comptage (entiers positifs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultinomialNB {
+    alpha: f64,
+    fit_prior: bool,
+    priors: Option<Vec<f64>>,
+    classes: Vec<usize>,
+    class_log_prior: Array1<f64>,
+    feature_log_prob: Array2<f64>,
+}
+
+impl Default for MultinomialNB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultinomialNB {
+    pub fn new() -> Self {
+        Self {
+            alpha: 1.0,
+            fit_prior: true,
+            priors: None,
+            classes: Vec::new(),
+            class_log_prior: Array1::zeros(0),
+            feature_log_prob: Array2::zeros((0, 0)),
+        }
+    }
+
+    /// Lissage additif de Laplace/Lidstone (1.0 par défaut).
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Si `false`, les classes sont supposées équiprobables.
+    pub fn with_fit_prior(mut self, fit_prior: bool) -> Self {
+        self.fit_prior = fit_prior;
+        self
+    }
+
+    /// Probabilités a priori des classes, prioritaires sur `fit_prior`.
+    pub fn with_priors(mut self, priors: Vec<f64>) -> Self {
+        self.priors = Some(priors);
+        self
+    }
+
+    /// log P(xⱼ | c), matrice (n_classes × n_features).
+    pub fn feature_log_prob(&self) -> &Array2<f64> {
+        &self.feature_log_prob
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        check_alpha(self.alpha)?;
+        if x.iter().any(|&v| v < 0.0) {
+            return Err(ModelError::InvalidParameter(
+                "MultinomialNB n'accepte que des features positives".to_string(),
+            ));
+        }
+        let classes = unique_classes(y);
+
+        let mut feature_log_prob = Array2::zeros((classes.len(), x.ncols()));
+        let mut counts = Vec::with_capacity(classes.len());
+        for (k, &class) in classes.iter().enumerate() {
+            let x_c = select_class(x, y, class);
+            let feature_count = x_c.sum_axis(Axis(0)) + self.alpha;
+            let total = feature_count.sum();
+            feature_log_prob
+                .row_mut(k)
+                .assign(&feature_count.mapv(|c| (c / total).ln()));
+            counts.push(x_c.nrows() as f64);
+        }
+
+        self.class_log_prior = class_log_prior(self.priors.as_deref(), &counts, self.fit_prior)?;
+        self.classes = classes;
+        self.feature_log_prob = feature_log_prob;
+        Ok(())
+    }
+}
+
+impl NaiveBayes for MultinomialNB {
+    fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    fn joint_log_likelihood(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.feature_log_prob.ncols(), x.ncols())?;
+        Ok(x.dot(&self.feature_log_prob.t()) + &self.class_log_prior)
+    }
+}
+
+/// Naive Bayes de Bernoulli : adapté aux features binaires (présence / absence).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BernoulliNB {
+    alpha: f64,
+    binarize: Option<f64>,
+    fit_prior: bool,
+    priors: Option<Vec<f64>>,
+    classes: Vec<usize>,
+    class_log_prior: Array1<f64>,
+    feature_log_prob: Array2<f64>,
+    neg_log_prob: Array2<f64>,
+}
+
+impl Default for BernoulliNB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BernoulliNB {
+    pub fn new() -> Self {
+        Self {
+            alpha: 1.0,
+            binarize: Some(0.0),
+            fit_prior: true,
+            priors: None,
+            classes: Vec::new(),
+            class_log_prior: Array1::zeros(0),
+            feature_log_prob: Array2::zeros((0, 0)),
+            neg_log_prob: Array2::zeros((0, 0)),
+        }
+    }
+
+    /// Lissage additif de Laplace/Lidstone (1.0 par défaut).
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Seuil au-dessus duquel une feature vaut 1 (0.0 par défaut).
+    /// `None` si les données sont déjà binaires.
+    pub fn with_binarize(mut self, threshold: Option<f64>) -> Self {
+        self.binarize = threshold;
+        self
+    }
+
+    /// Si `false`, les classes sont supposées équiprobables.
+    pub fn with_fit_prior(mut self, fit_prior: bool) -> Self {
+        self.fit_prior = fit_prior;
+        self
+    }
+
+    /// Probabilités a priori des classes, prioritaires sur `fit_prior`.
+    pub fn with_priors(mut self, priors: Vec<f64>) -> Self {
+        self.priors = Some(priors);
+        self
+    }
+
+    /// log P(xⱼ = 1 | c), matrice (n_classes × n_features).
+    pub fn feature_log_prob(&self) -> &Array2<f64> {
+        &self.feature_log_prob
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        check_alpha(self.alpha)?;
+        let x = self.binarized(x);
+        let classes = unique_classes(y);
+
+        let mut feature_log_prob = Array2::zeros((classes.len(), x.ncols()));
+        let mut neg_log_prob = Array2::zeros((classes.len(), x.ncols()));
+        let mut counts = Vec::with_capacity(classes.len());
+        for (k, &class) in classes.iter().enumerate() {
+            let x_c = select_class(&x, y, class);
+            let n_c = x_c.nrows() as f64;
+            let p = (x_c.sum_axis(Axis(0)) + self.alpha) / (n_c + 2.0 * self.alpha);
+            feature_log_prob.row_mut(k).assign(&p.mapv(f64::ln));
+            neg_log_prob.row_mut(k).assign(&p.mapv(|p| (1.0 - p).ln()));
+            counts.push(n_c);
+        }
+
+        self.class_log_prior = class_log_prior(self.priors.as_deref(), &counts, self.fit_prior)?;
+        self.classes = classes;
+        self.feature_log_prob = feature_log_prob;
+        self.neg_log_prob = neg_log_prob;
+        Ok(())
+    }
+
+    fn binarized(&self, x: &Array2<f64>) -> Array2<f64> {
+        match self.binarize {
+            Some(threshold) => x.mapv(|v| if v > threshold { 1.0 } else { 0.0 }),
+            None => x.clone(),
+        }
+    }
+}
+
+impl NaiveBayes for BernoulliNB {
+    fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    fn joint_log_likelihood(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.classes.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.feature_log_prob.ncols(), x.ncols())?;
+        let x = self.binarized(x);
+        // Σⱼ xⱼ log p + (1 - xⱼ) log(1 - p) = x · (log p - log(1 - p)) + Σⱼ log(1 - p)
+        let delta = &self.feature_log_prob - &self.neg_log_prob;
+        Ok(x.dot(&delta.t()) + self.neg_log_prob.sum_axis(Axis(1)) + &self.class_log_prior)
+    }
+}
+
+/// Lignes de `x` appartenant à la classe `class`.
+fn select_class(x: &Array2<f64>, y: &Array1<usize>, class: usize) -> Array2<f64> {
+    let indices: Vec<usize> = y
+        .iter()
+        .enumerate()
+        .filter(|&(_, &c)| c == class)
+        .map(|(i, _)| i)
+        .collect();
+    x.select(Axis(0), &indices)
+}
+
+fn check_alpha(alpha: f64) -> Result<(), ModelError> {
+    if alpha <= 0.0 {
+        return Err(ModelError::InvalidParameter(
+            "alpha doit être strictement positif".to_string(),
+        ));
+    }
+    Ok(())
+}
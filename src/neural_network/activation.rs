@@ -0,0 +1,208 @@
+use ndarray::{Array2, Axis, Zip};
+use serde::{Deserialize, Serialize};
+
+// √(2/π), pour l'approximation de GELU par tanh
+const SQRT_2_OVER_PI: f64 = 0.797_884_560_802_865_4;
+const GELU_COEF: f64 = 0.044_715;
+
+/// Fonction d'activation appliquée à la sortie d'une couche dense.
+///
+/// Toutes les activations sauf softmax s'appliquent élément par élément : [`value`]
+/// et [`derivative`] donnent f(x) et f'(x) pour un scalaire (`None` pour softmax).
+/// Softmax n'est définie que ligne par ligne, avec [`forward`] et [`backward`]. Les
+/// formules évitent les dépassements de `exp()` pour les grandes valeurs de |x|.
+///
+/// [`value`]: Activation::value
+/// [`derivative`]: Activation::derivative
+/// [`forward`]: Activation::forward
+/// [`backward`]: Activation::backward
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Activation {
+    /// f(x) = x, pour la sortie d'un régresseur.
+    Identity,
+    /// f(x) = max(0, x)
+    Relu,
+    /// f(x) = x si x > 0, alpha · x sinon : le gradient ne s'annule jamais complètement.
+    LeakyRelu { alpha: f64 },
+    /// f(x) = x si x > 0, alpha · (eˣ - 1) sinon : lisse, avec des sorties négatives bornées par -alpha.
+    Elu { alpha: f64 },
+    /// f(x) = x · Φ(x), où Φ est la fonction de répartition de la loi normale
+    /// (approximation par tanh de Hendrycks & Gimpel).
+    Gelu,
+    /// f(x) = x · σ(x) (aussi appelée swish).
+    Silu,
+    /// f(x) = σ(x) = 1 / (1 + e⁻ˣ)
+    Sigmoid,
+    /// f(x) = tanh(x)
+    Tanh,
+    /// f(x) = ln(1 + eˣ), version lisse de ReLU.
+    Softplus,
+    /// Normalise chaque ligne en probabilités : f(z)ₖ = e^(zₖ) / Σⱼ e^(zⱼ).
+    ///
+    /// Ligne par ligne seulement : softmax d'un scalaire seul vaut toujours 1, elle n'a
+    /// pas de version élément par élément.
+    Softmax,
+}
+
+impl Activation {
+    /// f(x) pour un scalaire.
+    ///
+    /// Renvoie `None` pour [`Activation::Softmax`], qui n'existe que ligne par ligne :
+    /// utiliser [`Activation::forward`].
+    ///
+    /// ```
+    /// use rust_first_steps::neural_network::Activation;
+    ///
+    /// assert_eq!(Activation::Relu.value(-2.0), Some(0.0));
+    /// assert_eq!(Activation::Softmax.value(1.0), None);
+    /// ```
+    pub fn value(&self, x: f64) -> Option<f64> {
+        let y = match *self {
+            Activation::Identity => x,
+            Activation::Relu => x.max(0.0),
+            Activation::LeakyRelu { alpha } => {
+                if x > 0.0 {
+                    x
+                } else {
+                    alpha * x
+                }
+            }
+            // exp_m1 reste précis pour eˣ - 1 quand x est proche de 0
+            Activation::Elu { alpha } => {
+                if x > 0.0 {
+                    x
+                } else {
+                    alpha * x.exp_m1()
+                }
+            }
+            Activation::Gelu => 0.5 * x * (1.0 + gelu_inner(x).tanh()),
+            Activation::Silu => x * sigmoid(x),
+            Activation::Sigmoid => sigmoid(x),
+            Activation::Tanh => x.tanh(),
+            Activation::Softplus => softplus(x),
+            Activation::Softmax => return None,
+        };
+        Some(y)
+    }
+
+    /// f'(x) pour un scalaire (à droite en 0 pour ReLU et ses variantes).
+    ///
+    /// Renvoie `None` pour [`Activation::Softmax`] : sa jacobienne ligne par ligne est
+    /// appliquée par [`Activation::backward`].
+    ///
+    /// ```
+    /// use rust_first_steps::neural_network::Activation;
+    ///
+    /// assert_eq!(Activation::Relu.derivative(0.0), Some(1.0));
+    /// assert_eq!(Activation::LeakyRelu { alpha: 0.1 }.derivative(-1.0), Some(0.1));
+    /// assert_eq!(Activation::Softmax.derivative(1.0), None);
+    /// ```
+    pub fn derivative(&self, x: f64) -> Option<f64> {
+        let dy = match *self {
+            Activation::Identity => 1.0,
+            Activation::Relu => {
+                if x >= 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Activation::LeakyRelu { alpha } => {
+                if x >= 0.0 {
+                    1.0
+                } else {
+                    alpha
+                }
+            }
+            Activation::Elu { alpha } => {
+                if x >= 0.0 {
+                    1.0
+                } else {
+                    alpha * x.exp()
+                }
+            }
+            Activation::Gelu => {
+                let t = gelu_inner(x).tanh();
+                let inner_derivative = SQRT_2_OVER_PI * (1.0 + 3.0 * GELU_COEF * x * x);
+                0.5 * (1.0 + t) + 0.5 * x * (1.0 - t * t) * inner_derivative
+            }
+            Activation::Silu => {
+                let s = sigmoid(x);
+                s * (1.0 + x * (1.0 - s))
+            }
+            Activation::Sigmoid => {
+                let s = sigmoid(x);
+                s * (1.0 - s)
+            }
+            Activation::Tanh => 1.0 - x.tanh().powi(2),
+            // La dérivée de softplus est la sigmoïde
+            Activation::Softplus => sigmoid(x),
+            Activation::Softmax => return None,
+        };
+        Some(dy)
+    }
+
+    /// Applique l'activation à chaque ligne (une observation par ligne).
+    pub fn forward(&self, z: &Array2<f64>) -> Array2<f64> {
+        match *self {
+            Activation::Softmax => {
+                let mut a = z.clone();
+                for mut row in a.axis_iter_mut(Axis(0)) {
+                    // On retire le max de la ligne pour éviter un dépassement de exp()
+                    let max = row.fold(f64::NEG_INFINITY, |acc, &v| acc.max(v));
+                    row.mapv_inplace(|v| (v - max).exp());
+                    let sum = row.sum();
+                    row /= sum;
+                }
+                a
+            }
+            activation => z.mapv(|v| {
+                activation
+                    .value(v)
+                    .expect("seule softmax n'a pas de version scalaire")
+            }),
+        }
+    }
+
+    /// Rétropropage le gradient `grad` (par rapport à la sortie a = f(z)) en un
+    /// gradient par rapport à l'entrée z.
+    pub fn backward(&self, z: &Array2<f64>, a: &Array2<f64>, grad: &Array2<f64>) -> Array2<f64> {
+        match *self {
+            Activation::Softmax => {
+                // Produit par la jacobienne diag(a) - a aᵀ, ligne par ligne
+                let mut out = grad.clone();
+                for (mut row, a_row) in out.axis_iter_mut(Axis(0)).zip(a.axis_iter(Axis(0))) {
+                    let dot = row.dot(&a_row);
+                    Zip::from(&mut row)
+                        .and(&a_row)
+                        .for_each(|g, &a| *g = a * (*g - dot));
+                }
+                out
+            }
+            activation => Zip::from(z).and(grad).map_collect(|&z, &g| {
+                g * activation
+                    .derivative(z)
+                    .expect("seule softmax n'a pas de version scalaire")
+            }),
+        }
+    }
+}
+
+/// Sigmoïde stable numériquement (pas de exp() d'un grand nombre positif).
+fn sigmoid(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
+
+/// ln(1 + eˣ) = max(x, 0) + ln(1 + e^(-|x|)), sans dépassement pour x grand.
+fn softplus(x: f64) -> f64 {
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+
+fn gelu_inner(x: f64) -> f64 {
+    SQRT_2_OVER_PI * (x + GELU_COEF * x * x * x)
+}
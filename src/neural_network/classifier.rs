@@ -0,0 +1,156 @@
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use super::network::{Network, TrainParams, TrainingHistory};
+use super::{Activation, Loss, Optimizer};
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, unique_classes};
+
+/// Perceptron multicouche pour la classification : sortie softmax et entropie croisée.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::neural_network::{MlpClassifier, Optimizer};
+///
+/// // XOR : impossible à séparer sans couche cachée
+/// let x = array![[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]];
+/// let y = array![0, 1, 1, 0];
+/// let mut mlp = MlpClassifier::new(&[8])
+///     .with_optimizer(Optimizer::adam(0.05))
+///     .with_batch_size(4)
+///     .with_max_epochs(500);
+/// mlp.fit(&x, &y).unwrap();
+/// assert_eq!(mlp.predict(&x).unwrap(), y);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MlpClassifier {
+    params: TrainParams,
+    classes: Vec<usize>,
+    network: Option<Network>,
+    history: TrainingHistory,
+}
+
+impl MlpClassifier {
+    /// Réseau avec les couches cachées données, par exemple `&[32, 16]`.
+    pub fn new(hidden_layer_sizes: &[usize]) -> Self {
+        Self {
+            params: TrainParams {
+                hidden_layer_sizes: hidden_layer_sizes.to_vec(),
+                ..TrainParams::default()
+            },
+            classes: Vec::new(),
+            network: None,
+            history: TrainingHistory::default(),
+        }
+    }
+
+    /// Activation des couches cachées (ReLU par défaut).
+    pub fn with_activation(mut self, activation: Activation) -> Self {
+        self.params.activation = activation;
+        self
+    }
+
+    /// Optimiseur (Adam avec η = 1e-3 par défaut).
+    pub fn with_optimizer(mut self, optimizer: Optimizer) -> Self {
+        self.params.optimizer = optimizer;
+        self
+    }
+
+    /// Taille des mini-batchs (32 par défaut).
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.params.batch_size = batch_size;
+        self
+    }
+
+    /// Nombre de passes sur le jeu d'entraînement (200 par défaut).
+    pub fn with_max_epochs(mut self, max_epochs: usize) -> Self {
+        self.params.max_epochs = max_epochs;
+        self
+    }
+
+    /// Met de côté une fraction des données pour suivre la perte de validation.
+    pub fn with_validation_fraction(mut self, validation_fraction: f64) -> Self {
+        self.params.validation_fraction = Some(validation_fraction);
+        self
+    }
+
+    /// Calcule les gradients avec le ruban d'[`autograd`](crate::autograd) plutôt que
+    /// par la rétropropagation écrite à la main : plus lent, mêmes poids aux arrondis près.
+    pub fn with_autograd(mut self, autograd: bool) -> Self {
+        self.params.autograd = autograd;
+        self
+    }
+
+    /// Graine de l'initialisation des poids et du mélange des mini-batchs.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        let classes = unique_classes(y);
+        if classes.len() < 2 {
+            return Err(ModelError::InvalidParameter(
+                "au moins deux classes sont nécessaires".to_string(),
+            ));
+        }
+        // Cibles one-hot : une colonne par classe
+        let mut target = Array2::zeros((y.len(), classes.len()));
+        for (i, c) in y.iter().enumerate() {
+            let k = classes
+                .binary_search(c)
+                .expect("classe vue pendant unique_classes");
+            target[[i, k]] = 1.0;
+        }
+
+        let (network, history) = self.params.train(
+            x,
+            &target,
+            Activation::Softmax,
+            Loss::CrossEntropy,
+            |proba, target| {
+                let hits = proba
+                    .rows()
+                    .into_iter()
+                    .zip(target.rows())
+                    .filter(|(p, t)| argmax(p.iter().copied()) == argmax(t.iter().copied()))
+                    .count();
+                Ok(hits as f64 / proba.nrows() as f64)
+            },
+        )?;
+        self.classes = classes;
+        self.network = Some(network);
+        self.history = history;
+        Ok(())
+    }
+
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let network = self.network()?;
+        check_n_features(network.layers()[0].weights().nrows(), x.ncols())?;
+        Ok(network.forward(x))
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let proba = self.predict_proba(x)?;
+        Ok(proba
+            .rows()
+            .into_iter()
+            .map(|row| self.classes[argmax(row.iter().copied())])
+            .collect())
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    pub fn network(&self) -> Result<&Network, ModelError> {
+        self.network.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Entropie croisée et accuracy à chaque époque.
+    pub fn history(&self) -> &TrainingHistory {
+        &self.history
+    }
+}
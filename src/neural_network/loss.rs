@@ -0,0 +1,54 @@
+use ndarray::{Array2, Zip};
+
+use crate::autograd::Var;
+
+// Les probabilités sont bornées pour éviter ln(0)
+const EPS: f64 = 1e-15;
+
+/// Fonction de perte minimisée pendant l'entraînement, moyennée sur les observations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Loss {
+    /// -Σₖ yₖ ln(pₖ), pour des cibles one-hot et une sortie softmax.
+    CrossEntropy,
+    /// ½ Σₖ (ŷₖ - yₖ)²
+    MeanSquaredError,
+}
+
+impl Loss {
+    pub fn value(&self, output: &Array2<f64>, target: &Array2<f64>) -> f64 {
+        let n = output.nrows() as f64;
+        let total = match self {
+            Loss::CrossEntropy => Zip::from(output)
+                .and(target)
+                .fold(0.0, |acc, &p, &y| acc - y * p.clamp(EPS, 1.0).ln()),
+            Loss::MeanSquaredError => Zip::from(output)
+                .and(target)
+                .fold(0.0, |acc, &p, &y| acc + 0.5 * (p - y) * (p - y)),
+        };
+        total / n
+    }
+
+    /// Gradient de la perte moyenne par rapport à la sortie du réseau.
+    pub fn gradient(&self, output: &Array2<f64>, target: &Array2<f64>) -> Array2<f64> {
+        let n = output.nrows() as f64;
+        match self {
+            Loss::CrossEntropy => Zip::from(output)
+                .and(target)
+                .map_collect(|&p, &y| -y / (p.clamp(EPS, 1.0) * n)),
+            Loss::MeanSquaredError => (output - target) / n,
+        }
+    }
+
+    /// Même perte que [`value`](Self::value), enregistrée sur le ruban de
+    /// [`autograd`](crate::autograd) pour en obtenir le gradient par [`Var::backward`].
+    pub fn tape_value<'t>(&self, output: Var<'t>, target: Var<'t>) -> Var<'t> {
+        let n = output.value().nrows() as f64;
+        match self {
+            Loss::CrossEntropy => (target * output.clamp(EPS, 1.0).ln()).sum() * (-1.0 / n),
+            Loss::MeanSquaredError => {
+                let diff = output - target;
+                (diff * diff).sum() * (0.5 / n)
+            }
+        }
+    }
+}
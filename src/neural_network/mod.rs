@@ -0,0 +1,25 @@
+//! Réseaux de neurones denses (perceptrons multicouches) sur ndarray.
+//!
+//! Chaque couche calcule a = f(x W + b). L'entraînement enchaîne, pour chaque
+//! mini-batch, une passe avant, le calcul de la perte, la rétropropagation du
+//! gradient couche par couche (règle de la chaîne) et la mise à jour des poids
+//! par l'optimiseur (SGD ou Adam). Avec `with_autograd(true)`, les gradients sont
+//! obtenus par le ruban d'[`autograd`](crate::autograd) au lieu de la rétropropagation
+//! écrite à la main.
+//!
+//! - [`MlpClassifier`] : sortie softmax, entropie croisée.
+//! - [`MlpRegressor`] : sortie linéaire, erreur quadratique.
+
+mod activation;
+mod classifier;
+mod loss;
+mod network;
+mod optimizer;
+mod regressor;
+
+pub use activation::Activation;
+pub use classifier::MlpClassifier;
+pub use loss::Loss;
+pub use network::{Dense, Network, TrainingHistory};
+pub use optimizer::Optimizer;
+pub use regressor::MlpRegressor;
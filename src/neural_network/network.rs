@@ -0,0 +1,358 @@
+use ndarray::{Array1, Array2, Axis, Ix1, Ix2};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::optimizer::ParamState;
+use super::{Activation, Loss, Optimizer};
+use crate::autograd::{Tape, Var};
+use crate::error::ModelError;
+
+/// Couche dense : a = f(x W + b), avec W de taille (entrées × sorties).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dense {
+    weights: Array2<f64>,
+    bias: Array1<f64>,
+    activation: Activation,
+}
+
+impl Dense {
+    /// Poids tirés uniformément dans [-l, l] : initialisation de He (l = √(6 / entrées))
+    /// pour ReLU et ses variantes, de Glorot (l = √(6 / (entrées + sorties))) sinon.
+    fn new(n_inputs: usize, n_outputs: usize, activation: Activation, rng: &mut StdRng) -> Self {
+        let limit = match activation {
+            Activation::Relu
+            | Activation::LeakyRelu { .. }
+            | Activation::Elu { .. }
+            | Activation::Gelu
+            | Activation::Silu => (6.0 / n_inputs as f64).sqrt(),
+            _ => (6.0 / (n_inputs + n_outputs) as f64).sqrt(),
+        };
+        Self {
+            weights: Array2::from_shape_fn((n_inputs, n_outputs), |_| {
+                rng.random_range(-limit..limit)
+            }),
+            bias: Array1::zeros(n_outputs),
+            activation,
+        }
+    }
+
+    pub fn weights(&self) -> &Array2<f64> {
+        &self.weights
+    }
+
+    pub fn bias(&self) -> &Array1<f64> {
+        &self.bias
+    }
+
+    pub fn activation(&self) -> Activation {
+        self.activation
+    }
+
+    /// Renvoie z = x W + b et a = f(z).
+    fn forward(&self, x: &Array2<f64>) -> (Array2<f64>, Array2<f64>) {
+        let z = x.dot(&self.weights) + &self.bias;
+        let a = self.activation.forward(&z);
+        (z, a)
+    }
+}
+
+/// Gradients (dW, db) d'une couche dense.
+type LayerGradient = (Array2<f64>, Array1<f64>);
+
+/// Empilement de couches denses (perceptron multicouche).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Network {
+    layers: Vec<Dense>,
+}
+
+impl Network {
+    /// `sizes` donne le nombre de neurones de l'entrée jusqu'à la sortie, par exemple [4, 16, 3].
+    fn new(sizes: &[usize], hidden: Activation, output: Activation, rng: &mut StdRng) -> Self {
+        let layers = sizes
+            .windows(2)
+            .enumerate()
+            .map(|(l, w)| {
+                let activation = if l + 2 == sizes.len() { output } else { hidden };
+                Dense::new(w[0], w[1], activation, rng)
+            })
+            .collect();
+        Self { layers }
+    }
+
+    pub fn layers(&self) -> &[Dense] {
+        &self.layers
+    }
+
+    /// Sortie du réseau pour chaque ligne de `x`.
+    pub fn forward(&self, x: &Array2<f64>) -> Array2<f64> {
+        self.layers
+            .iter()
+            .fold(x.clone(), |a, layer| layer.forward(&a).1)
+    }
+
+    /// Une passe avant puis arrière sur un mini-batch ; renvoie la perte et les
+    /// gradients (dW, db) de chaque couche.
+    fn gradients(
+        &self,
+        x: &Array2<f64>,
+        target: &Array2<f64>,
+        loss: Loss,
+    ) -> (f64, Vec<LayerGradient>) {
+        // Passe avant : on garde l'entrée, z et a de chaque couche pour la rétropropagation
+        let mut inputs = Vec::with_capacity(self.layers.len());
+        let mut cache = Vec::with_capacity(self.layers.len());
+        let mut a = x.clone();
+        for layer in &self.layers {
+            let (z, next) = layer.forward(&a);
+            inputs.push(a);
+            cache.push((z, next.clone()));
+            a = next;
+        }
+        let value = loss.value(&a, target);
+
+        // Passe arrière : règle de la chaîne de la sortie vers l'entrée
+        let mut grads = Vec::with_capacity(self.layers.len());
+        let last = self.layers.len() - 1;
+        let mut delta =
+            if self.layers[last].activation == Activation::Softmax && loss == Loss::CrossEntropy {
+                // Softmax + entropie croisée : le gradient par rapport à z se simplifie en (p - y) / n
+                (&a - target) / x.nrows() as f64
+            } else {
+                let (z, out) = &cache[last];
+                self.layers[last]
+                    .activation
+                    .backward(z, out, &loss.gradient(&a, target))
+            };
+        for l in (0..self.layers.len()).rev() {
+            let d_weights = inputs[l].t().dot(&delta);
+            let d_bias = delta.sum_axis(Axis(0));
+            if l > 0 {
+                let grad_input = delta.dot(&self.layers[l].weights.t());
+                let (z, out) = &cache[l - 1];
+                delta = self.layers[l - 1].activation.backward(z, out, &grad_input);
+            }
+            grads.push((d_weights, d_bias));
+        }
+        grads.reverse();
+        (value, grads)
+    }
+
+    /// Mêmes perte et gradients que [`gradients`](Self::gradients), obtenus par le
+    /// ruban de [`autograd`](crate::autograd) plutôt que par la rétropropagation écrite
+    /// à la main.
+    fn tape_gradients(
+        &self,
+        x: &Array2<f64>,
+        target: &Array2<f64>,
+        loss: Loss,
+    ) -> (f64, Vec<LayerGradient>) {
+        let tape = Tape::new();
+        // Le biais devient une ligne 1 × sorties, diffusée sur les observations
+        let params: Vec<(Var, Var)> = self
+            .layers
+            .iter()
+            .map(|layer| {
+                let bias = layer.bias.clone().insert_axis(Axis(0));
+                (tape.var(layer.weights.clone()), tape.var(bias))
+            })
+            .collect();
+        let output = self
+            .layers
+            .iter()
+            .zip(&params)
+            .fold(tape.var(x.clone()), |a, (layer, &(w, b))| {
+                (a.matmul(w) + b).activation(layer.activation)
+            });
+        let value = loss.tape_value(output, tape.var(target.clone()));
+
+        let grads = value.backward();
+        let layer_grads = params
+            .iter()
+            .map(|&(w, b)| {
+                let d_weights = grads.wrt(w).expect("la perte dépend de tous les poids");
+                let d_bias = grads.wrt(b).expect("la perte dépend de tous les biais");
+                (d_weights.clone(), d_bias.row(0).to_owned())
+            })
+            .collect();
+        (value.item(), layer_grads)
+    }
+}
+
+/// Courbes d'apprentissage : perte et score (accuracy ou R²) à la fin de chaque époque.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrainingHistory {
+    pub loss: Vec<f64>,
+    pub score: Vec<f64>,
+    /// Vides si aucune fraction de validation n'a été demandée.
+    pub validation_loss: Vec<f64>,
+    pub validation_score: Vec<f64>,
+}
+
+/// Hyperparamètres communs au classifieur et au régresseur.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TrainParams {
+    pub hidden_layer_sizes: Vec<usize>,
+    pub activation: Activation,
+    pub optimizer: Optimizer,
+    pub batch_size: usize,
+    pub max_epochs: usize,
+    pub validation_fraction: Option<f64>,
+    pub random_state: u64,
+    /// Gradients calculés par le ruban d'autograd (absent des modèles sauvegardés avant).
+    #[serde(default)]
+    pub autograd: bool,
+}
+
+impl Default for TrainParams {
+    fn default() -> Self {
+        Self {
+            hidden_layer_sizes: vec![100],
+            activation: Activation::Relu,
+            optimizer: Optimizer::default(),
+            batch_size: 32,
+            max_epochs: 200,
+            validation_fraction: None,
+            random_state: 0,
+            autograd: false,
+        }
+    }
+}
+
+impl TrainParams {
+    fn validate(&self) -> Result<(), ModelError> {
+        let invalid = |msg: &str| Err(ModelError::InvalidParameter(msg.to_string()));
+        if self.hidden_layer_sizes.contains(&0) {
+            return invalid("chaque couche cachée doit avoir au moins un neurone");
+        }
+        if self.batch_size == 0 || self.max_epochs == 0 {
+            return invalid("batch_size et max_epochs doivent être >= 1");
+        }
+        if self.optimizer.learning_rate() <= 0.0 {
+            return invalid("le taux d'apprentissage doit être strictement positif");
+        }
+        if matches!(self.validation_fraction, Some(f) if !(f > 0.0 && f < 1.0)) {
+            return invalid("validation_fraction doit être dans ]0, 1[");
+        }
+        if self.activation == Activation::Softmax {
+            return invalid("softmax est réservée à la couche de sortie");
+        }
+        Ok(())
+    }
+
+    /// Entraîne un réseau par descente de gradient sur mini-batchs.
+    ///
+    /// `target` contient une ligne par observation (one-hot en classification) ;
+    /// `score` évalue les sorties du réseau pour remplir l'historique.
+    pub(crate) fn train(
+        &self,
+        x: &Array2<f64>,
+        target: &Array2<f64>,
+        output: Activation,
+        loss: Loss,
+        score: impl Fn(&Array2<f64>, &Array2<f64>) -> Result<f64, ModelError>,
+    ) -> Result<(Network, TrainingHistory), ModelError> {
+        self.validate()?;
+        if self.validation_fraction.is_some() && x.nrows() < 2 {
+            return Err(ModelError::InvalidParameter(
+                "validation_fraction demande au moins 2 observations (entraînement et validation)"
+                    .to_string(),
+            ));
+        }
+        let mut rng = StdRng::seed_from_u64(self.random_state);
+
+        // Mise de côté éventuelle d'un jeu de validation
+        let mut indices: Vec<usize> = (0..x.nrows()).collect();
+        indices.shuffle(&mut rng);
+        let n_val = self.validation_fraction.map_or(0, |f| {
+            ((x.nrows() as f64 * f).round() as usize).clamp(1, x.nrows() - 1)
+        });
+        let val = indices.split_off(x.nrows() - n_val);
+        let mut train = indices;
+        let (x_val, target_val) = (x.select(Axis(0), &val), target.select(Axis(0), &val));
+
+        let mut sizes = vec![x.ncols()];
+        sizes.extend(&self.hidden_layer_sizes);
+        sizes.push(target.ncols());
+        let mut network = Network::new(&sizes, self.activation, output, &mut rng);
+        let mut states: Vec<(ParamState<Ix2>, ParamState<Ix1>)> = network
+            .layers
+            .iter()
+            .map(|layer| {
+                (
+                    ParamState::new(layer.weights.raw_dim()),
+                    ParamState::new(layer.bias.raw_dim()),
+                )
+            })
+            .collect();
+
+        let mut history = TrainingHistory::default();
+        for _ in 0..self.max_epochs {
+            // Nouvel ordre des observations à chaque époque, puis découpage en mini-batchs
+            train.shuffle(&mut rng);
+            let mut epoch_loss = 0.0;
+            for batch in train.chunks(self.batch_size) {
+                let x_batch = x.select(Axis(0), batch);
+                let target_batch = target.select(Axis(0), batch);
+                let (value, grads) = if self.autograd {
+                    network.tape_gradients(&x_batch, &target_batch, loss)
+                } else {
+                    network.gradients(&x_batch, &target_batch, loss)
+                };
+                epoch_loss += value * batch.len() as f64;
+                for ((layer, state), (d_weights, d_bias)) in
+                    network.layers.iter_mut().zip(&mut states).zip(grads)
+                {
+                    state
+                        .0
+                        .update(&self.optimizer, &mut layer.weights, &d_weights);
+                    state.1.update(&self.optimizer, &mut layer.bias, &d_bias);
+                }
+            }
+            history.loss.push(epoch_loss / train.len() as f64);
+            let x_train = x.select(Axis(0), &train);
+            history.score.push(score(
+                &network.forward(&x_train),
+                &target.select(Axis(0), &train),
+            )?);
+            if !val.is_empty() {
+                let output_val = network.forward(&x_val);
+                history
+                    .validation_loss
+                    .push(loss.value(&output_val, &target_val));
+                history
+                    .validation_score
+                    .push(score(&output_val, &target_val)?);
+            }
+        }
+        Ok((network, history))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn tape_gradients_match_backpropagation() {
+        let x = array![[0.5, -1.0, 2.0], [1.5, 0.3, -0.4], [-0.7, 2.0, 0.1]];
+        let one_hot = array![[1.0, 0.0], [0.0, 1.0], [1.0, 0.0]];
+        let mut rng = StdRng::seed_from_u64(0);
+        for (output, loss) in [
+            (Activation::Softmax, Loss::CrossEntropy),
+            (Activation::Identity, Loss::MeanSquaredError),
+        ] {
+            let network = Network::new(&[3, 4, 2], Activation::Tanh, output, &mut rng);
+            let (value, grads) = network.gradients(&x, &one_hot, loss);
+            let (tape_value, tape_grads) = network.tape_gradients(&x, &one_hot, loss);
+            assert!((value - tape_value).abs() < 1e-12);
+            for ((dw, db), (tape_dw, tape_db)) in grads.iter().zip(&tape_grads) {
+                assert!((dw - tape_dw).iter().all(|e| e.abs() < 1e-12));
+                assert!((db - tape_db).iter().all(|e| e.abs() < 1e-12));
+            }
+        }
+    }
+}
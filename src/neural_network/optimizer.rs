@@ -0,0 +1,112 @@
+use ndarray::{Array, Dimension, Zip};
+use serde::{Deserialize, Serialize};
+
+/// Règle de mise à jour des poids à partir de leur gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Optimizer {
+    /// Descente de gradient stochastique, avec un terme d'inertie optionnel (0 = SGD simple).
+    Sgd { learning_rate: f64, momentum: f64 },
+    /// Adam (Kingma & Ba, 2014) : pas adapté à chaque poids à partir des moyennes
+    /// mobiles du gradient et de son carré.
+    Adam {
+        learning_rate: f64,
+        beta1: f64,
+        beta2: f64,
+        epsilon: f64,
+    },
+}
+
+impl Default for Optimizer {
+    /// Adam avec un taux d'apprentissage de 1e-3.
+    fn default() -> Self {
+        Optimizer::adam(1e-3)
+    }
+}
+
+impl Optimizer {
+    pub fn sgd(learning_rate: f64, momentum: f64) -> Self {
+        Optimizer::Sgd {
+            learning_rate,
+            momentum,
+        }
+    }
+
+    /// Adam avec les valeurs usuelles β₁ = 0.9, β₂ = 0.999, ε = 1e-8.
+    pub fn adam(learning_rate: f64) -> Self {
+        Optimizer::Adam {
+            learning_rate,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+        }
+    }
+
+    pub(crate) fn learning_rate(&self) -> f64 {
+        match *self {
+            Optimizer::Sgd { learning_rate, .. } | Optimizer::Adam { learning_rate, .. } => {
+                learning_rate
+            }
+        }
+    }
+}
+
+/// Mémoire de l'optimiseur pour un tableau de paramètres (vitesse ou moments d'Adam).
+#[derive(Debug, Clone)]
+pub(crate) struct ParamState<D: Dimension> {
+    first: Array<f64, D>,
+    second: Array<f64, D>,
+    step: i32,
+}
+
+impl<D: Dimension> ParamState<D> {
+    pub(crate) fn new(shape: D) -> Self {
+        Self {
+            first: Array::zeros(shape.clone()),
+            second: Array::zeros(shape),
+            step: 0,
+        }
+    }
+
+    /// Met à jour `param` en place à partir de son gradient.
+    pub(crate) fn update(
+        &mut self,
+        optimizer: &Optimizer,
+        param: &mut Array<f64, D>,
+        grad: &Array<f64, D>,
+    ) {
+        self.step += 1;
+        match *optimizer {
+            Optimizer::Sgd {
+                learning_rate,
+                momentum,
+            } => {
+                // v ← μ v - η g ; θ ← θ + v
+                Zip::from(&mut self.first)
+                    .and(grad)
+                    .for_each(|v, &g| *v = momentum * *v - learning_rate * g);
+                *param += &self.first;
+            }
+            Optimizer::Adam {
+                learning_rate,
+                beta1,
+                beta2,
+                epsilon,
+            } => {
+                // Correction du biais des moyennes mobiles, initialisées à 0
+                let correction1 = 1.0 - beta1.powi(self.step);
+                let correction2 = 1.0 - beta2.powi(self.step);
+                Zip::from(param)
+                    .and(&mut self.first)
+                    .and(&mut self.second)
+                    .and(grad)
+                    .for_each(|p, m, v, &g| {
+                        *m = beta1 * *m + (1.0 - beta1) * g;
+                        *v = beta2 * *v + (1.0 - beta2) * g * g;
+                        let m_hat = *m / correction1;
+                        let v_hat = *v / correction2;
+                        *p -= learning_rate * m_hat / (v_hat.sqrt() + epsilon);
+                    });
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+use super::network::{Network, TrainParams, TrainingHistory};
+use super::{Activation, Loss, Optimizer};
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::metrics::r2_score;
+
+/// Perceptron multicouche pour la régression : sortie linéaire et erreur quadratique.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MlpRegressor {
+    params: TrainParams,
+    network: Option<Network>,
+    history: TrainingHistory,
+}
+
+impl MlpRegressor {
+    /// Réseau avec les couches cachées données, par exemple `&[32, 16]`.
+    pub fn new(hidden_layer_sizes: &[usize]) -> Self {
+        Self {
+            params: TrainParams {
+                hidden_layer_sizes: hidden_layer_sizes.to_vec(),
+                ..TrainParams::default()
+            },
+            network: None,
+            history: TrainingHistory::default(),
+        }
+    }
+
+    /// Activation des couches cachées (ReLU par défaut).
+    pub fn with_activation(mut self, activation: Activation) -> Self {
+        self.params.activation = activation;
+        self
+    }
+
+    /// Optimiseur (Adam avec η = 1e-3 par défaut).
+    pub fn with_optimizer(mut self, optimizer: Optimizer) -> Self {
+        self.params.optimizer = optimizer;
+        self
+    }
+
+    /// Taille des mini-batchs (32 par défaut).
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.params.batch_size = batch_size;
+        self
+    }
+
+    /// Nombre de passes sur le jeu d'entraînement (200 par défaut).
+    pub fn with_max_epochs(mut self, max_epochs: usize) -> Self {
+        self.params.max_epochs = max_epochs;
+        self
+    }
+
+    /// Met de côté une fraction des données pour suivre la perte de validation.
+    ///
+    /// Le jeu de validation contient au moins une observation, et l'entraînement aussi :
+    /// `fit` renvoie une erreur avec moins de 2 observations.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::array;
+    /// use rust_first_steps::neural_network::MlpRegressor;
+    ///
+    /// let mut mlp = MlpRegressor::new(&[4]).with_validation_fraction(0.2);
+    /// assert!(mlp.fit(&array![[1.0]], &array![1.0]).is_err());
+    /// ```
+    pub fn with_validation_fraction(mut self, validation_fraction: f64) -> Self {
+        self.params.validation_fraction = Some(validation_fraction);
+        self
+    }
+
+    /// Calcule les gradients avec le ruban d'[`autograd`](crate::autograd) plutôt que
+    /// par la rétropropagation écrite à la main : plus lent, mêmes poids aux arrondis près.
+    pub fn with_autograd(mut self, autograd: bool) -> Self {
+        self.params.autograd = autograd;
+        self
+    }
+
+    /// Graine de l'initialisation des poids et du mélange des mini-batchs.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.random_state = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        let target = y.clone().insert_axis(Axis(1));
+        let (network, history) = self.params.train(
+            x,
+            &target,
+            Activation::Identity,
+            Loss::MeanSquaredError,
+            |output, target| r2_score(&target.column(0).to_owned(), &output.column(0).to_owned()),
+        )?;
+        self.network = Some(network);
+        self.history = history;
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        let network = self.network()?;
+        check_n_features(network.layers()[0].weights().nrows(), x.ncols())?;
+        Ok(network.forward(x).column(0).to_owned())
+    }
+
+    pub fn network(&self) -> Result<&Network, ModelError> {
+        self.network.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Demi-erreur quadratique moyenne et R² à chaque époque.
+    pub fn history(&self) -> &TrainingHistory {
+        &self.history
+    }
+}
@@ -0,0 +1,653 @@
+//! Sauvegarde et chargement des modèles entraînés.
+//!
+//! Un modèle est enregistré dans un [`SavedModel`] avec ses métadonnées : version
+//! du format, version de la crate, nom du modèle ([`Persist`]) et noms des features (dans
+//! l'ordre des colonnes de X attendu par `predict`). Deux formats sont proposés,
+//! choisis d'après l'extension du fichier :
+//! - JSON (`.json`), lisible et modifiable à la main ;
+//! - binaire (toute autre extension), compact et exact : un en-tête de 8 octets
+//!   (`RFSM` puis la version du format en u32 little-endian) suivi des
+//!   métadonnées et du modèle encodés avec bincode.
+//!
+//! Tout type implémentant `Serialize`/`Deserialize` et [`Persist`] peut être
+//! sauvegardé : les modèles, les transformations ([`crate::preprocessing`],
+//! [`crate::impute`]), un [`Pipeline`] ou un simple vecteur θ.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use nalgebra::DVector;
+use ndarray::{Array1, Array2};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::cluster::KMeans;
+use crate::decomposition::Pca;
+use crate::discriminant_analysis::{LinearDiscriminantAnalysis, QuadraticDiscriminantAnalysis};
+use crate::ensemble::{
+    GradientBoostingClassifier, GradientBoostingRegressor, RandomForestClassifier,
+    RandomForestRegressor,
+};
+use crate::impute::{KnnImputer, SimpleImputer};
+use crate::linear_model::LinearRegression;
+use crate::manifold::Tsne;
+use crate::naive_bayes::{BernoulliNB, GaussianNB, MultinomialNB};
+use crate::neural_network::{MlpClassifier, MlpRegressor};
+use crate::pipeline::Pipeline;
+use crate::preprocessing::{
+    BiasColumn, LabelEncoder, OneHotEncoder, OrdinalEncoder, PolynomialFeatures, StandardScaler,
+};
+use crate::svm::{SvmClassifier, SvmRegressor};
+use crate::tree::{DecisionTreeClassifier, DecisionTreeRegressor};
+
+/// Version du format des fichiers de modèle, incrémentée à chaque changement
+/// incompatible.
+///
+/// Version 2 : `model_type` est le nom donné par [`Persist`] et non plus celui
+/// du compilateur.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Début de tout fichier binaire de modèle.
+const MAGIC: &[u8; 4] = b"RFSM";
+
+/// Erreurs de sauvegarde ou de chargement d'un modèle.
+#[derive(Debug)]
+pub enum PersistenceError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Binary(bincode::Error),
+    /// Le fichier binaire ne commence pas par l'en-tête attendu.
+    InvalidHeader,
+    /// Le fichier a été écrit avec une autre version du format.
+    UnsupportedVersion(u32),
+    /// Le fichier contient un autre type de modèle que celui demandé.
+    ModelTypeMismatch {
+        expected: String,
+        found: String,
+    },
+    /// Des features du modèle sont absentes des colonnes fournies.
+    MissingFeatures(Vec<String>),
+    /// Les features sont présentes mais pas dans l'ordre d'entraînement.
+    FeatureOrder {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+}
+
+impl fmt::Display for PersistenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistenceError::Io(e) => write!(f, "erreur d'entrée/sortie : {}", e),
+            PersistenceError::Json(e) => write!(f, "JSON invalide : {}", e),
+            PersistenceError::Binary(e) => write!(f, "encodage binaire invalide : {}", e),
+            PersistenceError::InvalidHeader => {
+                write!(
+                    f,
+                    "ce fichier n'est pas un modèle sauvegardé (en-tête inconnu)"
+                )
+            }
+            PersistenceError::UnsupportedVersion(found) => write!(
+                f,
+                "version de format {} non prise en charge (version attendue : {})",
+                found, FORMAT_VERSION
+            ),
+            PersistenceError::ModelTypeMismatch { expected, found } => write!(
+                f,
+                "le fichier contient un modèle {} et non {}",
+                found, expected
+            ),
+            PersistenceError::MissingFeatures(missing) => write!(
+                f,
+                "colonnes attendues par le modèle absentes : {}",
+                missing.join(", ")
+            ),
+            PersistenceError::FeatureOrder { expected, found } => write!(
+                f,
+                "les features ne sont pas dans l'ordre d'entraînement : attendu [{}], trouvé [{}]",
+                expected.join(", "),
+                found.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for PersistenceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PersistenceError::Io(e) => Some(e),
+            PersistenceError::Json(e) => Some(e),
+            PersistenceError::Binary(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PersistenceError {
+    fn from(e: io::Error) -> Self {
+        PersistenceError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PersistenceError {
+    fn from(e: serde_json::Error) -> Self {
+        PersistenceError::Json(e)
+    }
+}
+
+impl From<bincode::Error> for PersistenceError {
+    fn from(e: bincode::Error) -> Self {
+        PersistenceError::Binary(e)
+    }
+}
+
+/// Format d'un fichier de modèle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Binary,
+}
+
+impl Format {
+    /// JSON pour l'extension `.json`, binaire sinon.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Format::Json,
+            _ => Format::Binary,
+        }
+    }
+}
+
+/// Type pouvant être enregistré dans un [`SavedModel`].
+///
+/// Le nom renvoyé par `model_type` est écrit dans les métadonnées puis vérifié au
+/// chargement. Il est fixé par la crate plutôt que tiré de `std::any::type_name`,
+/// dont le format n'est pas garanti d'une version du compilateur à l'autre.
+///
+/// # Example
+/// Chaque modèle supervisé, relu en JSON et en binaire, prédit la même chose :
+/// ```
+/// use ndarray::{Array1, Axis, s};
+/// use serde::Serialize;
+/// use serde::de::DeserializeOwned;
+/// use rust_first_steps::data::{Dataset, load_iris};
+/// use rust_first_steps::discriminant_analysis::{
+///     LinearDiscriminantAnalysis, QuadraticDiscriminantAnalysis,
+/// };
+/// use rust_first_steps::ensemble::{
+///     GradientBoostingClassifier, GradientBoostingRegressor, RandomForestClassifier,
+///     RandomForestRegressor,
+/// };
+/// use rust_first_steps::linear_model::LinearRegression;
+/// use rust_first_steps::naive_bayes::{BernoulliNB, GaussianNB, MultinomialNB};
+/// use rust_first_steps::neural_network::{MlpClassifier, MlpRegressor};
+/// use rust_first_steps::persistence::{Format, Persist, SavedModel};
+/// use rust_first_steps::pipeline::Pipeline;
+/// use rust_first_steps::preprocessing::{LabelEncoder, StandardScaler};
+/// use rust_first_steps::svm::{SvmClassifier, SvmRegressor};
+/// use rust_first_steps::traits::{Fit, Predict};
+/// use rust_first_steps::tree::{DecisionTreeClassifier, DecisionTreeRegressor};
+///
+/// // Entraîne le modèle, le relit dans chaque format et compare les prédictions
+/// fn round_trip<T, E>(mut model: E, data: &Dataset<T>, same: Same<T>)
+/// where
+///     E: Fit<T> + Predict<Output = T> + Persist + Serialize + DeserializeOwned + Clone,
+/// {
+///     model.fit(data).unwrap();
+///     let x = data.x();
+///     let expected = model.predict(x).unwrap();
+///     for format in [Format::Json, Format::Binary] {
+///         let mut bytes = Vec::new();
+///         SavedModel::new(model.clone(), &[]).to_writer(&mut bytes, format).unwrap();
+///         let loaded = SavedModel::<E>::from_reader(bytes.as_slice(), format).unwrap();
+///         assert_eq!(loaded.metadata.model_type, E::model_type());
+///         let found = loaded.model.predict(x).unwrap();
+///         assert!(same(&expected, &found), "{} ({:?})", E::model_type(), format);
+///     }
+/// }
+/// type Same<T> = fn(&Array1<T>, &Array1<T>) -> bool;
+/// let classes: Same<usize> = |a, b| a == b;
+/// // Le JSON peut arrondir un f64 au dernier bit
+/// let values: Same<f64> =
+///     |a, b| a.iter().zip(b).all(|(a, b)| (a - b).abs() <= 1e-9 * a.abs().max(1.0));
+///
+/// let (x, species) = load_iris("data/iris.csv").unwrap();
+/// let rows: Vec<usize> = (0..x.nrows()).step_by(3).collect();
+/// let x = x.select(Axis(0), &rows);
+/// let y = LabelEncoder::new().fit_transform(species.select(Axis(0), &rows).iter()).unwrap();
+/// let iris = Dataset::new(x.clone(), y).unwrap();
+/// round_trip(DecisionTreeClassifier::new(), &iris, classes);
+/// round_trip(RandomForestClassifier::new().with_random_state(0), &iris, classes);
+/// round_trip(GradientBoostingClassifier::new(), &iris, classes);
+/// round_trip(SvmClassifier::new(), &iris, classes);
+/// round_trip(MlpClassifier::new(&[8]).with_random_state(0), &iris, classes);
+/// round_trip(LinearDiscriminantAnalysis::new(), &iris, classes);
+/// round_trip(QuadraticDiscriminantAnalysis::new(), &iris, classes);
+/// round_trip(GaussianNB::new(), &iris, classes);
+/// round_trip(MultinomialNB::new(), &iris, classes);
+/// round_trip(BernoulliNB::new(), &iris, classes);
+/// let pipeline = Pipeline::new(SvmClassifier::new()).with_step(StandardScaler::new());
+/// round_trip(pipeline, &iris, classes);
+///
+/// // Régression : largeur du pétale à partir des trois autres mesures
+/// let petals = Dataset::new(x.slice(s![.., ..3]).to_owned(), x.column(3).to_owned()).unwrap();
+/// round_trip(LinearRegression::new(), &petals, values);
+/// round_trip(DecisionTreeRegressor::new(), &petals, values);
+/// round_trip(RandomForestRegressor::new().with_random_state(0), &petals, values);
+/// round_trip(GradientBoostingRegressor::new(), &petals, values);
+/// round_trip(SvmRegressor::new(), &petals, values);
+/// round_trip(MlpRegressor::new(&[8]).with_random_state(0), &petals, values);
+/// ```
+pub trait Persist {
+    fn model_type() -> String;
+}
+
+/// Informations enregistrées avec le modèle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelMetadata {
+    pub format_version: u32,
+    /// Version de la crate qui a écrit le fichier.
+    pub crate_version: String,
+    /// Nom du modèle, donné par [`Persist::model_type`].
+    pub model_type: String,
+    /// Noms des colonnes de X, dans l'ordre attendu par le modèle.
+    pub feature_names: Vec<String>,
+    /// Nom de la colonne cible, si le modèle en a une.
+    pub target: Option<String>,
+}
+
+impl ModelMetadata {
+    /// Vérifie que `columns` (l'en-tête d'un CSV à prédire) contient toutes les features
+    /// du modèle, dans le même ordre. Les autres colonnes sont ignorées.
+    pub fn check_columns(&self, columns: &[String]) -> Result<(), PersistenceError> {
+        let missing: Vec<String> = self
+            .feature_names
+            .iter()
+            .filter(|name| !columns.contains(name))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            return Err(PersistenceError::MissingFeatures(missing));
+        }
+
+        let found: Vec<String> = columns
+            .iter()
+            .filter(|name| self.feature_names.contains(name))
+            .cloned()
+            .collect();
+        if found != self.feature_names {
+            return Err(PersistenceError::FeatureOrder {
+                expected: self.feature_names.clone(),
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Un modèle et ses métadonnées, tels qu'ils sont écrits sur le disque.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::naive_bayes::{GaussianNB, NaiveBayes};
+/// use rust_first_steps::persistence::SavedModel;
+///
+/// let x = array![[1.0, 2.0], [1.2, 1.8], [5.0, 6.0], [5.2, 6.1]];
+/// let y = array![0, 0, 1, 1];
+/// let mut model = GaussianNB::new();
+/// model.fit(&x, &y).unwrap();
+///
+/// let path = std::env::temp_dir().join("rust_first_steps_doctest_gnb.json");
+/// SavedModel::new(model, &["a", "b"]).with_target("y").save(&path).unwrap();
+///
+/// let saved = SavedModel::<GaussianNB>::load(&path).unwrap();
+/// assert_eq!(saved.metadata.feature_names, ["a", "b"]);
+/// assert_eq!(saved.model.predict(&x).unwrap(), y);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedModel<M> {
+    pub metadata: ModelMetadata,
+    pub model: M,
+}
+
+impl<M: Persist> SavedModel<M> {
+    pub fn new(model: M, feature_names: &[&str]) -> Self {
+        Self {
+            metadata: ModelMetadata {
+                format_version: FORMAT_VERSION,
+                crate_version: env!("CARGO_PKG_VERSION").to_string(),
+                model_type: M::model_type(),
+                feature_names: feature_names.iter().map(|name| name.to_string()).collect(),
+                target: None,
+            },
+            model,
+        }
+    }
+
+    /// Nom de la colonne cible, recopié dans les métadonnées.
+    pub fn with_target(mut self, target: &str) -> Self {
+        self.metadata.target = Some(target.to_string());
+        self
+    }
+
+    /// Écrit le modèle dans `path`, au format donné par son extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), PersistenceError>
+    where
+        M: Serialize,
+    {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.to_writer(&mut writer, Format::from_path(path))?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Relit un modèle écrit par [`SavedModel::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PersistenceError>
+    where
+        M: DeserializeOwned,
+    {
+        let path = path.as_ref();
+        Self::from_reader(BufReader::new(File::open(path)?), Format::from_path(path))
+    }
+
+    /// Écrit le modèle dans `writer` ; [`SavedModel::from_reader`] le relit.
+    ///
+    /// # Example
+    /// Les transformations et les modèles non supervisés relus en JSON et en binaire
+    /// donnent le même résultat :
+    /// ```
+    /// use ndarray::{Array2, Axis};
+    /// use serde::Serialize;
+    /// use serde::de::DeserializeOwned;
+    /// use rust_first_steps::cluster::KMeans;
+    /// use rust_first_steps::data::{Dataset, load_iris};
+    /// use rust_first_steps::decomposition::Pca;
+    /// use rust_first_steps::impute::{KnnImputer, SimpleImputer};
+    /// use rust_first_steps::persistence::{Format, Persist, SavedModel};
+    /// use rust_first_steps::preprocessing::{
+    ///     BiasColumn, LabelEncoder, OneHotEncoder, OrdinalEncoder, PolynomialFeatures,
+    ///     StandardScaler,
+    /// };
+    /// use rust_first_steps::traits::{Fit, Transform};
+    ///
+    /// fn reload<M: Persist + Serialize + DeserializeOwned>(model: M, format: Format) -> M {
+    ///     let mut bytes = Vec::new();
+    ///     SavedModel::new(model, &[]).to_writer(&mut bytes, format).unwrap();
+    ///     SavedModel::<M>::from_reader(bytes.as_slice(), format).unwrap().model
+    /// }
+    /// // Le JSON peut arrondir un f64 au dernier bit
+    /// fn close(a: &Array2<f64>, b: &Array2<f64>) -> bool {
+    ///     a.iter().zip(b).all(|(a, b)| (a - b).abs() <= 1e-9 * a.abs().max(1.0))
+    /// }
+    /// fn round_trip<M>(mut transformer: M, x: &Array2<f64>)
+    /// where
+    ///     M: Fit<()> + Transform + Persist + Serialize + DeserializeOwned + Clone,
+    /// {
+    ///     transformer.fit(&Dataset::unlabeled(x.clone())).unwrap();
+    ///     let expected = transformer.transform(x).unwrap();
+    ///     for format in [Format::Json, Format::Binary] {
+    ///         let found = reload(transformer.clone(), format).transform(x).unwrap();
+    ///         assert!(close(&expected, &found), "{} ({:?})", M::model_type(), format);
+    ///     }
+    /// }
+    ///
+    /// let (x, species) = load_iris("data/iris.csv").unwrap();
+    /// let rows: Vec<usize> = (0..x.nrows()).step_by(3).collect();
+    /// let (x, species) = (x.select(Axis(0), &rows), species.select(Axis(0), &rows));
+    /// // Une valeur manquante : le KnnImputer garde ses données d'entraînement, NaN compris
+    /// let mut x_missing = x.clone();
+    /// x_missing[[0, 1]] = f64::NAN;
+    /// round_trip(StandardScaler::new(), &x);
+    /// round_trip(PolynomialFeatures::new(2), &x);
+    /// round_trip(BiasColumn, &x);
+    /// round_trip(SimpleImputer::new(), &x_missing);
+    /// round_trip(KnnImputer::new(3), &x_missing);
+    ///
+    /// let mut pca = Pca::new();
+    /// let expected = pca.fit_transform(&x).unwrap();
+    /// let mut kmeans = KMeans::new(3).with_random_state(0);
+    /// let clusters = kmeans.fit_predict(&x).unwrap();
+    /// let mut labels = LabelEncoder::new();
+    /// let y = labels.fit_transform(species.iter()).unwrap();
+    /// let categories = species.clone().insert_axis(Axis(1));
+    /// let mut one_hot = OneHotEncoder::new();
+    /// let encoded = one_hot.fit_transform(&categories).unwrap();
+    /// let mut ordinal = OrdinalEncoder::new();
+    /// let ranks = ordinal.fit_transform(&categories).unwrap();
+    /// for format in [Format::Json, Format::Binary] {
+    ///     assert!(close(&reload(pca.clone(), format).transform(&x).unwrap(), &expected));
+    ///     assert_eq!(reload(kmeans.clone(), format).predict(&x).unwrap(), clusters);
+    ///     assert_eq!(reload(labels.clone(), format).transform(species.iter()).unwrap(), y);
+    ///     assert_eq!(reload(one_hot.clone(), format).transform(&categories).unwrap(), encoded);
+    ///     assert_eq!(reload(ordinal.clone(), format).transform(&categories).unwrap(), ranks);
+    /// }
+    /// ```
+    pub fn to_writer(&self, mut writer: impl Write, format: Format) -> Result<(), PersistenceError>
+    where
+        M: Serialize,
+    {
+        match format {
+            Format::Json => serde_json::to_writer_pretty(writer, self)?,
+            Format::Binary => {
+                writer.write_all(MAGIC)?;
+                writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+                bincode::serialize_into(&mut writer, &self.metadata)?;
+                bincode::serialize_into(writer, &self.model)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Relit un modèle ; l'en-tête et les métadonnées sont vérifiés avant de décoder
+    /// le modèle lui-même, dont la forme dépend de la version et du type.
+    pub fn from_reader(mut reader: impl Read, format: Format) -> Result<Self, PersistenceError>
+    where
+        M: DeserializeOwned,
+    {
+        match format {
+            Format::Json => {
+                let mut value: serde_json::Value = serde_json::from_reader(reader)?;
+                let version = value["metadata"]["format_version"]
+                    .as_u64()
+                    .ok_or(PersistenceError::InvalidHeader)?;
+                check_version(version as u32)?;
+                let metadata: ModelMetadata = serde_json::from_value(value["metadata"].take())?;
+                check_model_type::<M>(&metadata)?;
+                let model = serde_json::from_value(value["model"].take())?;
+                Ok(Self { metadata, model })
+            }
+            Format::Binary => {
+                let mut header = [0u8; 8];
+                reader.read_exact(&mut header)?;
+                if &header[..4] != MAGIC {
+                    return Err(PersistenceError::InvalidHeader);
+                }
+                check_version(u32::from_le_bytes(
+                    header[4..].try_into().expect("4 octets"),
+                ))?;
+                let metadata: ModelMetadata = bincode::deserialize_from(&mut reader)?;
+                check_model_type::<M>(&metadata)?;
+                let model = bincode::deserialize_from(reader)?;
+                Ok(Self { metadata, model })
+            }
+        }
+    }
+}
+
+fn check_model_type<M: Persist>(metadata: &ModelMetadata) -> Result<(), PersistenceError> {
+    let expected = M::model_type();
+    if metadata.model_type != expected {
+        return Err(PersistenceError::ModelTypeMismatch {
+            expected,
+            found: metadata.model_type.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn check_version(version: u32) -> Result<(), PersistenceError> {
+    if version != FORMAT_VERSION {
+        return Err(PersistenceError::UnsupportedVersion(version));
+    }
+    Ok(())
+}
+
+// Le nom d'un modèle de la crate est celui de son type
+macro_rules! impl_persist {
+    ($($model:ident),*) => {$(
+        impl Persist for $model {
+            fn model_type() -> String {
+                stringify!($model).to_string()
+            }
+        }
+    )*};
+}
+
+impl_persist!(
+    LinearRegression,
+    DecisionTreeClassifier,
+    DecisionTreeRegressor,
+    RandomForestClassifier,
+    RandomForestRegressor,
+    GradientBoostingClassifier,
+    GradientBoostingRegressor,
+    SvmClassifier,
+    SvmRegressor,
+    MlpClassifier,
+    MlpRegressor,
+    LinearDiscriminantAnalysis,
+    QuadraticDiscriminantAnalysis,
+    GaussianNB,
+    MultinomialNB,
+    BernoulliNB,
+    KMeans,
+    Pca,
+    Tsne,
+    StandardScaler,
+    PolynomialFeatures,
+    BiasColumn,
+    LabelEncoder,
+    OneHotEncoder,
+    OrdinalEncoder,
+    SimpleImputer,
+    KnnImputer
+);
+
+impl<E: Persist> Persist for Pipeline<E> {
+    fn model_type() -> String {
+        format!("Pipeline<{}>", E::model_type())
+    }
+}
+
+impl<A: Persist, B: Persist> Persist for (A, B) {
+    fn model_type() -> String {
+        format!("({}, {})", A::model_type(), B::model_type())
+    }
+}
+
+impl Persist for DVector<f64> {
+    fn model_type() -> String {
+        "DVector<f64>".to_string()
+    }
+}
+
+impl Persist for Array1<f64> {
+    fn model_type() -> String {
+        "Array1<f64>".to_string()
+    }
+}
+
+impl Persist for Array2<f64> {
+    fn model_type() -> String {
+        "Array2<f64>".to_string()
+    }
+}
+
+/// (Dé)sérialise une matrice optionnelle pouvant contenir des `NaN` (valeurs
+/// manquantes) : JSON ne les représente pas, ils sont écrits `null`.
+pub(crate) mod nan_as_null {
+    use ndarray::Array2;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    type Raw = Option<((usize, usize), Vec<Option<f64>>)>;
+
+    pub fn serialize<S: Serializer>(
+        x: &Option<Array2<f64>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let raw: Raw = x.as_ref().map(|x| {
+            (
+                x.dim(),
+                x.iter().map(|v| (!v.is_nan()).then_some(*v)).collect(),
+            )
+        });
+        raw.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Array2<f64>>, D::Error> {
+        Raw::deserialize(deserializer)?
+            .map(|(dim, values)| {
+                let values = values.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect();
+                Array2::from_shape_vec(dim, values).map_err(D::Error::custom)
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{Array1, Array2, array};
+
+    use super::*;
+
+    fn saved() -> SavedModel<Array1<f64>> {
+        SavedModel::new(array![1.0, 2.0], &["a", "b"])
+    }
+
+    #[test]
+    fn json_with_another_format_version_is_rejected() {
+        let mut bytes = Vec::new();
+        saved().to_writer(&mut bytes, Format::Json).unwrap();
+        let mut value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        value["metadata"]["format_version"] = (FORMAT_VERSION - 1).into();
+        let bytes = serde_json::to_vec(&value).unwrap();
+        let result = SavedModel::<Array1<f64>>::from_reader(&bytes[..], Format::Json);
+        assert!(matches!(
+            result,
+            Err(PersistenceError::UnsupportedVersion(v)) if v == FORMAT_VERSION - 1
+        ));
+    }
+
+    #[test]
+    fn binary_with_another_format_version_is_rejected() {
+        let mut bytes = Vec::new();
+        saved().to_writer(&mut bytes, Format::Binary).unwrap();
+        bytes[4..8].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        let result = SavedModel::<Array1<f64>>::from_reader(&bytes[..], Format::Binary);
+        assert!(matches!(
+            result,
+            Err(PersistenceError::UnsupportedVersion(v)) if v == FORMAT_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn another_model_type_is_rejected() {
+        let mut bytes = Vec::new();
+        saved().to_writer(&mut bytes, Format::Binary).unwrap();
+        let result = SavedModel::<Array2<f64>>::from_reader(&bytes[..], Format::Binary);
+        assert!(matches!(
+            result,
+            Err(PersistenceError::ModelTypeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+//! Enchaînement du prétraitement et d'un modèle en un seul objet.
+//!
+//! Imputation, mise à l'échelle, features polynomiales et colonne de biais doivent
+//! être appris sur le jeu d'entraînement puis réappliqués à l'identique au moment de
+//! prédire. Un [`Pipeline`] regroupe ces étapes ([`Step`]) et le modèle final, qui
+//! implémente [`Fit`] et [`Predict`] : `fit` entraîne chaque étape sur la sortie de la
+//! précédente, `predict` les applique dans le même ordre. Le pipeline se sauvegarde en
+//! un seul fichier avec [`crate::persistence::SavedModel`], et
+//! [`crate::model_selection::cross_val_score`] le réentraîne entièrement sur chaque
+//! pli : les statistiques du scaler ne voient jamais les observations de test.
+
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use crate::data::Dataset;
+use crate::error::ModelError;
+use crate::impute::{KnnImputer, SimpleImputer};
+use crate::metrics::{accuracy, r2_score};
+use crate::preprocessing::{BiasColumn, PolynomialFeatures, StandardScaler};
+use crate::traits::{Fit, Predict, PredictProba, Score};
+
+/// Étape de prétraitement d'un [`Pipeline`].
+///
+/// Une énumération plutôt qu'un `Box<dyn Transform>` : le pipeline reste
+/// sérialisable et le fichier sauvegardé indique le type de chaque étape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Step {
+    SimpleImputer(SimpleImputer),
+    KnnImputer(KnnImputer),
+    StandardScaler(StandardScaler),
+    PolynomialFeatures(PolynomialFeatures),
+    BiasColumn(BiasColumn),
+}
+
+impl Step {
+    /// Apprend les paramètres de l'étape sur X (rien à apprendre pour la colonne de biais).
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        match self {
+            Step::SimpleImputer(step) => step.fit(x),
+            Step::KnnImputer(step) => step.fit(x),
+            Step::StandardScaler(step) => step.fit(x),
+            Step::PolynomialFeatures(step) => step.fit(x),
+            Step::BiasColumn(_) => Ok(()),
+        }
+    }
+
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        match self {
+            Step::SimpleImputer(step) => step.transform(x),
+            Step::KnnImputer(step) => step.transform(x),
+            Step::StandardScaler(step) => step.transform(x),
+            Step::PolynomialFeatures(step) => step.transform(x),
+            Step::BiasColumn(step) => Ok(step.transform(x)),
+        }
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+}
+
+macro_rules! impl_from_step {
+    ($($variant:ident),*) => {$(
+        impl From<$variant> for Step {
+            fn from(step: $variant) -> Self {
+                Step::$variant(step)
+            }
+        }
+    )*};
+}
+
+impl_from_step!(
+    SimpleImputer,
+    KnnImputer,
+    StandardScaler,
+    PolynomialFeatures,
+    BiasColumn
+);
+
+/// Étapes de prétraitement suivies d'un modèle, entraînés et appliqués ensemble.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::data::Dataset;
+/// use rust_first_steps::linear_model::LinearRegression;
+/// use rust_first_steps::pipeline::Pipeline;
+/// use rust_first_steps::preprocessing::{BiasColumn, PolynomialFeatures, StandardScaler};
+/// use rust_first_steps::traits::{Fit, Predict};
+///
+/// // y = x², retrouvé par une régression polynomiale de degré 2
+/// let x = array![[0.0], [1.0], [2.0], [3.0], [4.0]];
+/// let y = x.column(0).mapv(|v| v * v);
+/// let mut pipeline = Pipeline::new(LinearRegression::new().with_fit_intercept(false))
+///     .with_step(StandardScaler::new())
+///     .with_step(PolynomialFeatures::new(2))
+///     .with_step(BiasColumn);
+/// pipeline.fit(&Dataset::new(x, y).unwrap()).unwrap();
+/// assert!((pipeline.predict(&array![[5.0]]).unwrap()[0] - 25.0).abs() < 1e-8);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pipeline<E> {
+    steps: Vec<Step>,
+    estimator: E,
+}
+
+impl<E> Pipeline<E> {
+    /// Pipeline sans prétraitement autour du modèle `estimator`.
+    pub fn new(estimator: E) -> Self {
+        Self {
+            steps: Vec::new(),
+            estimator,
+        }
+    }
+
+    /// Ajoute une étape, appliquée après les précédentes et avant le modèle.
+    pub fn with_step(mut self, step: impl Into<Step>) -> Self {
+        self.steps.push(step.into());
+        self
+    }
+
+    /// Applique les étapes de prétraitement entraînées, sans le modèle.
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mut x = x.clone();
+        for step in &self.steps {
+            x = step.transform(&x)?;
+        }
+        Ok(x)
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn estimator(&self) -> &E {
+        &self.estimator
+    }
+}
+
+impl<T: Clone, E: Fit<T>> Fit<T> for Pipeline<E> {
+    /// Entraîne chaque étape sur la sortie de la précédente, puis le modèle.
+    fn fit(&mut self, data: &Dataset<T>) -> Result<(), ModelError> {
+        let mut x = data.x().clone();
+        for step in &mut self.steps {
+            x = step.fit_transform(&x)?;
+        }
+        self.estimator.fit(&Dataset::new(x, data.y().clone())?)
+    }
+}
+
+impl<E: Predict> Predict for Pipeline<E> {
+    type Output = E::Output;
+
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<E::Output>, ModelError> {
+        self.estimator.predict(&self.transform(x)?)
+    }
+}
+
+/// Probabilités du modèle final, calculées sur X prétraité.
+impl<E: PredictProba> PredictProba for Pipeline<E> {
+    fn classes(&self) -> &[usize] {
+        self.estimator.classes()
+    }
+
+    fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.estimator.predict_proba(&self.transform(x)?)
+    }
+}
+
+/// Accuracy des classes prédites.
+impl<E: Predict<Output = usize>> Score<usize> for Pipeline<E> {
+    fn score(&self, data: &Dataset<usize>) -> Result<f64, ModelError> {
+        accuracy(data.y(), &self.predict(data.x())?)
+    }
+}
+
+/// R² des valeurs prédites.
+impl<E: Predict<Output = f64>> Score<f64> for Pipeline<E> {
+    fn score(&self, data: &Dataset<f64>) -> Result<f64, ModelError> {
+        r2_score(data.y(), &self.predict(data.x())?)
+    }
+}
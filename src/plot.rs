@@ -0,0 +1,240 @@
+//! Graphiques Plotly partagés par les expériences.
+//!
+//! Les fonctions écrivent des fichiers HTML autonomes ; le dossier de destination
+//! est créé au besoin.
+
+use std::io;
+use std::path::Path;
+
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2};
+use plotly::common::{ColorScale, ColorScalePalette, Marker, Mode};
+use plotly::layout::{Annotation, Axis, GridPattern, Layout, LayoutGrid};
+use plotly::{HeatMap, Plot, Scatter};
+
+/// Couleurs par défaut de Plotly, pour garder la même couleur à une classe dans
+/// tous les sous-graphiques.
+const CLASS_COLORS: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+/// Écrit le graphique dans `path` en créant le dossier parent s'il n'existe pas.
+pub fn save_html(plot: &Plot, path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    plot.write_html(path);
+    Ok(())
+}
+
+/// Ajoute un nuage de points des deux premières colonnes de `points`, avec une trace
+/// (donc une couleur et une entrée de légende) par classe.
+///
+/// # Arguments
+/// * `points` - Coordonnées des observations (au moins 2 colonnes).
+/// * `labels` - Classe de chaque observation, un indice dans `class_names`.
+/// * `class_names` - Nom affiché dans la légende pour chaque classe.
+pub fn add_class_scatter(
+    plot: &mut Plot,
+    points: &Array2<f64>,
+    labels: &Array1<usize>,
+    class_names: &[&str],
+) {
+    for (class, name) in class_names.iter().enumerate() {
+        let rows: Vec<usize> = (0..labels.len()).filter(|&i| labels[i] == class).collect();
+        plot.add_trace(
+            Scatter::new(
+                rows.iter().map(|&i| points[[i, 0]]).collect(),
+                rows.iter().map(|&i| points[[i, 1]]).collect(),
+            )
+            .mode(Mode::Markers)
+            .name(*name)
+            .marker(Marker::new().size(7).opacity(0.8)),
+        );
+    }
+}
+
+/// Affiche chaque feature par rapport à la cible, un fichier `target_vs_<feature>.html`
+/// par colonne de `x` dans `out_dir`.
+pub fn plot_features_and_target(
+    x: &DMatrix<f64>,
+    y: &DVector<f64>,
+    feature_names: &[&str],
+    target_name: &str,
+    out_dir: impl AsRef<Path>,
+) -> io::Result<()> {
+    for (column, feature) in x.column_iter().zip(feature_names) {
+        let trace = Scatter::new(column.iter().copied().collect(), y.as_slice().to_vec())
+            .mode(Mode::Markers)
+            .name(format!("{} vs {}", target_name, feature));
+
+        let mut plot = Plot::new();
+        plot.add_trace(trace);
+        plot.set_layout(
+            Layout::new()
+                .title(format!("{} vs {}", target_name, feature))
+                .x_axis(Axis::new().title(feature.to_string()))
+                .y_axis(Axis::new().title(target_name.to_string())),
+        );
+        save_html(
+            &plot,
+            out_dir.as_ref().join(format!("target_vs_{}.html", feature)),
+        )?;
+    }
+    Ok(())
+}
+
+/// Visualise une régression univariée y = θ₀ + θ₁ x : les données et la droite,
+/// dans `out_dir/regression_<name>.html`.
+pub fn plot_regression_result(
+    x: &DVector<f64>,
+    y_true: &DVector<f64>,
+    theta: &DVector<f64>,
+    name: &str,
+    out_dir: impl AsRef<Path>,
+) -> io::Result<()> {
+    let y_pred: Vec<f64> = x.iter().map(|xi| theta[0] + theta[1] * xi).collect();
+
+    let trace_points = Scatter::new(x.as_slice().to_vec(), y_true.as_slice().to_vec())
+        .mode(Mode::Markers)
+        .name("Données");
+    let trace_line = Scatter::new(x.as_slice().to_vec(), y_pred)
+        .mode(Mode::Lines)
+        .name("Régression");
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace_points);
+    plot.add_trace(trace_line);
+    plot.set_layout(
+        Layout::new()
+            .title(format!("Régression sur {}", name))
+            .x_axis(Axis::new().title(name.to_string()))
+            .y_axis(Axis::new().title("Cible")),
+    );
+    save_html(
+        &plot,
+        out_dir.as_ref().join(format!("regression_{}.html", name)),
+    )
+}
+
+/// Heatmap d'une matrice de corrélation, chaque case annotée de son coefficient.
+/// La première variable est en haut à gauche, comme dans la matrice affichée.
+pub fn plot_correlation_heatmap(
+    corr: &Array2<f64>,
+    names: &[&str],
+    title: &str,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    // Plotly trace la première ligne de z en bas : on inverse l'ordre des lignes
+    let rows: Vec<usize> = (0..corr.nrows()).rev().collect();
+    let y_names: Vec<String> = rows.iter().map(|&i| names[i].to_string()).collect();
+    let z: Vec<Vec<f64>> = rows.iter().map(|&i| corr.row(i).to_vec()).collect();
+    let x_names: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+
+    let annotations = rows
+        .iter()
+        .flat_map(|&i| (0..corr.ncols()).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            Annotation::new()
+                .x(names[j].to_string())
+                .y(names[i].to_string())
+                .text(format!("{:.2}", corr[[i, j]]))
+                .show_arrow(false)
+        })
+        .collect();
+
+    let mut plot = Plot::new();
+    plot.add_trace(
+        HeatMap::new(x_names, y_names, z)
+            .name(title)
+            .color_scale(ColorScale::Palette(ColorScalePalette::RdBu)),
+    );
+    plot.set_layout(
+        Layout::new()
+            .title(title)
+            .annotations(annotations)
+            .width(150 + 90 * names.len())
+            .height(100 + 90 * names.len()),
+    );
+    save_html(&plot, path)
+}
+
+/// Matrice de nuages de points (pair plot) : chaque feature contre chaque autre,
+/// colorées par classe. La diagonale porte le nom de la feature de sa ligne et de
+/// sa colonne ; les axes sont partagés par ligne et par colonne.
+pub fn plot_pair_plot(
+    x: &Array2<f64>,
+    labels: &Array1<usize>,
+    feature_names: &[&str],
+    class_names: &[&str],
+    title: &str,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let p = feature_names.len();
+    // Identifiants Plotly des axes : "x", "x2", "x3"...
+    let axis_id = |prefix: &str, k: usize| match k {
+        0 => prefix.to_string(),
+        _ => format!("{}{}", prefix, k + 1),
+    };
+
+    let mut plot = Plot::new();
+    let mut annotations = Vec::new();
+    for i in 0..p {
+        for j in 0..p {
+            if i == j {
+                let column = x.column(j);
+                let min = column.fold(f64::INFINITY, |a, &b| a.min(b));
+                let max = column.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+                let middle = (min + max) / 2.0;
+                annotations.push(
+                    Annotation::new()
+                        .x(middle)
+                        .y(middle)
+                        .x_ref(axis_id("x", j))
+                        .y_ref(axis_id("y", i))
+                        .text(feature_names[j])
+                        .show_arrow(false),
+                );
+                continue;
+            }
+            for (class, name) in class_names.iter().enumerate() {
+                let rows: Vec<usize> = (0..labels.len()).filter(|&r| labels[r] == class).collect();
+                plot.add_trace(
+                    Scatter::new(
+                        rows.iter().map(|&r| x[[r, j]]).collect(),
+                        rows.iter().map(|&r| x[[r, i]]).collect(),
+                    )
+                    .mode(Mode::Markers)
+                    .name(*name)
+                    .legend_group(name)
+                    // Une seule entrée de légende par classe
+                    .show_legend(i == 0 && j == 1)
+                    .x_axis(axis_id("x", j))
+                    .y_axis(axis_id("y", i))
+                    .marker(
+                        Marker::new()
+                            .size(4)
+                            .opacity(0.6)
+                            .color(CLASS_COLORS[class % CLASS_COLORS.len()]),
+                    ),
+                );
+            }
+        }
+    }
+    plot.set_layout(
+        Layout::new()
+            .title(title)
+            .grid(
+                LayoutGrid::new()
+                    .rows(p)
+                    .columns(p)
+                    .pattern(GridPattern::Coupled),
+            )
+            .annotations(annotations)
+            .width(200 * p + 150)
+            .height(200 * p + 100),
+    );
+    save_html(&plot, path)
+}
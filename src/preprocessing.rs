@@ -0,0 +1,512 @@
+//! Mise à l'échelle des features et encodage des variables catégorielles.
+//!
+//! [`StandardScaler`] centre et réduit les features numériques avec la moyenne et
+//! l'écart-type appris sur le jeu d'entraînement. [`PolynomialFeatures`] et
+//! [`BiasColumn`] construisent la matrice de design d'une régression polynomiale.
+//!
+//! Les catégories sont apprises par `fit` (triées par ordre alphabétique, sauf ordre
+//! imposé) puis appliquées telles quelles par `transform`. Une catégorie absente de
+//! l'entraînement renvoie [`ModelError::UnknownCategory`] par défaut ; avec
+//! [`HandleUnknown::Ignore`], le [`OneHotEncoder`] produit une ligne de zéros et
+//! l'[`OrdinalEncoder`] un `NaN`, que l'on peut ensuite traiter avec [`crate::impute`].
+
+use ndarray::{Array1, Array2, Axis, s};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ModelError, check_n_features};
+
+/// Centre et réduit chaque feature : (x − moyenne) / écart-type.
+///
+/// Les statistiques sont apprises par `fit` sur le jeu d'entraînement puis
+/// réappliquées telles quelles, au jeu de test comme aux nouvelles données. Une
+/// feature constante (écart-type nul) est seulement centrée.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::StandardScaler;
+///
+/// let x = array![[1.0, 10.0], [3.0, 10.0]];
+/// let mut scaler = StandardScaler::new();
+/// let scaled = scaler.fit_transform(&x).unwrap();
+/// assert_eq!(scaled, array![[-1.0, 0.0], [1.0, 0.0]]);
+/// assert_eq!(scaler.inverse_transform(&scaled).unwrap(), x);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StandardScaler {
+    mean: Option<Array1<f64>>,
+    scale: Array1<f64>,
+}
+
+impl StandardScaler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        let mean = x.mean_axis(Axis(0)).ok_or(ModelError::EmptyDataset)?;
+        self.scale = x
+            .std_axis(Axis(0), 0.0)
+            .mapv(|s| if s > 0.0 { s } else { 1.0 });
+        self.mean = Some(mean);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mean = self.mean()?;
+        check_n_features(mean.len(), x.ncols())?;
+        Ok((x - mean) / &self.scale)
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Revient à l'échelle d'origine.
+    pub fn inverse_transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let mean = self.mean()?;
+        check_n_features(mean.len(), x.ncols())?;
+        Ok(x * &self.scale + mean)
+    }
+
+    /// Moyenne de chaque feature sur le jeu d'entraînement.
+    pub fn mean(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Écart-type de chaque feature (1 pour une feature constante).
+    pub fn scale(&self) -> Result<&Array1<f64>, ModelError> {
+        self.mean()?;
+        Ok(&self.scale)
+    }
+}
+
+/// Ajoute les produits des features jusqu'au degré `degree` : avec deux features
+/// a et b et le degré 2, les colonnes produites sont a, b, a², a·b, b².
+///
+/// Le terme constant n'est pas produit, il s'ajoute avec [`BiasColumn`].
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::PolynomialFeatures;
+///
+/// let x = array![[2.0, 3.0]];
+/// let mut poly = PolynomialFeatures::new(2);
+/// assert_eq!(poly.fit_transform(&x).unwrap(), array![[2.0, 3.0, 4.0, 6.0, 9.0]]);
+/// assert_eq!(poly.feature_names(&["a", "b"]).unwrap(), ["a", "b", "a^2", "a b", "b^2"]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolynomialFeatures {
+    degree: usize,
+    interaction_only: bool,
+    /// Indices des features multipliées pour chaque colonne produite.
+    powers: Option<Vec<Vec<usize>>>,
+    n_features: usize,
+}
+
+impl PolynomialFeatures {
+    pub fn new(degree: usize) -> Self {
+        Self {
+            degree,
+            interaction_only: false,
+            powers: None,
+            n_features: 0,
+        }
+    }
+
+    /// Ne garde que les produits de features distinctes (a·b mais pas a²).
+    pub fn with_interaction_only(mut self, interaction_only: bool) -> Self {
+        self.interaction_only = interaction_only;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>) -> Result<(), ModelError> {
+        if self.degree == 0 {
+            return Err(ModelError::InvalidParameter(
+                "le degré doit être au moins 1".to_string(),
+            ));
+        }
+        if x.nrows() == 0 {
+            return Err(ModelError::EmptyDataset);
+        }
+        // Combinaisons croissantes d'indices, degré par degré
+        let mut powers: Vec<Vec<usize>> = Vec::new();
+        let mut previous: Vec<Vec<usize>> = vec![Vec::new()];
+        for _ in 0..self.degree {
+            let mut current = Vec::new();
+            for combination in &previous {
+                let start = match combination.last() {
+                    Some(&last) if self.interaction_only => last + 1,
+                    Some(&last) => last,
+                    None => 0,
+                };
+                for j in start..x.ncols() {
+                    let mut next = combination.clone();
+                    next.push(j);
+                    current.push(next);
+                }
+            }
+            powers.extend(current.iter().cloned());
+            previous = current;
+        }
+        self.n_features = x.ncols();
+        self.powers = Some(powers);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let powers = self.powers.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(self.n_features, x.ncols())?;
+        Ok(Array2::from_shape_fn(
+            (x.nrows(), powers.len()),
+            |(i, k)| powers[k].iter().map(|&j| x[[i, j]]).product(),
+        ))
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Noms des colonnes produites : `a`, `a^2`, `a b`...
+    pub fn feature_names(&self, input_names: &[&str]) -> Result<Vec<String>, ModelError> {
+        let powers = self.powers.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(self.n_features, input_names.len())?;
+        Ok(powers
+            .iter()
+            .map(|combination| {
+                let mut terms: Vec<String> = Vec::new();
+                for (k, &j) in combination.iter().enumerate() {
+                    if k > 0 && combination[k - 1] == j {
+                        continue;
+                    }
+                    let exponent = combination.iter().filter(|&&i| i == j).count();
+                    terms.push(match exponent {
+                        1 => input_names[j].to_string(),
+                        e => format!("{}^{}", input_names[j], e),
+                    });
+                }
+                terms.join(" ")
+            })
+            .collect())
+    }
+}
+
+/// Ajoute une colonne de 1 en première position, pour l'ordonnée à l'origine d'un
+/// modèle linéaire (équivalent ndarray de [`crate::linear_model::add_bias_column`]).
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::BiasColumn;
+///
+/// assert_eq!(BiasColumn.transform(&array![[2.0], [3.0]]), array![[1.0, 2.0], [1.0, 3.0]]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct BiasColumn;
+
+impl BiasColumn {
+    pub fn transform(&self, x: &Array2<f64>) -> Array2<f64> {
+        let mut with_bias = Array2::ones((x.nrows(), x.ncols() + 1));
+        with_bias.slice_mut(s![.., 1..]).assign(x);
+        with_bias
+    }
+}
+
+/// Traitement d'une catégorie vue pour la première fois par `transform`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HandleUnknown {
+    /// Renvoie [`ModelError::UnknownCategory`].
+    #[default]
+    Error,
+    /// Encode la valeur comme « aucune catégorie » (zéros ou NaN selon l'encodeur).
+    Ignore,
+}
+
+/// Valeurs distinctes triées.
+fn sorted_unique<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> Vec<String> {
+    let mut unique: Vec<String> = values.into_iter().map(|v| v.as_ref().to_string()).collect();
+    unique.sort();
+    unique.dedup();
+    unique
+}
+
+/// Catégories de chaque colonne de `x`, apprises ou imposées.
+fn fit_categories(
+    x: &Array2<String>,
+    imposed: Option<&Vec<Vec<String>>>,
+) -> Result<Vec<Vec<String>>, ModelError> {
+    if x.nrows() == 0 {
+        return Err(ModelError::EmptyDataset);
+    }
+    let Some(imposed) = imposed else {
+        return Ok(x.columns().into_iter().map(sorted_unique).collect());
+    };
+    check_n_features(imposed.len(), x.ncols())?;
+    for (j, categories) in imposed.iter().enumerate() {
+        if let Some(value) = x.column(j).iter().find(|v| !categories.contains(v)) {
+            return Err(ModelError::UnknownCategory(format!(
+                "'{}' absente des catégories imposées pour la colonne {}",
+                value, j
+            )));
+        }
+    }
+    Ok(imposed.clone())
+}
+
+/// Indice de `value` dans `categories`, `None` si elle est inconnue et ignorée.
+fn category_index(
+    categories: &[String],
+    value: &str,
+    j: usize,
+    handle_unknown: HandleUnknown,
+) -> Result<Option<usize>, ModelError> {
+    match categories.iter().position(|c| c == value) {
+        Some(k) => Ok(Some(k)),
+        None if handle_unknown == HandleUnknown::Ignore => Ok(None),
+        None => Err(ModelError::UnknownCategory(format!(
+            "'{}' dans la colonne {}",
+            value, j
+        ))),
+    }
+}
+
+/// Encode les classes d'une cible en indices 0..n_classes, dans l'ordre alphabétique.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::LabelEncoder;
+///
+/// let species = ["Virginica", "Setosa", "Virginica"];
+/// let mut encoder = LabelEncoder::new();
+/// let y = encoder.fit_transform(species).unwrap();
+/// assert_eq!(y, array![1, 0, 1]);
+/// assert_eq!(encoder.inverse_transform(&array![0]).unwrap()[0], "Setosa");
+/// assert!(encoder.transform(["Rose"]).is_err());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelEncoder {
+    classes: Option<Vec<String>>,
+}
+
+impl LabelEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fit<S: AsRef<str>>(
+        &mut self,
+        labels: impl IntoIterator<Item = S>,
+    ) -> Result<(), ModelError> {
+        let classes = sorted_unique(labels);
+        if classes.is_empty() {
+            return Err(ModelError::EmptyDataset);
+        }
+        self.classes = Some(classes);
+        Ok(())
+    }
+
+    pub fn transform<S: AsRef<str>>(
+        &self,
+        labels: impl IntoIterator<Item = S>,
+    ) -> Result<Array1<usize>, ModelError> {
+        let classes = self.classes()?;
+        labels
+            .into_iter()
+            .map(|label| {
+                let label = label.as_ref();
+                classes
+                    .binary_search_by(|c| c.as_str().cmp(label))
+                    .map_err(|_| ModelError::UnknownCategory(format!("classe '{}'", label)))
+            })
+            .collect()
+    }
+
+    pub fn fit_transform<S: AsRef<str>>(
+        &mut self,
+        labels: impl IntoIterator<Item = S> + Clone,
+    ) -> Result<Array1<usize>, ModelError> {
+        self.fit(labels.clone())?;
+        self.transform(labels)
+    }
+
+    /// Retrouve le nom des classes à partir de leurs indices (prédictions d'un modèle).
+    pub fn inverse_transform(&self, y: &Array1<usize>) -> Result<Array1<String>, ModelError> {
+        let classes = self.classes()?;
+        y.iter()
+            .map(|&k| {
+                classes.get(k).cloned().ok_or_else(|| {
+                    ModelError::InvalidParameter(format!(
+                        "indice de classe {} hors de 0..{}",
+                        k,
+                        classes.len()
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Classes apprises, la classe k étant encodée par l'indice k.
+    pub fn classes(&self) -> Result<&[String], ModelError> {
+        self.classes.as_deref().ok_or(ModelError::NotFitted)
+    }
+}
+
+/// Remplace chaque colonne catégorielle par une colonne 0/1 par catégorie.
+///
+/// Les colonnes produites sont rangées colonne d'entrée par colonne d'entrée, puis
+/// dans l'ordre de [`OneHotEncoder::categories`] ; on les ajoute aux features
+/// numériques avec `concatenate![Axis(1), x, one_hot]`.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::{HandleUnknown, OneHotEncoder};
+///
+/// let colors = array![["rouge".to_string()], ["bleu".to_string()]];
+/// let mut encoder = OneHotEncoder::new().with_handle_unknown(HandleUnknown::Ignore);
+/// assert_eq!(encoder.fit_transform(&colors).unwrap(), array![[0.0, 1.0], [1.0, 0.0]]);
+///
+/// let unseen = array![["vert".to_string()]];
+/// assert_eq!(encoder.transform(&unseen).unwrap(), array![[0.0, 0.0]]);
+/// assert_eq!(encoder.feature_names(&["couleur"]).unwrap(), ["couleur=bleu", "couleur=rouge"]);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OneHotEncoder {
+    handle_unknown: HandleUnknown,
+    categories: Option<Vec<Vec<String>>>,
+}
+
+impl OneHotEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Traitement des catégories inconnues ([`HandleUnknown::Error`] par défaut).
+    pub fn with_handle_unknown(mut self, handle_unknown: HandleUnknown) -> Self {
+        self.handle_unknown = handle_unknown;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<String>) -> Result<(), ModelError> {
+        self.categories = Some(fit_categories(x, None)?);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<String>) -> Result<Array2<f64>, ModelError> {
+        let categories = self.categories()?;
+        check_n_features(categories.len(), x.ncols())?;
+        let n_outputs = categories.iter().map(Vec::len).sum();
+        let mut encoded = Array2::zeros((x.nrows(), n_outputs));
+        for (i, row) in x.rows().into_iter().enumerate() {
+            let mut offset = 0;
+            for (j, (value, column_categories)) in row.iter().zip(categories).enumerate() {
+                if let Some(k) = category_index(column_categories, value, j, self.handle_unknown)? {
+                    encoded[[i, offset + k]] = 1.0;
+                }
+                offset += column_categories.len();
+            }
+        }
+        Ok(encoded)
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<String>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Catégories apprises pour chaque colonne d'entrée.
+    pub fn categories(&self) -> Result<&[Vec<String>], ModelError> {
+        self.categories.as_deref().ok_or(ModelError::NotFitted)
+    }
+
+    /// Noms des colonnes produites, de la forme `colonne=catégorie`.
+    pub fn feature_names(&self, input_names: &[&str]) -> Result<Vec<String>, ModelError> {
+        let categories = self.categories()?;
+        check_n_features(categories.len(), input_names.len())?;
+        Ok(input_names
+            .iter()
+            .zip(categories)
+            .flat_map(|(name, column_categories)| {
+                column_categories
+                    .iter()
+                    .map(move |category| format!("{}={}", name, category))
+            })
+            .collect())
+    }
+}
+
+/// Remplace chaque catégorie par son rang : à réserver aux catégories ordonnées
+/// (« faible » < « moyen » < « élevé »), dont on impose alors l'ordre avec
+/// [`OrdinalEncoder::with_categories`].
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::preprocessing::{HandleUnknown, OrdinalEncoder};
+///
+/// let levels = vec!["faible".to_string(), "moyen".to_string(), "élevé".to_string()];
+/// let mut encoder = OrdinalEncoder::new()
+///     .with_categories(vec![levels])
+///     .with_handle_unknown(HandleUnknown::Ignore);
+/// let x = array![["élevé".to_string()], ["faible".to_string()]];
+/// assert_eq!(encoder.fit_transform(&x).unwrap(), array![[2.0], [0.0]]);
+/// assert!(encoder.transform(&array![["?".to_string()]]).unwrap()[[0, 0]].is_nan());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrdinalEncoder {
+    handle_unknown: HandleUnknown,
+    imposed_categories: Option<Vec<Vec<String>>>,
+    categories: Option<Vec<Vec<String>>>,
+}
+
+impl OrdinalEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Traitement des catégories inconnues ([`HandleUnknown::Error`] par défaut,
+    /// `Ignore` les encode en `NaN`).
+    pub fn with_handle_unknown(mut self, handle_unknown: HandleUnknown) -> Self {
+        self.handle_unknown = handle_unknown;
+        self
+    }
+
+    /// Impose les catégories de chaque colonne et leur ordre, au lieu de l'ordre
+    /// alphabétique des valeurs vues par `fit`.
+    pub fn with_categories(mut self, categories: Vec<Vec<String>>) -> Self {
+        self.imposed_categories = Some(categories);
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<String>) -> Result<(), ModelError> {
+        self.categories = Some(fit_categories(x, self.imposed_categories.as_ref())?);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Array2<String>) -> Result<Array2<f64>, ModelError> {
+        let categories = self.categories()?;
+        check_n_features(categories.len(), x.ncols())?;
+        let mut encoded = Array2::zeros(x.dim());
+        for ((i, j), value) in x.indexed_iter() {
+            encoded[[i, j]] = match category_index(&categories[j], value, j, self.handle_unknown)? {
+                Some(k) => k as f64,
+                None => f64::NAN,
+            };
+        }
+        Ok(encoded)
+    }
+
+    pub fn fit_transform(&mut self, x: &Array2<String>) -> Result<Array2<f64>, ModelError> {
+        self.fit(x)?;
+        self.transform(x)
+    }
+
+    /// Catégories de chaque colonne, la catégorie k étant encodée par k.
+    pub fn categories(&self) -> Result<&[Vec<String>], ModelError> {
+        self.categories.as_deref().ok_or(ModelError::NotFitted)
+    }
+}
@@ -0,0 +1,209 @@
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+use super::Kernel;
+use super::smo::SmoProblem;
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, unique_classes};
+
+/// SVM binaire opposant la classe `positive` (f(x) > 0) à la classe `negative`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BinarySvm {
+    positive: usize,
+    negative: usize,
+    support_vectors: Array2<f64>,
+    // yᵢ αᵢ pour chaque vecteur support
+    dual_coef: Array1<f64>,
+    rho: f64,
+}
+
+/// SVM pour la classification (C-SVC).
+///
+/// Avec plus de deux classes, une SVM est entraînée pour chaque paire de classes
+/// (un-contre-un) et la classe prédite est celle qui remporte le plus de duels.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::svm::{Kernel, SvmClassifier};
+///
+/// let x = array![[0.0, 0.0], [0.0, 1.0], [3.0, 3.0], [3.0, 4.0]];
+/// let y = array![0, 0, 1, 1];
+/// let mut svm = SvmClassifier::new().with_kernel(Kernel::Linear);
+/// svm.fit(&x, &y).unwrap();
+/// assert_eq!(svm.predict(&array![[0.5, 0.5], [4.0, 4.0]]).unwrap(), array![0, 1]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SvmClassifier {
+    kernel: Kernel,
+    c: f64,
+    tol: f64,
+    max_iter: usize,
+    classes: Vec<usize>,
+    machines: Vec<BinarySvm>,
+    n_features: usize,
+    n_iter: usize,
+}
+
+impl Default for SvmClassifier {
+    fn default() -> Self {
+        Self {
+            kernel: Kernel::default(),
+            c: 1.0,
+            tol: 1e-3,
+            max_iter: 100_000,
+            classes: Vec::new(),
+            machines: Vec::new(),
+            n_features: 0,
+            n_iter: 0,
+        }
+    }
+}
+
+impl SvmClassifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Noyau utilisé (RBF avec γ = 1 par défaut).
+    pub fn with_kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Pénalité C des erreurs de marge : plus elle est grande, moins la marge est souple (1.0 par défaut).
+    pub fn with_c(mut self, c: f64) -> Self {
+        self.c = c;
+        self
+    }
+
+    /// Tolérance sur les conditions KKT pour l'arrêt de SMO (1e-3 par défaut).
+    pub fn with_tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Nombre maximal d'itérations de SMO par SVM binaire (100 000 par défaut).
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.kernel.validate()?;
+        if self.c <= 0.0 || self.tol <= 0.0 {
+            return Err(ModelError::InvalidParameter(
+                "C et tol doivent être strictement positifs".to_string(),
+            ));
+        }
+        let classes = unique_classes(y);
+        if classes.len() < 2 {
+            return Err(ModelError::InvalidParameter(
+                "au moins deux classes sont nécessaires".to_string(),
+            ));
+        }
+
+        let mut machines = Vec::new();
+        let mut n_iter = 0;
+        for a in 0..classes.len() {
+            for b in a + 1..classes.len() {
+                let rows: Vec<usize> = (0..y.len())
+                    .filter(|&i| y[i] == classes[a] || y[i] == classes[b])
+                    .collect();
+                let x_pair = x.select(Axis(0), &rows);
+                let signs: Vec<f64> = rows
+                    .iter()
+                    .map(|&i| if y[i] == classes[a] { 1.0 } else { -1.0 })
+                    .collect();
+
+                let kernel = self.kernel.matrix(x_pair.view(), x_pair.view())?;
+                let problem = SmoProblem {
+                    kernel: &kernel,
+                    p: vec![-1.0; signs.len()],
+                    y: signs,
+                    c: self.c,
+                };
+                let solution = problem.solve(self.tol, self.max_iter);
+                n_iter = n_iter.max(solution.n_iter);
+
+                // Seules les observations avec α > 0 interviennent dans la décision
+                let support: Vec<usize> = (0..rows.len())
+                    .filter(|&t| solution.alpha[t] > 0.0)
+                    .collect();
+                machines.push(BinarySvm {
+                    positive: a,
+                    negative: b,
+                    support_vectors: x_pair.select(Axis(0), &support),
+                    dual_coef: support
+                        .iter()
+                        .map(|&t| problem.y[t] * solution.alpha[t])
+                        .collect(),
+                    rho: solution.rho,
+                });
+            }
+        }
+
+        self.classes = classes;
+        self.machines = machines;
+        self.n_features = x.ncols();
+        self.n_iter = n_iter;
+        Ok(())
+    }
+
+    /// Valeur f(x) de chaque SVM binaire (une colonne par paire de classes, dans
+    /// l'ordre (0, 1), (0, 2), ..., (1, 2)...) : positive si la première classe l'emporte.
+    pub fn decision_function(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        if self.machines.is_empty() {
+            return Err(ModelError::NotFitted);
+        }
+        check_n_features(self.n_features, x.ncols())?;
+        let mut decision = Array2::zeros((x.nrows(), self.machines.len()));
+        for (m, machine) in self.machines.iter().enumerate() {
+            let kernel = self
+                .kernel
+                .matrix(x.view(), machine.support_vectors.view())?;
+            let values = kernel.dot(&machine.dual_coef) - machine.rho;
+            decision.column_mut(m).assign(&values);
+        }
+        Ok(decision)
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let decision = self.decision_function(x)?;
+        Ok(decision
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let mut votes = vec![0.0; self.classes.len()];
+                for (machine, &value) in self.machines.iter().zip(row) {
+                    let winner = if value > 0.0 {
+                        machine.positive
+                    } else {
+                        machine.negative
+                    };
+                    votes[winner] += 1.0;
+                }
+                self.classes[argmax(votes.into_iter())]
+            })
+            .collect())
+    }
+
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    /// Nombre de vecteurs supports de chaque SVM binaire.
+    pub fn n_support(&self) -> Vec<usize> {
+        self.machines
+            .iter()
+            .map(|m| m.support_vectors.nrows())
+            .collect()
+    }
+
+    /// Nombre d'itérations de SMO (maximum sur les SVM binaires) ; s'il atteint
+    /// `max_iter`, l'optimisation n'a pas convergé.
+    pub fn n_iter(&self) -> usize {
+        self.n_iter
+    }
+}
@@ -0,0 +1,97 @@
+//! Machines à vecteurs supports (SVM), entraînées par l'algorithme SMO.
+//!
+//! Le problème dual d'une SVM ne dépend des données qu'à travers les produits
+//! scalaires ⟨xᵢ, xⱼ⟩ ; en les remplaçant par un noyau K(xᵢ, xⱼ), on obtient une
+//! frontière linéaire dans un espace de features implicite, non linéaire dans
+//! l'espace d'origine.
+//!
+//! - [`SvmClassifier`] : classification (un-contre-un au-delà de deux classes).
+//! - [`SvmRegressor`] : régression ε-SVR, insensible aux erreurs inférieures à ε.
+
+use ndarray::{Array2, ArrayView2};
+use serde::{Deserialize, Serialize};
+
+use crate::distance::{Distance, SquaredEuclidean};
+use crate::error::ModelError;
+
+mod classifier;
+mod regressor;
+mod smo;
+
+pub use classifier::SvmClassifier;
+pub use regressor::SvmRegressor;
+
+/// Fonction noyau K(x, z).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Kernel {
+    /// ⟨x, z⟩
+    Linear,
+    /// (γ ⟨x, z⟩ + coef0)^degree
+    Polynomial { degree: u32, gamma: f64, coef0: f64 },
+    /// exp(-γ ‖x - z‖²)
+    Rbf { gamma: f64 },
+    /// tanh(γ ⟨x, z⟩ + coef0)
+    Sigmoid { gamma: f64, coef0: f64 },
+}
+
+impl Default for Kernel {
+    /// Noyau gaussien avec γ = 1 (à adapter à l'échelle des features).
+    fn default() -> Self {
+        Kernel::Rbf { gamma: 1.0 }
+    }
+}
+
+impl Kernel {
+    pub(crate) fn validate(&self) -> Result<(), ModelError> {
+        let gamma = match *self {
+            Kernel::Linear => return Ok(()),
+            Kernel::Polynomial { degree: 0, .. } => {
+                return Err(ModelError::InvalidParameter(
+                    "le degré du noyau polynomial doit être >= 1".to_string(),
+                ));
+            }
+            Kernel::Polynomial { gamma, .. }
+            | Kernel::Rbf { gamma }
+            | Kernel::Sigmoid { gamma, .. } => gamma,
+        };
+        if gamma > 0.0 {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidParameter(
+                "gamma doit être strictement positif".to_string(),
+            ))
+        }
+    }
+
+    /// Matrice des K(aᵢ, bⱼ) entre les lignes de `a` et celles de `b`.
+    pub fn matrix(
+        &self,
+        a: ArrayView2<'_, f64>,
+        b: ArrayView2<'_, f64>,
+    ) -> Result<Array2<f64>, ModelError> {
+        if a.ncols() != b.ncols() {
+            return Err(ModelError::ShapeMismatch(format!(
+                "{} features d'un côté, {} de l'autre",
+                a.ncols(),
+                b.ncols()
+            )));
+        }
+        Ok(match *self {
+            Kernel::Linear => a.dot(&b.t()),
+            Kernel::Polynomial {
+                degree,
+                gamma,
+                coef0,
+            } => a
+                .dot(&b.t())
+                .mapv_into(|v| (gamma * v + coef0).powi(degree as i32)),
+            // On réutilise le calcul des distances (‖x‖² + ‖z‖² - 2 x·z en BLAS)
+            Kernel::Rbf { gamma } => SquaredEuclidean
+                .pairwise(a, b)?
+                .mapv_into(|d| (-gamma * d).exp()),
+            Kernel::Sigmoid { gamma, coef0 } => {
+                a.dot(&b.t()).mapv_into(|v| (gamma * v + coef0).tanh())
+            }
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+use super::Kernel;
+use super::smo::SmoProblem;
+use crate::error::{ModelError, check_n_features, check_x_y};
+
+/// SVM pour la régression (ε-SVR).
+///
+/// Les erreurs inférieures à ε ne sont pas pénalisées : seules les observations
+/// hors du « tube » de largeur ε autour de la prédiction deviennent vecteurs supports.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::svm::{Kernel, SvmRegressor};
+///
+/// let x = array![[0.0], [1.0], [2.0], [3.0]];
+/// let y = array![1.0, 3.0, 5.0, 7.0];
+/// let mut svr = SvmRegressor::new().with_kernel(Kernel::Linear).with_c(100.0);
+/// svr.fit(&x, &y).unwrap();
+/// assert!((svr.predict(&array![[1.5]]).unwrap()[0] - 4.0).abs() < 0.2);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SvmRegressor {
+    kernel: Kernel,
+    c: f64,
+    epsilon: f64,
+    tol: f64,
+    max_iter: usize,
+    support_vectors: Option<Array2<f64>>,
+    // αᵢ - αᵢ* pour chaque vecteur support
+    dual_coef: Array1<f64>,
+    rho: f64,
+    n_iter: usize,
+}
+
+impl Default for SvmRegressor {
+    fn default() -> Self {
+        Self {
+            kernel: Kernel::default(),
+            c: 1.0,
+            epsilon: 0.1,
+            tol: 1e-3,
+            max_iter: 100_000,
+            support_vectors: None,
+            dual_coef: Array1::zeros(0),
+            rho: 0.0,
+            n_iter: 0,
+        }
+    }
+}
+
+impl SvmRegressor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Noyau utilisé (RBF avec γ = 1 par défaut).
+    pub fn with_kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Pénalité C des observations hors du tube (1.0 par défaut).
+    pub fn with_c(mut self, c: f64) -> Self {
+        self.c = c;
+        self
+    }
+
+    /// Demi-largeur ε du tube sans pénalité, dans l'unité de la cible (0.1 par défaut).
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Tolérance sur les conditions KKT pour l'arrêt de SMO (1e-3 par défaut).
+    pub fn with_tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Nombre maximal d'itérations de SMO (100 000 par défaut).
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.kernel.validate()?;
+        if self.c <= 0.0 || self.tol <= 0.0 || self.epsilon < 0.0 {
+            return Err(ModelError::InvalidParameter(
+                "C et tol doivent être strictement positifs, epsilon positif".to_string(),
+            ));
+        }
+
+        // 2n variables : αᵢ (signe +1, terme ε - yᵢ) puis αᵢ* (signe -1, terme ε + yᵢ)
+        let n = x.nrows();
+        let kernel = self.kernel.matrix(x.view(), x.view())?;
+        let problem = SmoProblem {
+            kernel: &kernel,
+            y: (0..2 * n).map(|t| if t < n { 1.0 } else { -1.0 }).collect(),
+            p: (0..2 * n)
+                .map(|t| {
+                    if t < n {
+                        self.epsilon - y[t]
+                    } else {
+                        self.epsilon + y[t - n]
+                    }
+                })
+                .collect(),
+            c: self.c,
+        };
+        let solution = problem.solve(self.tol, self.max_iter);
+
+        let coef: Vec<f64> = (0..n)
+            .map(|i| solution.alpha[i] - solution.alpha[i + n])
+            .collect();
+        let support: Vec<usize> = (0..n).filter(|&i| coef[i] != 0.0).collect();
+        self.support_vectors = Some(x.select(Axis(0), &support));
+        self.dual_coef = support.iter().map(|&i| coef[i]).collect();
+        self.rho = solution.rho;
+        self.n_iter = solution.n_iter;
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        let support_vectors = self.support_vectors.as_ref().ok_or(ModelError::NotFitted)?;
+        check_n_features(support_vectors.ncols(), x.ncols())?;
+        let kernel = self.kernel.matrix(x.view(), support_vectors.view())?;
+        Ok(kernel.dot(&self.dual_coef) - self.rho)
+    }
+
+    /// Nombre de vecteurs supports.
+    pub fn n_support(&self) -> usize {
+        self.support_vectors.as_ref().map_or(0, |sv| sv.nrows())
+    }
+
+    /// Nombre d'itérations de SMO ; s'il atteint `max_iter`, l'optimisation n'a pas convergé.
+    pub fn n_iter(&self) -> usize {
+        self.n_iter
+    }
+}
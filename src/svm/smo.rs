@@ -0,0 +1,197 @@
+//! Solveur SMO (Sequential Minimal Optimization) du problème dual, à la LIBSVM.
+//!
+//! On minimise ½ αᵀQα + pᵀα sous les contraintes yᵀα = 0 et 0 <= αₜ <= C, avec
+//! Qₜₛ = yₜ yₛ K(xₜ, xₛ) et yₜ = ±1. À chaque itération, SMO choisit une paire de
+//! variables (sélection du second ordre de Fan, Chen et Lin, 2005) et résout
+//! analytiquement le sous-problème à deux variables.
+//!
+//! La classification et l'ε-SVR se ramènent toutes deux à ce problème : seuls
+//! `y`, `p` et la correspondance variable → observation changent.
+
+use ndarray::Array2;
+
+// Remplace une courbure nulle ou négative (noyau non défini positif)
+const TAU: f64 = 1e-12;
+
+/// Problème dual à résoudre. La variable `t` porte sur l'observation `t % n`,
+/// où n est la taille de la matrice de noyau (2n variables pour l'ε-SVR).
+pub(crate) struct SmoProblem<'a> {
+    pub kernel: &'a Array2<f64>,
+    pub y: Vec<f64>,
+    pub p: Vec<f64>,
+    pub c: f64,
+}
+
+pub(crate) struct SmoSolution {
+    pub alpha: Vec<f64>,
+    /// Biais de la fonction de décision f(x) = Σ yₜ αₜ K(xₜ, x) - rho.
+    pub rho: f64,
+    pub n_iter: usize,
+}
+
+impl SmoProblem<'_> {
+    fn k(&self, t: usize, s: usize) -> f64 {
+        let n = self.kernel.nrows();
+        self.kernel[[t % n, s % n]]
+    }
+
+    fn q(&self, t: usize, s: usize) -> f64 {
+        self.y[t] * self.y[s] * self.k(t, s)
+    }
+
+    pub(crate) fn solve(&self, tol: f64, max_iter: usize) -> SmoSolution {
+        let m = self.y.len();
+        let c = self.c;
+        let mut alpha = vec![0.0; m];
+        // Gradient de l'objectif : G = Qα + p, qui vaut p pour α = 0
+        let mut grad = self.p.clone();
+        let is_upper = |a: f64| a >= c;
+        let is_lower = |a: f64| a <= 0.0;
+
+        let mut n_iter = 0;
+        while n_iter < max_iter {
+            let Some((i, j)) = self.select_working_set(&alpha, &grad, tol) else {
+                break;
+            };
+            n_iter += 1;
+
+            let (old_i, old_j) = (alpha[i], alpha[j]);
+            let (q_ii, q_jj, q_ij) = (self.q(i, i), self.q(j, j), self.q(i, j));
+            if self.y[i] != self.y[j] {
+                let quad = (q_ii + q_jj + 2.0 * q_ij).max(TAU);
+                let delta = (-grad[i] - grad[j]) / quad;
+                let diff = alpha[i] - alpha[j];
+                alpha[i] += delta;
+                alpha[j] += delta;
+                // Projection sur la boîte [0, C]² le long de αᵢ - αⱼ = diff
+                if diff > 0.0 {
+                    if alpha[j] < 0.0 {
+                        alpha[j] = 0.0;
+                        alpha[i] = diff;
+                    }
+                } else if alpha[i] < 0.0 {
+                    alpha[i] = 0.0;
+                    alpha[j] = -diff;
+                }
+                if diff > 0.0 {
+                    if alpha[i] > c {
+                        alpha[i] = c;
+                        alpha[j] = c - diff;
+                    }
+                } else if alpha[j] > c {
+                    alpha[j] = c;
+                    alpha[i] = c + diff;
+                }
+            } else {
+                let quad = (q_ii + q_jj - 2.0 * q_ij).max(TAU);
+                let delta = (grad[i] - grad[j]) / quad;
+                let sum = alpha[i] + alpha[j];
+                alpha[i] -= delta;
+                alpha[j] += delta;
+                // Projection sur la boîte [0, C]² le long de αᵢ + αⱼ = sum
+                if sum > c {
+                    if alpha[i] > c {
+                        alpha[i] = c;
+                        alpha[j] = sum - c;
+                    }
+                    if alpha[j] > c {
+                        alpha[j] = c;
+                        alpha[i] = sum - c;
+                    }
+                } else {
+                    if alpha[j] < 0.0 {
+                        alpha[j] = 0.0;
+                        alpha[i] = sum;
+                    }
+                    if alpha[i] < 0.0 {
+                        alpha[i] = 0.0;
+                        alpha[j] = sum;
+                    }
+                }
+            }
+
+            let (delta_i, delta_j) = (alpha[i] - old_i, alpha[j] - old_j);
+            for (t, g) in grad.iter_mut().enumerate() {
+                *g += self.q(t, i) * delta_i + self.q(t, j) * delta_j;
+            }
+        }
+
+        // Biais : moyenne sur les variables libres (0 < α < C), sinon milieu de l'intervalle admissible
+        let (mut upper, mut lower) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut sum_free, mut n_free) = (0.0, 0);
+        for t in 0..m {
+            let yg = self.y[t] * grad[t];
+            let bounded_above =
+                (is_upper(alpha[t]) && self.y[t] < 0.0) || (is_lower(alpha[t]) && self.y[t] > 0.0);
+            if is_upper(alpha[t]) || is_lower(alpha[t]) {
+                if bounded_above {
+                    upper = upper.min(yg);
+                } else {
+                    lower = lower.max(yg);
+                }
+            } else {
+                sum_free += yg;
+                n_free += 1;
+            }
+        }
+        let rho = if n_free > 0 {
+            sum_free / n_free as f64
+        } else {
+            (upper + lower) / 2.0
+        };
+
+        SmoSolution { alpha, rho, n_iter }
+    }
+
+    /// Choisit la paire (i, j) qui viole le plus les conditions KKT, ou `None` si
+    /// l'écart de dualité est inférieur à `tol` (convergence).
+    fn select_working_set(&self, alpha: &[f64], grad: &[f64], tol: f64) -> Option<(usize, usize)> {
+        let c = self.c;
+        // i : variable qui peut le plus augmenter -yₜ Gₜ
+        let mut g_max = f64::NEG_INFINITY;
+        let mut i = None;
+        for t in 0..alpha.len() {
+            let can_move = if self.y[t] > 0.0 {
+                alpha[t] < c
+            } else {
+                alpha[t] > 0.0
+            };
+            if can_move && -self.y[t] * grad[t] >= g_max {
+                g_max = -self.y[t] * grad[t];
+                i = Some(t);
+            }
+        }
+        let i = i?;
+
+        // j : variable qui diminue le plus l'objectif avec i (approximation du second ordre)
+        let mut g_max2 = f64::NEG_INFINITY;
+        let mut j = None;
+        let mut best_decrease = f64::INFINITY;
+        for t in 0..alpha.len() {
+            let can_move = if self.y[t] > 0.0 {
+                alpha[t] > 0.0
+            } else {
+                alpha[t] < c
+            };
+            if !can_move {
+                continue;
+            }
+            let yg = self.y[t] * grad[t];
+            g_max2 = g_max2.max(yg);
+            let grad_diff = g_max + yg;
+            if grad_diff > 0.0 {
+                let quad = (self.k(i, i) + self.k(t, t) - 2.0 * self.k(i, t)).max(TAU);
+                let decrease = -grad_diff * grad_diff / quad;
+                if decrease <= best_decrease {
+                    best_decrease = decrease;
+                    j = Some(t);
+                }
+            }
+        }
+
+        if g_max + g_max2 < tol {
+            return None;
+        }
+        j.map(|j| (i, j))
+    }
+}
@@ -0,0 +1,306 @@
+//! Interface commune des modèles et des transformations.
+//!
+//! Chaque modèle garde ses méthodes `fit(x, y)` / `predict(x)` sur des tableaux
+//! ndarray. Les traits ci-dessous les exposent sous une même signature, autour de
+//! [`Dataset`], pour écrire du code générique : une fonction d'évaluation qui accepte
+//! n'importe quel classifieur ou un modèle non supervisé comme [`KMeans`], le
+//! [`Pipeline`](crate::pipeline::Pipeline), la validation croisée
+//! ([`crate::model_selection::cross_val_score`]).
+//!
+//! - [`Fit`] : apprend à partir d'un [`Dataset<T>`] (`T = ()` sans cible) ;
+//! - [`Predict`] : prédit une valeur par observation ;
+//! - [`PredictProba`] : probabilité de chaque classe ;
+//! - [`Transform`] : renvoie une nouvelle matrice de features ;
+//! - [`Score`] : qualité du modèle sur un jeu de données (accuracy pour un
+//!   classifieur, R² pour un régresseur, plus grand = meilleur).
+//!
+//! Les modèles supervisés implémentent [`Fit`], [`Predict`] et [`Score`], les étapes
+//! de prétraitement et la PCA [`Fit`] et [`Transform`] ; le pipeline les implémente à
+//! son tour à partir de ceux de son modèle final. Sur un type concret,
+//! `model.fit(&x, &y)` reste la méthode du modèle ; dans une fonction générique
+//! (`M: Fit<T>`), `model.fit(&data)` est celle du trait.
+
+use ndarray::{Array1, Array2};
+
+use crate::cluster::KMeans;
+use crate::data::Dataset;
+use crate::decomposition::Pca;
+use crate::discriminant_analysis::{LinearDiscriminantAnalysis, QuadraticDiscriminantAnalysis};
+use crate::ensemble::{
+    GradientBoostingClassifier, GradientBoostingRegressor, RandomForestClassifier,
+    RandomForestRegressor,
+};
+use crate::error::ModelError;
+use crate::impute::{KnnImputer, SimpleImputer};
+use crate::linear_model::LinearRegression;
+use crate::metrics::{accuracy, r2_score};
+use crate::naive_bayes::{BernoulliNB, GaussianNB, MultinomialNB, NaiveBayes};
+use crate::neural_network::{MlpClassifier, MlpRegressor};
+use crate::pipeline::Step;
+use crate::preprocessing::{BiasColumn, PolynomialFeatures, StandardScaler};
+use crate::svm::{SvmClassifier, SvmRegressor};
+use crate::tree::{DecisionTreeClassifier, DecisionTreeRegressor};
+
+/// Apprentissage à partir d'un jeu de données de cible `T`.
+///
+/// Les modèles non supervisés et les transformations implémentent `Fit<T>` pour tout
+/// `T` et ignorent la cible.
+pub trait Fit<T> {
+    fn fit(&mut self, data: &Dataset<T>) -> Result<(), ModelError>;
+}
+
+/// Prédiction d'une valeur par observation : classe (`usize`), valeur (`f64`) ou
+/// cluster (`usize`).
+pub trait Predict {
+    type Output;
+
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<Self::Output>, ModelError>;
+}
+
+/// Classifieur qui estime la probabilité de chaque classe.
+pub trait PredictProba: Predict<Output = usize> {
+    /// Classes vues pendant l'entraînement, dans l'ordre des colonnes de `predict_proba`.
+    fn classes(&self) -> &[usize];
+
+    /// Probabilités P(c | x), matrice (n_observations × n_classes).
+    fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError>;
+}
+
+/// Transformation des features apprise par [`Fit`].
+pub trait Transform {
+    fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError>;
+}
+
+/// Score d'un modèle entraîné sur `data`, plus grand = meilleur.
+///
+/// # Example
+/// ```
+/// use rust_first_steps::data::{DIABETES_FEATURES, Dataset, load_dataset};
+/// use rust_first_steps::discriminant_analysis::LinearDiscriminantAnalysis;
+/// use rust_first_steps::error::ModelError;
+/// use rust_first_steps::naive_bayes::GaussianNB;
+/// use rust_first_steps::traits::{Fit, Score};
+///
+/// // N'importe quel classifieur de la bibliothèque convient
+/// fn holdout_score<M: Fit<usize> + Score<usize>>(
+///     mut model: M,
+///     data: &Dataset<usize>,
+/// ) -> Result<f64, ModelError> {
+///     let (train, test) = data.train_test_split(0.3, 42);
+///     model.fit(&train)?;
+///     model.score(&test)
+/// }
+///
+/// let diabetes = load_dataset("data/diabetes.csv", &DIABETES_FEATURES, "outcome").unwrap();
+/// assert!(holdout_score(GaussianNB::new(), &diabetes).unwrap() > 0.7);
+/// assert!(holdout_score(LinearDiscriminantAnalysis::new(), &diabetes).unwrap() > 0.7);
+/// ```
+pub trait Score<T> {
+    fn score(&self, data: &Dataset<T>) -> Result<f64, ModelError>;
+}
+
+// Les modèles exposent déjà fit(x, y) / predict(x) : les traits délèguent aux méthodes
+macro_rules! impl_fit_predict {
+    ($target:ty => $($model:ty),*) => {$(
+        impl Fit<$target> for $model {
+            fn fit(&mut self, data: &Dataset<$target>) -> Result<(), ModelError> {
+                <$model>::fit(self, data.x(), data.y())
+            }
+        }
+
+        impl Predict for $model {
+            type Output = $target;
+
+            fn predict(&self, x: &Array2<f64>) -> Result<Array1<$target>, ModelError> {
+                <$model>::predict(self, x)
+            }
+        }
+    )*};
+}
+
+impl_fit_predict!(usize =>
+    DecisionTreeClassifier,
+    RandomForestClassifier,
+    GradientBoostingClassifier,
+    SvmClassifier,
+    MlpClassifier,
+    LinearDiscriminantAnalysis,
+    QuadraticDiscriminantAnalysis
+);
+
+impl_fit_predict!(f64 =>
+    LinearRegression,
+    DecisionTreeRegressor,
+    RandomForestRegressor,
+    GradientBoostingRegressor,
+    SvmRegressor,
+    MlpRegressor
+);
+
+// `predict` vient du trait NaiveBayes pour ces modèles
+macro_rules! impl_naive_bayes_fit_predict {
+    ($($model:ty),*) => {$(
+        impl Fit<usize> for $model {
+            fn fit(&mut self, data: &Dataset<usize>) -> Result<(), ModelError> {
+                <$model>::fit(self, data.x(), data.y())
+            }
+        }
+
+        impl Predict for $model {
+            type Output = usize;
+
+            fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+                NaiveBayes::predict(self, x)
+            }
+        }
+    )*};
+}
+
+impl_naive_bayes_fit_predict!(GaussianNB, MultinomialNB, BernoulliNB);
+
+// Accuracy des classes prédites ou R² des valeurs prédites
+macro_rules! impl_score {
+    ($target:ty, $metric:ident => $($model:ty),*) => {$(
+        impl Score<$target> for $model {
+            fn score(&self, data: &Dataset<$target>) -> Result<f64, ModelError> {
+                $metric(data.y(), &Predict::predict(self, data.x())?)
+            }
+        }
+    )*};
+}
+
+impl_score!(usize, accuracy =>
+    DecisionTreeClassifier,
+    RandomForestClassifier,
+    GradientBoostingClassifier,
+    SvmClassifier,
+    MlpClassifier,
+    LinearDiscriminantAnalysis,
+    QuadraticDiscriminantAnalysis,
+    GaussianNB,
+    MultinomialNB,
+    BernoulliNB
+);
+
+impl_score!(f64, r2_score =>
+    LinearRegression,
+    DecisionTreeRegressor,
+    RandomForestRegressor,
+    GradientBoostingRegressor,
+    SvmRegressor,
+    MlpRegressor
+);
+
+// Les étapes de prétraitement ignorent la cible
+macro_rules! impl_fit_transform {
+    ($($transformer:ty),*) => {$(
+        impl<T> Fit<T> for $transformer {
+            fn fit(&mut self, data: &Dataset<T>) -> Result<(), ModelError> {
+                <$transformer>::fit(self, data.x())
+            }
+        }
+
+        impl Transform for $transformer {
+            fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+                <$transformer>::transform(self, x)
+            }
+        }
+    )*};
+}
+
+impl_fit_transform!(
+    SimpleImputer,
+    KnnImputer,
+    StandardScaler,
+    PolynomialFeatures,
+    Step,
+    Pca
+);
+
+/// La colonne de biais n'a rien à apprendre.
+impl<T> Fit<T> for BiasColumn {
+    fn fit(&mut self, _data: &Dataset<T>) -> Result<(), ModelError> {
+        Ok(())
+    }
+}
+
+impl Transform for BiasColumn {
+    fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        Ok(BiasColumn::transform(self, x))
+    }
+}
+
+macro_rules! impl_predict_proba {
+    ($($model:ty),*) => {$(
+        impl PredictProba for $model {
+            fn classes(&self) -> &[usize] {
+                <$model>::classes(self)
+            }
+
+            fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+                <$model>::predict_proba(self, x)
+            }
+        }
+    )*};
+}
+
+impl_predict_proba!(
+    DecisionTreeClassifier,
+    RandomForestClassifier,
+    GradientBoostingClassifier,
+    MlpClassifier,
+    LinearDiscriminantAnalysis,
+    QuadraticDiscriminantAnalysis
+);
+
+// Pour les bayésiens naïfs, predict_proba vient du trait NaiveBayes
+macro_rules! impl_naive_bayes_proba {
+    ($($model:ty),*) => {$(
+        impl PredictProba for $model {
+            fn classes(&self) -> &[usize] {
+                NaiveBayes::classes(self)
+            }
+
+            fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+                NaiveBayes::predict_proba(self, x)
+            }
+        }
+    )*};
+}
+
+impl_naive_bayes_proba!(GaussianNB, MultinomialNB, BernoulliNB);
+
+/// Projection sur les directions discriminantes.
+impl Transform for LinearDiscriminantAnalysis {
+    fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        LinearDiscriminantAnalysis::transform(self, x)
+    }
+}
+
+impl<T> Fit<T> for KMeans {
+    fn fit(&mut self, data: &Dataset<T>) -> Result<(), ModelError> {
+        KMeans::fit(self, data.x())
+    }
+}
+
+impl Predict for KMeans {
+    type Output = usize;
+
+    fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        KMeans::predict(self, x)
+    }
+}
+
+/// Distance de chaque observation à chaque centroïde.
+impl Transform for KMeans {
+    fn transform(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        KMeans::transform(self, x)
+    }
+}
+
+/// Opposé de l'inertie de `data` (plus grand = clusters plus compacts).
+impl<T> Score<T> for KMeans {
+    fn score(&self, data: &Dataset<T>) -> Result<f64, ModelError> {
+        KMeans::score(self, data.x())
+    }
+}
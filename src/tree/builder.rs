@@ -0,0 +1,313 @@
+//! Construction d'un arbre CART : recherche gloutonne de la meilleure coupure binaire.
+
+use ndarray::{Array1, ArrayView2};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+use super::{Node, Split, Tree};
+use crate::error::ModelError;
+
+/// Critère d'impureté pour la classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Criterion {
+    /// Indice de Gini : 1 - Σ pₖ².
+    #[default]
+    Gini,
+    /// Entropie de Shannon : -Σ pₖ log₂ pₖ.
+    Entropy,
+}
+
+/// Hyperparamètres communes aux arbres de classification et de régression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TreeParams {
+    pub max_depth: Option<usize>,
+    pub min_samples_split: usize,
+    pub min_samples_leaf: usize,
+    /// Nombre de features tirées au hasard à chaque nœud (toutes si `None`).
+    pub max_features: Option<usize>,
+    /// Graine du tirage des features.
+    pub seed: u64,
+}
+
+impl Default for TreeParams {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            min_samples_split: 2,
+            min_samples_leaf: 1,
+            max_features: None,
+            seed: 0,
+        }
+    }
+}
+
+impl TreeParams {
+    pub(crate) fn validate(&self) -> Result<(), ModelError> {
+        if self.min_samples_leaf == 0 || self.min_samples_split < 2 {
+            return Err(ModelError::InvalidParameter(
+                "min_samples_leaf doit être >= 1 et min_samples_split >= 2".to_string(),
+            ));
+        }
+        if self.max_features == Some(0) {
+            return Err(ModelError::InvalidParameter(
+                "max_features doit être >= 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Ce qui distingue la classification de la régression pendant la construction :
+/// les statistiques accumulées dans un nœud et l'impureté qu'on en déduit.
+pub(crate) trait Task {
+    type Stats: Clone;
+
+    fn empty_stats(&self) -> Self::Stats;
+    fn add(&self, stats: &mut Self::Stats, i: usize);
+    fn remove(&self, stats: &mut Self::Stats, i: usize);
+    fn impurity(&self, stats: &Self::Stats, n: usize) -> f64;
+    /// Valeur stockée dans le nœud : proportions des classes ou moyenne de la cible.
+    fn value(&self, stats: &Self::Stats, n: usize) -> Vec<f64>;
+}
+
+/// Classification : `y` contient des indices de classe dans 0..n_classes.
+pub(crate) struct Classification<'a> {
+    pub y: &'a [usize],
+    pub n_classes: usize,
+    pub criterion: Criterion,
+}
+
+impl Task for Classification<'_> {
+    // Nombre d'observations de chaque classe
+    type Stats = Vec<f64>;
+
+    fn empty_stats(&self) -> Self::Stats {
+        vec![0.0; self.n_classes]
+    }
+
+    fn add(&self, stats: &mut Self::Stats, i: usize) {
+        stats[self.y[i]] += 1.0;
+    }
+
+    fn remove(&self, stats: &mut Self::Stats, i: usize) {
+        stats[self.y[i]] -= 1.0;
+    }
+
+    fn impurity(&self, stats: &Self::Stats, n: usize) -> f64 {
+        let n = n as f64;
+        match self.criterion {
+            Criterion::Gini => 1.0 - stats.iter().map(|c| (c / n).powi(2)).sum::<f64>(),
+            Criterion::Entropy => -stats
+                .iter()
+                .filter(|&&c| c > 0.0)
+                .map(|c| (c / n) * (c / n).log2())
+                .sum::<f64>(),
+        }
+    }
+
+    fn value(&self, stats: &Self::Stats, n: usize) -> Vec<f64> {
+        stats.iter().map(|c| c / n as f64).collect()
+    }
+}
+
+/// Régression : impureté = erreur quadratique moyenne autour de la moyenne du nœud.
+pub(crate) struct Regression<'a> {
+    pub y: &'a [f64],
+}
+
+impl Task for Regression<'_> {
+    // (Σ y, Σ y²)
+    type Stats = (f64, f64);
+
+    fn empty_stats(&self) -> Self::Stats {
+        (0.0, 0.0)
+    }
+
+    fn add(&self, stats: &mut Self::Stats, i: usize) {
+        stats.0 += self.y[i];
+        stats.1 += self.y[i] * self.y[i];
+    }
+
+    fn remove(&self, stats: &mut Self::Stats, i: usize) {
+        stats.0 -= self.y[i];
+        stats.1 -= self.y[i] * self.y[i];
+    }
+
+    fn impurity(&self, stats: &Self::Stats, n: usize) -> f64 {
+        let n = n as f64;
+        let mean = stats.0 / n;
+        // Var(y) = E[y²] - E[y]², max(0) contre les erreurs d'arrondi
+        (stats.1 / n - mean * mean).max(0.0)
+    }
+
+    fn value(&self, stats: &Self::Stats, n: usize) -> Vec<f64> {
+        vec![stats.0 / n as f64]
+    }
+}
+
+struct BestSplit {
+    feature: usize,
+    threshold: f64,
+    // Σ n_enfant × impureté_enfant, à minimiser
+    cost: f64,
+}
+
+struct Builder<'x, 'a, T: Task> {
+    x: ArrayView2<'x, f64>,
+    task: &'a T,
+    params: &'a TreeParams,
+    nodes: Vec<Node>,
+    importances: Vec<f64>,
+    rng: StdRng,
+}
+
+/// Construit un arbre sur les observations `indices` de `x`.
+///
+/// `indices` peut contenir des doublons (échantillon bootstrap d'une forêt aléatoire).
+pub(crate) fn build_tree<T: Task>(
+    x: ArrayView2<'_, f64>,
+    task: &T,
+    params: &TreeParams,
+    mut indices: Vec<usize>,
+) -> Tree {
+    let mut builder = Builder {
+        x,
+        task,
+        params,
+        nodes: Vec::new(),
+        importances: vec![0.0; x.ncols()],
+        rng: StdRng::seed_from_u64(params.seed),
+    };
+    builder.grow(&mut indices, 0);
+
+    // Importances normalisées pour sommer à 1 (toutes nulles si l'arbre est une seule feuille)
+    let total: f64 = builder.importances.iter().sum();
+    let mut importances = Array1::from_vec(builder.importances);
+    if total > 0.0 {
+        importances /= total;
+    }
+
+    Tree {
+        nodes: builder.nodes,
+        n_features: x.ncols(),
+        feature_importances: importances,
+    }
+}
+
+impl<T: Task> Builder<'_, '_, T> {
+    /// Crée le nœud des observations `indices` puis ses enfants, renvoie son indice.
+    fn grow(&mut self, indices: &mut [usize], depth: usize) -> usize {
+        let n = indices.len();
+        let mut stats = self.task.empty_stats();
+        for &i in indices.iter() {
+            self.task.add(&mut stats, i);
+        }
+        let impurity = self.task.impurity(&stats, n);
+
+        let node_id = self.nodes.len();
+        self.nodes.push(Node {
+            split: None,
+            value: self.task.value(&stats, n),
+            impurity,
+            n_samples: n,
+        });
+
+        let can_split = n >= self.params.min_samples_split
+            && n >= 2 * self.params.min_samples_leaf
+            && self.params.max_depth.is_none_or(|max| depth < max)
+            && impurity > 1e-12;
+        if !can_split {
+            return node_id;
+        }
+        let Some(best) = self.best_split(indices, &stats) else {
+            return node_id;
+        };
+
+        // Les observations qui vont à gauche (x <= seuil) sont placées en tête
+        let mut n_left = 0;
+        for k in 0..n {
+            if self.x[[indices[k], best.feature]] <= best.threshold {
+                indices.swap(k, n_left);
+                n_left += 1;
+            }
+        }
+        self.importances[best.feature] += n as f64 * impurity - best.cost;
+
+        let (left_indices, right_indices) = indices.split_at_mut(n_left);
+        let left = self.grow(left_indices, depth + 1);
+        let right = self.grow(right_indices, depth + 1);
+        self.nodes[node_id].split = Some(Split {
+            feature: best.feature,
+            threshold: best.threshold,
+            left,
+            right,
+        });
+        node_id
+    }
+
+    /// Parcourt les features candidates et tous les seuils possibles, garde la coupure
+    /// qui minimise l'impureté pondérée des deux enfants.
+    fn best_split(&mut self, indices: &[usize], total: &T::Stats) -> Option<BestSplit> {
+        let n = indices.len();
+        let min_leaf = self.params.min_samples_leaf;
+        let mut sorted = indices.to_vec();
+        let mut best: Option<BestSplit> = None;
+
+        for feature in self.candidate_features() {
+            let column = self.x.column(feature);
+            sorted.sort_by(|&a, &b| column[a].total_cmp(&column[b]));
+
+            // On fait glisser les observations une à une de droite à gauche
+            let mut left = self.task.empty_stats();
+            let mut right = total.clone();
+            for pos in 0..n - 1 {
+                let i = sorted[pos];
+                self.task.add(&mut left, i);
+                self.task.remove(&mut right, i);
+
+                let n_left = pos + 1;
+                let n_right = n - n_left;
+                if n_left < min_leaf {
+                    continue;
+                }
+                if n_right < min_leaf {
+                    break;
+                }
+                // Impossible de séparer deux valeurs identiques
+                let (value, next_value) = (column[i], column[sorted[pos + 1]]);
+                if next_value <= value {
+                    continue;
+                }
+
+                let cost = n_left as f64 * self.task.impurity(&left, n_left)
+                    + n_right as f64 * self.task.impurity(&right, n_right);
+                if best.as_ref().is_none_or(|b| cost < b.cost) {
+                    best = Some(BestSplit {
+                        feature,
+                        threshold: midpoint(value, next_value),
+                        cost,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    /// Features examinées à ce nœud : toutes, ou `max_features` tirées sans remise.
+    fn candidate_features(&mut self) -> Vec<usize> {
+        let n_features = self.x.ncols();
+        match self.params.max_features {
+            Some(k) if k < n_features => {
+                rand::seq::index::sample(&mut self.rng, n_features, k).into_vec()
+            }
+            _ => (0..n_features).collect(),
+        }
+    }
+}
+
+/// Seuil au milieu de deux valeurs consécutives, toujours strictement inférieur à `b`.
+fn midpoint(a: f64, b: f64) -> f64 {
+    let mid = a + (b - a) / 2.0;
+    if mid < b { mid } else { a }
+}
@@ -0,0 +1,152 @@
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use super::{Classification, Criterion, Tree, TreeParams, build_tree};
+use crate::error::{ModelError, check_n_features, check_x_y};
+use crate::utils::{argmax, unique_classes};
+
+/// Arbre de décision pour la classification.
+///
+/// # Example
+/// ```
+/// use ndarray::array;
+/// use rust_first_steps::tree::DecisionTreeClassifier;
+///
+/// let x = array![[1.0], [2.0], [10.0], [11.0]];
+/// let y = array![0, 0, 1, 1];
+/// let mut tree = DecisionTreeClassifier::new().with_max_depth(1);
+/// tree.fit(&x, &y).unwrap();
+/// assert_eq!(tree.predict(&array![[0.0], [12.0]]).unwrap(), array![0, 1]);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DecisionTreeClassifier {
+    criterion: Criterion,
+    params: TreeParams,
+    classes: Vec<usize>,
+    tree: Option<Tree>,
+}
+
+impl DecisionTreeClassifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Critère d'impureté (Gini par défaut).
+    pub fn with_criterion(mut self, criterion: Criterion) -> Self {
+        self.criterion = criterion;
+        self
+    }
+
+    /// Profondeur maximale (illimitée par défaut).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Nombre minimal d'observations pour découper un nœud (2 par défaut).
+    pub fn with_min_samples_split(mut self, min_samples_split: usize) -> Self {
+        self.params.min_samples_split = min_samples_split;
+        self
+    }
+
+    /// Nombre minimal d'observations dans chaque feuille (1 par défaut).
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Nombre de features tirées au hasard parmi lesquelles chercher chaque coupure
+    /// (toutes par défaut).
+    pub fn with_max_features(mut self, max_features: usize) -> Self {
+        self.params.max_features = Some(max_features);
+        self
+    }
+
+    /// Graine du tirage des features quand `max_features` est utilisé.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.seed = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<usize>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        let classes = unique_classes(y);
+        // Les classes sont ramenées à des indices 0..n_classes
+        let encoded: Vec<usize> = y
+            .iter()
+            .map(|c| {
+                classes
+                    .binary_search(c)
+                    .expect("classe vue pendant unique_classes")
+            })
+            .collect();
+        let task = Classification {
+            y: &encoded,
+            n_classes: classes.len(),
+            criterion: self.criterion,
+        };
+        self.tree = Some(build_tree(
+            x.view(),
+            &task,
+            &self.params,
+            (0..x.nrows()).collect(),
+        ));
+        self.classes = classes;
+        Ok(())
+    }
+
+    /// Probabilités des classes : proportions de chaque classe dans la feuille atteinte.
+    pub fn predict_proba(&self, x: &Array2<f64>) -> Result<Array2<f64>, ModelError> {
+        let tree = self.tree()?;
+        check_n_features(tree.n_features(), x.ncols())?;
+        let mut proba = Array2::zeros((x.nrows(), self.classes.len()));
+        for (mut out, row) in proba.rows_mut().into_iter().zip(x.rows()) {
+            out.assign(&Array1::from_vec(tree.leaf(row).value.clone()));
+        }
+        Ok(proba)
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<usize>, ModelError> {
+        let proba = self.predict_proba(x)?;
+        Ok(proba
+            .rows()
+            .into_iter()
+            .map(|row| self.classes[argmax(row.iter().copied())])
+            .collect())
+    }
+
+    /// Classes vues pendant l'entraînement, triées par ordre croissant.
+    pub fn classes(&self) -> &[usize] {
+        &self.classes
+    }
+
+    pub fn tree(&self) -> Result<&Tree, ModelError> {
+        self.tree.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    pub fn feature_importances(&self) -> Result<&Array1<f64>, ModelError> {
+        Ok(self.tree()?.feature_importances())
+    }
+
+    /// Arbre au format texte, les feuilles affichent la classe majoritaire.
+    ///
+    /// # Arguments
+    /// * `feature_names` - Nom des features, dans l'ordre des colonnes de X.
+    /// * `class_names` - Nom des classes, dans l'ordre de [`classes`](Self::classes)
+    ///   (la valeur numérique est affichée si le nom est absent).
+    pub fn export_text(
+        &self,
+        feature_names: &[&str],
+        class_names: &[&str],
+    ) -> Result<String, ModelError> {
+        let tree = self.tree()?;
+        Ok(tree.export_text(feature_names, |node| {
+            let k = node.majority_class();
+            match class_names.get(k) {
+                Some(name) => format!("class: {}", name),
+                None => format!("class: {}", self.classes[k]),
+            }
+        }))
+    }
+}
@@ -0,0 +1,171 @@
+//! Arbres de décision CART (Classification And Regression Trees).
+//!
+//! Un arbre découpe récursivement l'espace des features par des questions binaires
+//! « xⱼ <= seuil ? », choisies pour rendre chaque enfant le plus homogène possible
+//! (Gini ou entropie en classification, erreur quadratique en régression).
+//!
+//! - [`DecisionTreeClassifier`] : prédit une classe et ses probabilités.
+//! - [`DecisionTreeRegressor`] : prédit une valeur continue.
+//!
+//! L'arbre entraîné ([`Tree`]) peut être exporté en texte ou parcouru nœud par nœud.
+
+use ndarray::{Array1, ArrayView1};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::argmax;
+
+mod builder;
+mod classifier;
+mod regressor;
+
+pub use builder::Criterion;
+pub use classifier::DecisionTreeClassifier;
+pub use regressor::DecisionTreeRegressor;
+
+pub(crate) use builder::{Classification, Regression, TreeParams, build_tree};
+
+/// Question posée par un nœud interne : les observations avec `x[feature] <= threshold`
+/// vont dans l'enfant `left`, les autres dans `right`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Split {
+    pub feature: usize,
+    pub threshold: f64,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// Un nœud de l'arbre (feuille si `split` vaut `None`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub split: Option<Split>,
+    /// Proportions des classes (classification) ou moyenne de la cible (régression).
+    pub value: Vec<f64>,
+    pub impurity: f64,
+    pub n_samples: usize,
+}
+
+impl Node {
+    pub fn is_leaf(&self) -> bool {
+        self.split.is_none()
+    }
+
+    /// Indice de la classe majoritaire du nœud (arbre de classification).
+    pub fn majority_class(&self) -> usize {
+        argmax(self.value.iter().copied())
+    }
+}
+
+/// Arbre entraîné, stocké à plat : la racine est `nodes[0]` et les enfants sont
+/// référencés par leur indice dans `nodes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    n_features: usize,
+    feature_importances: Array1<f64>,
+}
+
+impl Tree {
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn n_features(&self) -> usize {
+        self.n_features
+    }
+
+    /// Diminution d'impureté apportée par chaque feature, normalisée pour sommer à 1.
+    pub fn feature_importances(&self) -> &Array1<f64> {
+        &self.feature_importances
+    }
+
+    /// Feuille dans laquelle tombe une observation.
+    pub fn leaf(&self, row: ArrayView1<'_, f64>) -> &Node {
+        let mut node = &self.nodes[0];
+        while let Some(split) = &node.split {
+            node = if row[split.feature] <= split.threshold {
+                &self.nodes[split.left]
+            } else {
+                &self.nodes[split.right]
+            };
+        }
+        node
+    }
+
+    /// Profondeur de l'arbre (0 pour une seule feuille).
+    pub fn depth(&self) -> usize {
+        self.node_depth(0)
+    }
+
+    pub fn n_leaves(&self) -> usize {
+        self.nodes.iter().filter(|node| node.is_leaf()).count()
+    }
+
+    fn node_depth(&self, id: usize) -> usize {
+        match &self.nodes[id].split {
+            Some(split) => {
+                1 + self
+                    .node_depth(split.left)
+                    .max(self.node_depth(split.right))
+            }
+            None => 0,
+        }
+    }
+
+    /// Représentation texte de l'arbre, une ligne par branche :
+    ///
+    /// ```text
+    /// |--- petal_length <= 2.45
+    /// |   |--- class: Setosa
+    /// |--- petal_length >  2.45
+    /// ...
+    /// ```
+    ///
+    /// # Arguments
+    /// * `feature_names` - Nom des features (`feature_j` si le nom est absent).
+    /// * `format_leaf` - Texte affiché pour une feuille.
+    pub fn export_text(
+        &self,
+        feature_names: &[&str],
+        format_leaf: impl Fn(&Node) -> String,
+    ) -> String {
+        let mut out = String::new();
+        self.write_node(0, 0, feature_names, &format_leaf, &mut out);
+        out
+    }
+
+    fn write_node(
+        &self,
+        id: usize,
+        depth: usize,
+        feature_names: &[&str],
+        format_leaf: &impl Fn(&Node) -> String,
+        out: &mut String,
+    ) {
+        let indent = "|   ".repeat(depth);
+        let node = &self.nodes[id];
+        match &node.split {
+            Some(split) => {
+                let name = feature_name(feature_names, split.feature);
+                out.push_str(&format!(
+                    "{}|--- {} <= {:.2}\n",
+                    indent, name, split.threshold
+                ));
+                self.write_node(split.left, depth + 1, feature_names, format_leaf, out);
+                out.push_str(&format!(
+                    "{}|--- {} >  {:.2}\n",
+                    indent, name, split.threshold
+                ));
+                self.write_node(split.right, depth + 1, feature_names, format_leaf, out);
+            }
+            None => out.push_str(&format!("{}|--- {}\n", indent, format_leaf(node))),
+        }
+    }
+}
+
+/// Nom de la feature `j`, ou `feature_j` s'il n'a pas été fourni.
+pub(crate) fn feature_name(feature_names: &[&str], j: usize) -> String {
+    feature_names
+        .get(j)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("feature_{}", j))
+}
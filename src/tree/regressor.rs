@@ -0,0 +1,89 @@
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use super::{Regression, Tree, TreeParams, build_tree};
+use crate::error::{ModelError, check_n_features, check_x_y};
+
+/// Arbre de décision pour la régression (critère : erreur quadratique moyenne).
+///
+/// Chaque feuille prédit la moyenne de la cible des observations d'entraînement qu'elle contient.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DecisionTreeRegressor {
+    params: TreeParams,
+    tree: Option<Tree>,
+}
+
+impl DecisionTreeRegressor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Profondeur maximale (illimitée par défaut).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.params.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Nombre minimal d'observations pour découper un nœud (2 par défaut).
+    pub fn with_min_samples_split(mut self, min_samples_split: usize) -> Self {
+        self.params.min_samples_split = min_samples_split;
+        self
+    }
+
+    /// Nombre minimal d'observations dans chaque feuille (1 par défaut).
+    pub fn with_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.params.min_samples_leaf = min_samples_leaf;
+        self
+    }
+
+    /// Nombre de features tirées au hasard parmi lesquelles chercher chaque coupure
+    /// (toutes par défaut).
+    pub fn with_max_features(mut self, max_features: usize) -> Self {
+        self.params.max_features = Some(max_features);
+        self
+    }
+
+    /// Graine du tirage des features quand `max_features` est utilisé.
+    pub fn with_random_state(mut self, seed: u64) -> Self {
+        self.params.seed = seed;
+        self
+    }
+
+    pub fn fit(&mut self, x: &Array2<f64>, y: &Array1<f64>) -> Result<(), ModelError> {
+        check_x_y(x, y.len())?;
+        self.params.validate()?;
+        let y = y.to_vec();
+        let task = Regression { y: &y };
+        self.tree = Some(build_tree(
+            x.view(),
+            &task,
+            &self.params,
+            (0..x.nrows()).collect(),
+        ));
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Array2<f64>) -> Result<Array1<f64>, ModelError> {
+        let tree = self.tree()?;
+        check_n_features(tree.n_features(), x.ncols())?;
+        Ok(x.rows()
+            .into_iter()
+            .map(|row| tree.leaf(row).value[0])
+            .collect())
+    }
+
+    pub fn tree(&self) -> Result<&Tree, ModelError> {
+        self.tree.as_ref().ok_or(ModelError::NotFitted)
+    }
+
+    pub fn feature_importances(&self) -> Result<&Array1<f64>, ModelError> {
+        Ok(self.tree()?.feature_importances())
+    }
+
+    /// Arbre au format texte, les feuilles affichent la valeur prédite.
+    pub fn export_text(&self, feature_names: &[&str]) -> Result<String, ModelError> {
+        Ok(self
+            .tree()?
+            .export_text(feature_names, |node| format!("value: {:.2}", node.value[0])))
+    }
+}
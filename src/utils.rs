@@ -0,0 +1,54 @@
+//! Petites fonctions partagées par les modèles.
+
+use ndarray::Array1;
+
+use crate::error::ModelError;
+
+/// Indice de la plus grande valeur (le premier en cas d'égalité).
+pub(crate) fn argmax(values: impl Iterator<Item = f64>) -> usize {
+    values
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |(best_i, best_v), (i, v)| {
+            if v > best_v { (i, v) } else { (best_i, best_v) }
+        })
+        .0
+}
+
+/// Classes distinctes de `y`, triées par ordre croissant.
+pub(crate) fn unique_classes(y: &Array1<usize>) -> Vec<usize> {
+    let mut classes = y.to_vec();
+    classes.sort_unstable();
+    classes.dedup();
+    classes
+}
+
+/// log P(c) à partir des priors fournis, des fréquences observées ou d'une loi uniforme.
+pub(crate) fn class_log_prior(
+    priors: Option<&[f64]>,
+    counts: &[f64],
+    fit_prior: bool,
+) -> Result<Array1<f64>, ModelError> {
+    let n_classes = counts.len();
+    match priors {
+        Some(priors) => {
+            if priors.len() != n_classes {
+                return Err(ModelError::InvalidParameter(format!(
+                    "{} priors fournis pour {} classes",
+                    priors.len(),
+                    n_classes
+                )));
+            }
+            if priors.iter().any(|&p| p < 0.0) || (priors.iter().sum::<f64>() - 1.0).abs() > 1e-6 {
+                return Err(ModelError::InvalidParameter(
+                    "les priors doivent être positifs et sommer à 1".to_string(),
+                ));
+            }
+            Ok(priors.iter().map(|p| p.ln()).collect())
+        }
+        None if fit_prior => {
+            let total: f64 = counts.iter().sum();
+            Ok(counts.iter().map(|c| (c / total).ln()).collect())
+        }
+        None => Ok(Array1::from_elem(n_classes, -(n_classes as f64).ln())),
+    }
+}